@@ -197,7 +197,7 @@ impl ChunkedReader {
     }
 
     /// Read a 16-byte inline chunk header at the given offset.
-    fn read_header_at(data: &[u8], offset: usize) -> Result<ChunkIndexEntry, CtfsError> {
+    pub fn read_header_at(data: &[u8], offset: usize) -> Result<ChunkIndexEntry, CtfsError> {
         if offset + CHUNK_INDEX_ENTRY_SIZE > data.len() {
             return Err(CtfsError::Io(std::io::Error::new(
                 std::io::ErrorKind::UnexpectedEof,
@@ -150,6 +150,26 @@ fn conv_valuerecord(bldr: crate::trace_capnp::trace::value_record::Builder, vr:
             let mut q_typ_id = qi.init_type_id();
             q_typ_id.set_i(type_id.0.try_into().unwrap());
         }
+        codetracer_trace_types::ValueRecord::UInt { u, type_id } => {
+            let mut qu = bldr.init_uint();
+            qu.set_u(*u);
+            let mut q_typ_id = qu.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
+        codetracer_trace_types::ValueRecord::Int128 { i, type_id } => {
+            let mut qi = bldr.init_int128();
+            qi.set_lo(*i as u64);
+            qi.set_hi((*i >> 64) as u64);
+            let mut q_typ_id = qi.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
+        codetracer_trace_types::ValueRecord::UInt128 { u, type_id } => {
+            let mut qu = bldr.init_uint128();
+            qu.set_lo(*u as u64);
+            qu.set_hi((*u >> 64) as u64);
+            let mut q_typ_id = qu.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
         codetracer_trace_types::ValueRecord::Float { f, type_id } => {
             let mut qf = bldr.init_float();
             qf.set_f(*f);
@@ -202,6 +222,17 @@ fn conv_valuerecord(bldr: crate::trace_capnp::trace::value_record::Builder, vr:
             let mut q_typ_id = qstruc.init_type_id();
             q_typ_id.set_i(type_id.0.try_into().unwrap());
         }
+        codetracer_trace_types::ValueRecord::Map { entries, type_id } => {
+            let mut qmap = bldr.init_map();
+            let mut q_entries = qmap.reborrow().init_entries(entries.len().try_into().unwrap());
+            for (i, (key, value)) in entries.iter().enumerate() {
+                let mut q_entry = q_entries.reborrow().get(i.try_into().unwrap());
+                conv_valuerecord(q_entry.reborrow().init_key(), key);
+                conv_valuerecord(q_entry.init_value(), value);
+            }
+            let mut q_typ_id = qmap.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
         codetracer_trace_types::ValueRecord::Variant {
             discriminator,
             contents,
@@ -260,6 +291,33 @@ fn conv_valuerecord(bldr: crate::trace_capnp::trace::value_record::Builder, vr:
             let mut q_typ_id = qbigint.init_type_id();
             q_typ_id.set_i(type_id.0.try_into().unwrap());
         }
+        codetracer_trace_types::ValueRecord::Bytes { data, type_id } => {
+            let mut qbytes = bldr.init_bytes();
+            qbytes.set_data(data);
+            let mut q_typ_id = qbytes.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
+        codetracer_trace_types::ValueRecord::Elided {
+            partial,
+            original_len,
+            depth_cutoff,
+        } => {
+            let mut qelided = bldr.init_elided();
+            conv_valuerecord(qelided.reborrow().init_partial(), partial);
+            qelided.set_original_len(*original_len);
+            qelided.set_depth_cutoff(*depth_cutoff);
+        }
+        codetracer_trace_types::ValueRecord::Object { id, value } => {
+            let mut qobject = bldr.init_object();
+            qobject.set_id(id.0);
+            conv_valuerecord(qobject.init_value(), value);
+        }
+        codetracer_trace_types::ValueRecord::BackRef { id, type_id } => {
+            let mut qbackref = bldr.init_back_ref();
+            qbackref.set_id(id.0);
+            let mut q_typ_id = qbackref.init_type_id();
+            q_typ_id.set_i(type_id.0.try_into().unwrap());
+        }
         // The legacy capnp schema does not have a dedicated Char union member,
         // so we serialize it as Raw with the char's string representation.
         codetracer_trace_types::ValueRecord::Char { c, type_id } => {
@@ -307,6 +365,36 @@ pub fn write_trace(q: &[codetracer_trace_types::TraceLowLevelEvent], output: &mu
                         let mut deref_typ_id = ptr.init_dereference_type_id();
                         deref_typ_id.set_i(dereference_type_id.0.try_into().unwrap());
                     }
+                    codetracer_trace_types::TypeSpecificInfo::Collection { element_type_id } => {
+                        let collection = specific_info.init_collection();
+                        let mut elem_typ_id = collection.init_element_type_id();
+                        elem_typ_id.set_i(element_type_id.0.try_into().unwrap());
+                    }
+                    codetracer_trace_types::TypeSpecificInfo::Table { key_type_id, value_type_id } => {
+                        let mut table = specific_info.init_table();
+                        table.reborrow().init_key_type_id().set_i(key_type_id.0.try_into().unwrap());
+                        table.init_value_type_id().set_i(value_type_id.0.try_into().unwrap());
+                    }
+                    codetracer_trace_types::TypeSpecificInfo::Variants { variants } => {
+                        let vrnts = specific_info.init_variants();
+                        let mut ret_variants = vrnts.init_variants(variants.len().try_into().unwrap());
+                        for (i, variant) in variants.iter().enumerate() {
+                            let mut ret_variant = ret_variants.reborrow().get(i.try_into().unwrap());
+                            ret_variant.set_name(variant.name.clone());
+                            ret_variant.init_type_id().set_i(variant.type_id.0.try_into().unwrap());
+                        }
+                    }
+                    codetracer_trace_types::TypeSpecificInfo::Function {
+                        param_type_ids,
+                        return_type_id,
+                    } => {
+                        let mut function = specific_info.init_function();
+                        let mut params = function.reborrow().init_param_type_ids(param_type_ids.len().try_into().unwrap());
+                        for (i, param_type_id) in param_type_ids.iter().enumerate() {
+                            params.reborrow().get(i.try_into().unwrap()).set_i(param_type_id.0.try_into().unwrap());
+                        }
+                        function.init_return_type_id().set_i(return_type_id.0.try_into().unwrap());
+                    }
                 }
             }
             TraceLowLevelEvent::Path(pathbuf) => {
@@ -320,6 +408,9 @@ pub fn write_trace(q: &[codetracer_trace_types::TraceLowLevelEvent], output: &mu
                 let mut line = function_record.reborrow().init_line();
                 line.set_l(functionrecord.line.0);
                 function_record.set_name(functionrecord.name.clone());
+                if let Some(span) = &functionrecord.span {
+                    conv_source_span(function_record.init_span(), span);
+                }
             }
             TraceLowLevelEvent::Call(callrecord) => {
                 let mut call_record = event.init_call();
@@ -339,8 +430,11 @@ pub fn write_trace(q: &[codetracer_trace_types::TraceLowLevelEvent], output: &mu
                 let mut step_record = event.init_step();
                 let mut path_id = step_record.reborrow().init_path_id();
                 path_id.set_i(steprecord.path_id.0.try_into().unwrap());
-                let mut line = step_record.init_line();
+                let mut line = step_record.reborrow().init_line();
                 line.set_l(steprecord.line.0);
+                if let Some(span) = &steprecord.span {
+                    conv_source_span(step_record.init_span(), span);
+                }
             }
             TraceLowLevelEvent::VariableName(varname) => {
                 event.set_variable_name(varname);
@@ -362,6 +456,14 @@ pub fn write_trace(q: &[codetracer_trace_types::TraceLowLevelEvent], output: &mu
                 ret.set_metadata(&recordevent.metadata);
                 ret.set_content(&recordevent.content);
                 ret.set_kind(recordevent.kind.into());
+                if !recordevent.attributes.is_empty() {
+                    let mut ret_attributes = ret.init_attributes(recordevent.attributes.len().try_into().unwrap());
+                    for (i, attribute) in recordevent.attributes.iter().enumerate() {
+                        let mut ret_attribute = ret_attributes.reborrow().get(i.try_into().unwrap());
+                        ret_attribute.set_key(&attribute.key);
+                        conv_valuerecord(ret_attribute.init_value(), &attribute.value);
+                    }
+                }
             }
             TraceLowLevelEvent::Variable(str) => {
                 event.set_variable(str);
@@ -461,6 +563,75 @@ pub fn write_trace(q: &[codetracer_trace_types::TraceLowLevelEvent], output: &mu
                 let mut ret = event.init_thread_switch();
                 ret.set_i(tid.0);
             }
+            TraceLowLevelEvent::TaskSpawn(task_id) => {
+                let mut ret = event.init_task_spawn();
+                ret.set_i(task_id.0);
+            }
+            TraceLowLevelEvent::TaskSuspend(task_id) => {
+                let mut ret = event.init_task_suspend();
+                ret.set_i(task_id.0);
+            }
+            TraceLowLevelEvent::TaskResume(task_id) => {
+                let mut ret = event.init_task_resume();
+                ret.set_i(task_id.0);
+            }
+            TraceLowLevelEvent::TaskComplete(task_id) => {
+                let mut ret = event.init_task_complete();
+                ret.set_i(task_id.0);
+            }
+            TraceLowLevelEvent::Throw(throwrecord) => {
+                let ret = event.init_throw();
+                conv_valuerecord(ret.init_exception(), &throwrecord.exception);
+            }
+            TraceLowLevelEvent::Catch(catchrecord) => {
+                let ret = event.init_catch();
+                conv_valuerecord(ret.init_exception(), &catchrecord.exception);
+            }
+            TraceLowLevelEvent::Unwind(unwindrecord) => {
+                let ret = event.init_unwind();
+                let mut ret_frames = ret.init_frames(unwindrecord.frames.len().try_into().unwrap());
+                for (i, function_id) in unwindrecord.frames.iter().enumerate() {
+                    let mut frame = ret_frames.reborrow().get(i.try_into().unwrap());
+                    frame.set_i(function_id.0.try_into().unwrap());
+                }
+            }
+            TraceLowLevelEvent::ProcessStart(processstartrecord) => {
+                let mut ret = event.init_process_start();
+                ret.set_pid(processstartrecord.pid);
+                ret.set_parent_pid(processstartrecord.parent_pid);
+                ret.set_parent_recording_id(processstartrecord.parent_recording_id.as_deref().unwrap_or_default());
+            }
+            TraceLowLevelEvent::Exec(execrecord) => {
+                let mut ret = event.init_exec();
+                ret.set_program(&execrecord.program);
+                let mut ret_args = ret.init_args(execrecord.args.len().try_into().unwrap());
+                for (i, arg) in execrecord.args.iter().enumerate() {
+                    ret_args.set(i.try_into().unwrap(), arg);
+                }
+            }
+            TraceLowLevelEvent::Signal(signalrecord) => {
+                let mut ret = event.init_signal();
+                ret.set_signal(signalrecord.signal);
+                ret.set_name(&signalrecord.name);
+            }
+            TraceLowLevelEvent::Exit(exitrecord) => {
+                let mut ret = event.init_exit();
+                ret.set_code(exitrecord.code);
+            }
+            TraceLowLevelEvent::MemoryWrite(memorywriterecord) => {
+                let mut ret = event.init_memory_write();
+                ret.set_address(memorywriterecord.address);
+                ret.set_bytes(&memorywriterecord.bytes);
+            }
+            TraceLowLevelEvent::Alloc(allocrecord) => {
+                let mut ret = event.init_alloc();
+                ret.set_address(allocrecord.address);
+                ret.set_size(allocrecord.size);
+            }
+            TraceLowLevelEvent::Free(freerecord) => {
+                let mut ret = event.init_free();
+                ret.set_address(freerecord.address);
+            }
         }
     }
 
@@ -483,6 +654,18 @@ fn get_value_record(r: trace::value_record::Reader) -> Result<codetracer_trace_t
             i: q.get_i(),
             type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
         }),
+        Ok(trace::value_record::Which::Uint(q)) => Ok(codetracer_trace_types::ValueRecord::UInt {
+            u: q.get_u(),
+            type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+        }),
+        Ok(trace::value_record::Which::Int128(q)) => Ok(codetracer_trace_types::ValueRecord::Int128 {
+            i: (((q.get_hi() as u128) << 64) | q.get_lo() as u128) as i128,
+            type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+        }),
+        Ok(trace::value_record::Which::Uint128(q)) => Ok(codetracer_trace_types::ValueRecord::UInt128 {
+            u: ((q.get_hi() as u128) << 64) | q.get_lo() as u128,
+            type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+        }),
         Ok(trace::value_record::Which::Float(q)) => Ok(codetracer_trace_types::ValueRecord::Float {
             f: q.get_f(),
             type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
@@ -508,6 +691,17 @@ fn get_value_record(r: trace::value_record::Reader) -> Result<codetracer_trace_t
             field_values: get_value_records(q.get_field_values()?)?,
             type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
         }),
+        Ok(trace::value_record::Which::Map(q)) => {
+            let q_entries = q.get_entries()?;
+            let mut entries = Vec::with_capacity(q_entries.len().try_into().unwrap());
+            for q_entry in q_entries {
+                entries.push((get_value_record(q_entry.get_key()?)?, get_value_record(q_entry.get_value()?)?));
+            }
+            Ok(codetracer_trace_types::ValueRecord::Map {
+                entries,
+                type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+            })
+        }
         Ok(trace::value_record::Which::Variant(q)) => Ok(codetracer_trace_types::ValueRecord::Variant {
             discriminator: q.get_discriminator()?.to_string()?,
             contents: Box::new(get_value_record(q.get_contents()?)?),
@@ -538,10 +732,50 @@ fn get_value_record(r: trace::value_record::Reader) -> Result<codetracer_trace_t
             negative: q.get_negative(),
             type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
         }),
+        Ok(trace::value_record::Which::Bytes(q)) => Ok(codetracer_trace_types::ValueRecord::Bytes {
+            data: q.get_data()?.to_vec(),
+            type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+        }),
+        Ok(trace::value_record::Which::Elided(q)) => Ok(codetracer_trace_types::ValueRecord::Elided {
+            partial: Box::new(get_value_record(q.get_partial()?)?),
+            original_len: q.get_original_len(),
+            depth_cutoff: q.get_depth_cutoff(),
+        }),
+        Ok(trace::value_record::Which::Object(q)) => Ok(codetracer_trace_types::ValueRecord::Object {
+            id: codetracer_trace_types::ObjectId(q.get_id()),
+            value: Box::new(get_value_record(q.get_value()?)?),
+        }),
+        Ok(trace::value_record::Which::BackRef(q)) => Ok(codetracer_trace_types::ValueRecord::BackRef {
+            id: codetracer_trace_types::ObjectId(q.get_id()),
+            type_id: codetracer_trace_types::TypeId(q.get_type_id()?.get_i().try_into().unwrap()),
+        }),
         Err(_) => panic!(),
     }
 }
 
+fn conv_source_span(mut bldr: trace::source_span::Builder, span: &codetracer_trace_types::SourceSpan) {
+    bldr.set_start_line(span.start_line.0);
+    bldr.set_start_column(span.start_column);
+    bldr.set_end_line(span.end_line.0);
+    bldr.set_end_column(span.end_column);
+}
+
+fn get_source_span(
+    has_span: bool,
+    r: Result<trace::source_span::Reader, capnp::Error>,
+) -> Result<Option<codetracer_trace_types::SourceSpan>, capnp::Error> {
+    if !has_span {
+        return Ok(None);
+    }
+    let r = r?;
+    Ok(Some(codetracer_trace_types::SourceSpan {
+        start_line: codetracer_trace_types::Line(r.get_start_line()),
+        start_column: r.get_start_column(),
+        end_line: codetracer_trace_types::Line(r.get_end_line()),
+        end_column: r.get_end_column(),
+    }))
+}
+
 fn get_full_value_record(r: trace::full_value_record::Reader) -> Result<codetracer_trace_types::FullValueRecord, capnp::Error> {
     Ok(codetracer_trace_types::FullValueRecord {
         variable_id: codetracer_trace_types::VariableId(r.get_variable_id()?.get_i().try_into().unwrap()),
@@ -574,6 +808,7 @@ pub fn read_trace(input: &mut impl std::io::BufRead) -> ::capnp::Result<Vec<code
                 TraceLowLevelEvent::Step(codetracer_trace_types::StepRecord {
                     path_id: codetracer_trace_types::PathId(step_record.get_path_id()?.get_i().try_into().unwrap()),
                     line: codetracer_trace_types::Line(step_record.get_line()?.get_l()),
+                    span: get_source_span(step_record.has_span(), step_record.get_span())?,
                 })
             }
             Ok(trace::trace_low_level_event::Which::Path(path_buf)) => {
@@ -602,6 +837,36 @@ pub fn read_trace(input: &mut impl std::io::BufRead) -> ::capnp::Result<Vec<code
                         Ok(trace::type_specific_info::Which::Pointer(p)) => codetracer_trace_types::TypeSpecificInfo::Pointer {
                             dereference_type_id: codetracer_trace_types::TypeId(p.get_dereference_type_id()?.get_i().try_into().unwrap()),
                         },
+                        Ok(trace::type_specific_info::Which::Collection(c)) => codetracer_trace_types::TypeSpecificInfo::Collection {
+                            element_type_id: codetracer_trace_types::TypeId(c.get_element_type_id()?.get_i().try_into().unwrap()),
+                        },
+                        Ok(trace::type_specific_info::Which::Table(t)) => codetracer_trace_types::TypeSpecificInfo::Table {
+                            key_type_id: codetracer_trace_types::TypeId(t.get_key_type_id()?.get_i().try_into().unwrap()),
+                            value_type_id: codetracer_trace_types::TypeId(t.get_value_type_id()?.get_i().try_into().unwrap()),
+                        },
+                        Ok(trace::type_specific_info::Which::Variants(v)) => {
+                            let v_variants = v.get_variants()?;
+                            let mut variants: Vec<codetracer_trace_types::VariantTypeRecord> =
+                                Vec::with_capacity(v_variants.len().try_into().unwrap());
+                            for v_variant in v_variants {
+                                variants.push(codetracer_trace_types::VariantTypeRecord {
+                                    name: v_variant.get_name()?.to_string()?,
+                                    type_id: codetracer_trace_types::TypeId(v_variant.get_type_id()?.get_i().try_into().unwrap()),
+                                });
+                            }
+                            codetracer_trace_types::TypeSpecificInfo::Variants { variants }
+                        }
+                        Ok(trace::type_specific_info::Which::Function(f)) => {
+                            let f_params = f.get_param_type_ids()?;
+                            let mut param_type_ids: Vec<codetracer_trace_types::TypeId> = Vec::with_capacity(f_params.len().try_into().unwrap());
+                            for f_param in f_params {
+                                param_type_ids.push(codetracer_trace_types::TypeId(f_param.get_i().try_into().unwrap()));
+                            }
+                            codetracer_trace_types::TypeSpecificInfo::Function {
+                                param_type_ids,
+                                return_type_id: codetracer_trace_types::TypeId(f.get_return_type_id()?.get_i().try_into().unwrap()),
+                            }
+                        }
                         Err(_) => {
                             panic!()
                         }
@@ -615,6 +880,7 @@ pub fn read_trace(input: &mut impl std::io::BufRead) -> ::capnp::Result<Vec<code
                     path_id: codetracer_trace_types::PathId(function_record.get_path_id()?.get_i().try_into().unwrap()),
                     line: codetracer_trace_types::Line(function_record.get_line()?.get_l()),
                     name: function_record.get_name()?.to_string()?,
+                    span: get_source_span(function_record.has_span(), function_record.get_span())?,
                 })
             }
             Ok(trace::trace_low_level_event::Which::Call(call_record)) => {
@@ -637,10 +903,20 @@ pub fn read_trace(input: &mut impl std::io::BufRead) -> ::capnp::Result<Vec<code
             }),
             Ok(trace::trace_low_level_event::Which::Event(record_event)) => {
                 let record_event = record_event?;
+                let mut attributes = vec![];
+                if record_event.has_attributes() {
+                    for attribute in record_event.get_attributes()? {
+                        attributes.push(codetracer_trace_types::EventAttribute {
+                            key: attribute.get_key()?.to_string()?,
+                            value: get_value_record(attribute.get_value()?)?,
+                        });
+                    }
+                }
                 TraceLowLevelEvent::Event(codetracer_trace_types::RecordEvent {
                     kind: record_event.get_kind()?.into(),
                     metadata: record_event.get_metadata()?.to_string()?,
                     content: record_event.get_content()?.to_string()?,
+                    attributes,
                 })
             }
             Ok(trace::trace_low_level_event::Which::Asm(asm_strings)) => {
@@ -738,6 +1014,84 @@ pub fn read_trace(input: &mut impl std::io::BufRead) -> ::capnp::Result<Vec<code
                 TraceLowLevelEvent::ThreadSwitch(codetracer_trace_types::ThreadId(thread_id?.get_i()))
             }
             Ok(trace::trace_low_level_event::Which::DropLastStep(())) => TraceLowLevelEvent::DropLastStep,
+            Ok(trace::trace_low_level_event::Which::TaskSpawn(task_id)) => {
+                TraceLowLevelEvent::TaskSpawn(codetracer_trace_types::TaskId(task_id?.get_i()))
+            }
+            Ok(trace::trace_low_level_event::Which::TaskSuspend(task_id)) => {
+                TraceLowLevelEvent::TaskSuspend(codetracer_trace_types::TaskId(task_id?.get_i()))
+            }
+            Ok(trace::trace_low_level_event::Which::TaskResume(task_id)) => {
+                TraceLowLevelEvent::TaskResume(codetracer_trace_types::TaskId(task_id?.get_i()))
+            }
+            Ok(trace::trace_low_level_event::Which::TaskComplete(task_id)) => {
+                TraceLowLevelEvent::TaskComplete(codetracer_trace_types::TaskId(task_id?.get_i()))
+            }
+            Ok(trace::trace_low_level_event::Which::Throw(throw_record)) => TraceLowLevelEvent::Throw(codetracer_trace_types::ThrowRecord {
+                exception: get_value_record(throw_record?.get_exception()?)?,
+            }),
+            Ok(trace::trace_low_level_event::Which::Catch(catch_record)) => TraceLowLevelEvent::Catch(codetracer_trace_types::CatchRecord {
+                exception: get_value_record(catch_record?.get_exception()?)?,
+            }),
+            Ok(trace::trace_low_level_event::Which::Unwind(unwind_record)) => {
+                let frames = unwind_record?.get_frames()?;
+                let mut v: Vec<codetracer_trace_types::FunctionId> = Vec::with_capacity(frames.len().try_into().unwrap());
+                for frame in frames {
+                    v.push(codetracer_trace_types::FunctionId(frame.get_i().try_into().unwrap()));
+                }
+                TraceLowLevelEvent::Unwind(codetracer_trace_types::UnwindRecord { frames: v })
+            }
+            Ok(trace::trace_low_level_event::Which::ProcessStart(process_start_record)) => {
+                let process_start_record = process_start_record?;
+                let parent_recording_id = process_start_record.get_parent_recording_id()?.to_string()?;
+                TraceLowLevelEvent::ProcessStart(codetracer_trace_types::ProcessStartRecord {
+                    pid: process_start_record.get_pid(),
+                    parent_pid: process_start_record.get_parent_pid(),
+                    parent_recording_id: if parent_recording_id.is_empty() {
+                        None
+                    } else {
+                        Some(parent_recording_id)
+                    },
+                })
+            }
+            Ok(trace::trace_low_level_event::Which::Exec(exec_record)) => {
+                let exec_record = exec_record?;
+                let exec_args = exec_record.get_args()?;
+                let mut args: Vec<String> = Vec::with_capacity(exec_args.len().try_into().unwrap());
+                for arg in exec_args {
+                    args.push(arg?.to_string()?);
+                }
+                TraceLowLevelEvent::Exec(codetracer_trace_types::ExecRecord {
+                    program: exec_record.get_program()?.to_string()?,
+                    args,
+                })
+            }
+            Ok(trace::trace_low_level_event::Which::Signal(signal_record)) => {
+                let signal_record = signal_record?;
+                TraceLowLevelEvent::Signal(codetracer_trace_types::SignalRecord {
+                    signal: signal_record.get_signal(),
+                    name: signal_record.get_name()?.to_string()?,
+                })
+            }
+            Ok(trace::trace_low_level_event::Which::Exit(exit_record)) => TraceLowLevelEvent::Exit(codetracer_trace_types::ExitRecord {
+                code: exit_record?.get_code(),
+            }),
+            Ok(trace::trace_low_level_event::Which::MemoryWrite(memory_write_record)) => {
+                let memory_write_record = memory_write_record?;
+                TraceLowLevelEvent::MemoryWrite(codetracer_trace_types::MemoryWriteRecord {
+                    address: memory_write_record.get_address(),
+                    bytes: memory_write_record.get_bytes()?.to_vec(),
+                })
+            }
+            Ok(trace::trace_low_level_event::Which::Alloc(alloc_record)) => {
+                let alloc_record = alloc_record?;
+                TraceLowLevelEvent::Alloc(codetracer_trace_types::AllocRecord {
+                    address: alloc_record.get_address(),
+                    size: alloc_record.get_size(),
+                })
+            }
+            Ok(trace::trace_low_level_event::Which::Free(free_record)) => TraceLowLevelEvent::Free(codetracer_trace_types::FreeRecord {
+                address: free_record?.get_address(),
+            }),
             Err(_) => {
                 panic!()
             }
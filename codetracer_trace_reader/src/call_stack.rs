@@ -0,0 +1,132 @@
+//! Reader-side call stack reconstruction.
+//!
+//! [`CallStack`] is fed the events in trace order and keeps one stack of
+//! function ids per execution context: `Call` pushes, `Return` pops one frame
+//! and `Unwind` pops one frame per unwound function, so a trace where some
+//! functions exit through an exception stays balanced.
+//!
+//! A context is either an OS thread (selected by `ThreadSwitch`) or an async
+//! task running on it. Between `TaskResume` and the next `TaskSuspend` or
+//! `TaskComplete` the events belong to the task, and the task's frames are
+//! kept while it is suspended, so after the next `TaskResume` its logical
+//! stack continues where the `await` left it.
+
+use std::collections::HashMap;
+
+use codetracer_trace_types::{FunctionId, TaskId, ThreadId, TraceLowLevelEvent, ValueRecord};
+
+#[derive(Debug, Default)]
+pub struct CallStack {
+    threads: HashMap<ThreadId, ThreadState>,
+    tasks: HashMap<TaskId, Frames>,
+    current: ThreadId,
+}
+
+#[derive(Debug, Default)]
+struct ThreadState {
+    frames: Frames,
+    // tasks resumed on this thread, innermost (the one running) last
+    running: Vec<TaskId>,
+}
+
+#[derive(Debug, Default)]
+struct Frames {
+    frames: Vec<FunctionId>,
+    // thrown and not yet caught
+    exception: Option<ValueRecord>,
+}
+
+impl CallStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, event: &TraceLowLevelEvent) {
+        match event {
+            TraceLowLevelEvent::Call(call) => self.active_mut().frames.push(call.function_id),
+            TraceLowLevelEvent::Return(_) => {
+                self.active_mut().frames.pop();
+            }
+            TraceLowLevelEvent::Throw(throw) => self.active_mut().exception = Some(throw.exception.clone()),
+            TraceLowLevelEvent::Catch(_) => self.active_mut().exception = None,
+            TraceLowLevelEvent::Unwind(unwind) => {
+                let frames = &mut self.active_mut().frames;
+                frames.truncate(frames.len().saturating_sub(unwind.frames.len()));
+            }
+            TraceLowLevelEvent::ThreadSwitch(thread_id) => self.current = *thread_id,
+            TraceLowLevelEvent::ThreadExit(thread_id) => {
+                self.threads.remove(thread_id);
+            }
+            TraceLowLevelEvent::TaskSpawn(task_id) => {
+                self.tasks.entry(*task_id).or_default();
+            }
+            TraceLowLevelEvent::TaskResume(task_id) => {
+                self.tasks.entry(*task_id).or_default();
+                self.threads.entry(self.current).or_default().running.push(*task_id);
+            }
+            TraceLowLevelEvent::TaskSuspend(task_id) => self.stop_running(*task_id),
+            TraceLowLevelEvent::TaskComplete(task_id) => {
+                self.stop_running(*task_id);
+                self.tasks.remove(task_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// The logical stack of the active context, outermost first: the running
+    /// task's frames, or the thread's own frames when no task is running.
+    pub fn frames(&self) -> &[FunctionId] {
+        match self.current_task() {
+            Some(task_id) => self.task_frames(task_id),
+            None => self.thread_frames(self.current),
+        }
+    }
+
+    /// The frames of `thread_id` outside of any task, outermost first.
+    pub fn thread_frames(&self, thread_id: ThreadId) -> &[FunctionId] {
+        self.threads.get(&thread_id).map(|state| state.frames.frames.as_slice()).unwrap_or(&[])
+    }
+
+    /// The logical stack of `task_id`, including while it is suspended.
+    pub fn task_frames(&self, task_id: TaskId) -> &[FunctionId] {
+        self.tasks.get(&task_id).map(|frames| frames.frames.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn depth(&self) -> usize {
+        self.frames().len()
+    }
+
+    pub fn current_thread(&self) -> ThreadId {
+        self.current
+    }
+
+    /// The task running on the active thread, if any.
+    pub fn current_task(&self) -> Option<TaskId> {
+        self.threads.get(&self.current).and_then(|state| state.running.last().copied())
+    }
+
+    /// The exception the active context is propagating: thrown and not yet
+    /// caught.
+    pub fn pending_exception(&self) -> Option<&ValueRecord> {
+        let frames = match self.current_task() {
+            Some(task_id) => self.tasks.get(&task_id),
+            None => self.threads.get(&self.current).map(|state| &state.frames),
+        };
+        frames.and_then(|frames| frames.exception.as_ref())
+    }
+
+    fn active_mut(&mut self) -> &mut Frames {
+        let state = self.threads.entry(self.current).or_default();
+        match state.running.last() {
+            Some(task_id) => self.tasks.entry(*task_id).or_default(),
+            None => &mut state.frames,
+        }
+    }
+
+    fn stop_running(&mut self, task_id: TaskId) {
+        if let Some(state) = self.threads.get_mut(&self.current) {
+            state.running.retain(|running| *running != task_id);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Reader-side call tree and step index.
+//!
+//! Traces don't carry call keys, step ids or call depths: recorders only
+//! emit `Call`, `Return` and `Step` and leave the numbering to the reader.
+//! [`CallTree`] is fed the events in trace order together with their GEID
+//! (the event's index in the trace) and numbers them the way the rest of
+//! CodeTracer expects:
+//!
+//! - the n-th `Call` of the trace gets `CallKey(n)`
+//! - the n-th `Step` still in the trace gets `StepId(n)`; a `DropLastStep`
+//!   takes the number of the step it drops back
+//!
+//! Each call knows its parent, depth, arguments and return value, and each
+//! step the call it was executed in. Writers register a step at the
+//! function's line right before each (non-toplevel) `Call`; that step is
+//! the first step of the called function, not one of the caller's. Calls
+//! are nested per thread and per async task, following [`CallStack`], so
+//! interleaved threads don't become each other's callers.
+//!
+//! The step to call mapping is what lookups by position need most; it can
+//! be stored in the CTFS container as a step index (see
+//! `ctfs_reader::store_step_index`) so later readers don't have to replay
+//! the whole trace.
+
+use std::collections::HashMap;
+
+use codetracer_trace_types::{CallKey, FullValueRecord, FunctionId, NO_KEY, StepId, TaskId, ThreadId, TraceLowLevelEvent, ValueRecord};
+
+use crate::call_stack::CallStack;
+
+#[derive(Debug, Clone)]
+pub struct CallInfo {
+    pub key: CallKey,
+    pub function_id: FunctionId,
+    /// `NO_KEY` for the outermost call of a thread or task.
+    pub parent_key: CallKey,
+    /// Number of enclosing calls; 0 for the outermost ones.
+    pub depth: usize,
+    pub thread_id: ThreadId,
+    /// The async task the call ran in, if any.
+    pub task_id: Option<TaskId>,
+    pub call_geid: u64,
+    /// GEID of the `Return` or `Unwind` that left the call; `None` while it
+    /// is still open.
+    pub end_geid: Option<u64>,
+    /// First and last step executed in the call itself, not in its callees.
+    pub first_step: Option<StepId>,
+    pub last_step: Option<StepId>,
+    pub args: Vec<FullValueRecord>,
+    /// `None` while the call is open and for calls left by an `Unwind`.
+    pub return_value: Option<ValueRecord>,
+    pub children: Vec<CallKey>,
+}
+
+/// Where a step is in the trace and which call it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepIndexEntry {
+    pub geid: u64,
+    /// `NO_KEY` for steps outside of any call.
+    pub call_key: CallKey,
+}
+
+impl StepIndexEntry {
+    pub const ENCODED_SIZE: usize = 16;
+}
+
+#[derive(Hash, Debug, Copy, Clone, PartialEq, Eq)]
+enum Context {
+    Thread(ThreadId),
+    Task(TaskId),
+}
+
+#[derive(Debug, Default)]
+pub struct CallTree {
+    calls: Vec<CallInfo>,
+    steps: Vec<StepIndexEntry>,
+    // for each step, the `last_step` its call had before it, so the step
+    // can be taken back from the call
+    previous_last_steps: Vec<Option<StepId>>,
+    // the previous event was a `Step`
+    after_step: bool,
+    // open calls of each context, innermost last
+    open: HashMap<Context, Vec<CallKey>>,
+    call_stack: CallStack,
+}
+
+impl CallTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the tree for a whole trace; the GEID of each event is its index.
+    pub fn from_events(events: &[TraceLowLevelEvent]) -> Self {
+        let mut tree = Self::new();
+        for (geid, event) in events.iter().enumerate() {
+            tree.observe(geid as u64, event);
+        }
+        tree
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        let context = self.context();
+        match event {
+            TraceLowLevelEvent::Call(call) => {
+                let key = CallKey(self.calls.len() as i64);
+                let entry_step = if self.after_step { self.detach_last_step() } else { None };
+                let open = self.open.entry(context).or_default();
+                let parent_key = open.last().copied().unwrap_or(NO_KEY);
+                let depth = open.len();
+                open.push(key);
+                if parent_key != NO_KEY {
+                    self.calls[parent_key.0 as usize].children.push(key);
+                }
+                self.calls.push(CallInfo {
+                    key,
+                    function_id: call.function_id,
+                    parent_key,
+                    depth,
+                    thread_id: self.call_stack.current_thread(),
+                    task_id: self.call_stack.current_task(),
+                    call_geid: geid,
+                    end_geid: None,
+                    first_step: entry_step,
+                    last_step: entry_step,
+                    args: call.args.clone(),
+                    return_value: None,
+                    children: vec![],
+                });
+                if let Some(step_id) = entry_step {
+                    self.steps[step_id.0 as usize].call_key = key;
+                }
+            }
+            TraceLowLevelEvent::Return(ret) => {
+                if let Some(key) = self.open.get_mut(&context).and_then(|open| open.pop()) {
+                    let call = &mut self.calls[key.0 as usize];
+                    call.end_geid = Some(geid);
+                    call.return_value = Some(ret.return_value.clone());
+                }
+            }
+            TraceLowLevelEvent::Unwind(unwind) => {
+                if let Some(open) = self.open.get_mut(&context) {
+                    let remaining = open.len().saturating_sub(unwind.frames.len());
+                    for key in open.drain(remaining..) {
+                        self.calls[key.0 as usize].end_geid = Some(geid);
+                    }
+                }
+            }
+            TraceLowLevelEvent::Step(_) => {
+                let call_key = self.current_call();
+                self.push_step(geid, call_key);
+            }
+            TraceLowLevelEvent::DropLastStep => {
+                self.detach_last_step();
+                self.steps.pop();
+                self.previous_last_steps.pop();
+            }
+            TraceLowLevelEvent::ThreadExit(thread_id) => {
+                self.open.remove(&Context::Thread(*thread_id));
+            }
+            TraceLowLevelEvent::TaskComplete(task_id) => {
+                self.open.remove(&Context::Task(*task_id));
+            }
+            _ => {}
+        }
+        self.after_step = matches!(event, TraceLowLevelEvent::Step(_));
+        self.call_stack.observe(event);
+    }
+
+    /// Every call so far, indexed by `CallKey`.
+    pub fn calls(&self) -> &[CallInfo] {
+        &self.calls
+    }
+
+    pub fn call(&self, key: CallKey) -> Option<&CallInfo> {
+        usize::try_from(key.0).ok().and_then(|index| self.calls.get(index))
+    }
+
+    /// The outermost calls of every thread and task, in call order.
+    pub fn roots(&self) -> impl Iterator<Item = &CallInfo> {
+        self.calls.iter().filter(|call| call.parent_key == NO_KEY)
+    }
+
+    /// The step index: one entry per step, indexed by `StepId`.
+    pub fn steps(&self) -> &[StepIndexEntry] {
+        &self.steps
+    }
+
+    /// The call `step_id` was executed in; `NO_KEY` for an unknown step or
+    /// one outside of any call.
+    pub fn call_of_step(&self, step_id: StepId) -> CallKey {
+        usize::try_from(step_id.0)
+            .ok()
+            .and_then(|index| self.steps.get(index))
+            .map_or(NO_KEY, |step| step.call_key)
+    }
+
+    /// The innermost open call of the active thread or task.
+    pub fn current_call(&self) -> CallKey {
+        self.open.get(&self.context()).and_then(|open| open.last().copied()).unwrap_or(NO_KEY)
+    }
+
+    pub fn current_thread(&self) -> ThreadId {
+        self.call_stack.current_thread()
+    }
+
+    fn context(&self) -> Context {
+        match self.call_stack.current_task() {
+            Some(task_id) => Context::Task(task_id),
+            None => Context::Thread(self.call_stack.current_thread()),
+        }
+    }
+
+    fn push_step(&mut self, geid: u64, call_key: CallKey) {
+        let step_id = StepId(self.steps.len() as i64);
+        let mut previous_last_step = None;
+        if call_key != NO_KEY {
+            let call = &mut self.calls[call_key.0 as usize];
+            call.first_step.get_or_insert(step_id);
+            previous_last_step = call.last_step.replace(step_id);
+        }
+        self.steps.push(StepIndexEntry { geid, call_key });
+        self.previous_last_steps.push(previous_last_step);
+    }
+
+    /// Take the last step away from its call, leaving it in the index
+    /// without a call; returns its id.
+    fn detach_last_step(&mut self) -> Option<StepId> {
+        let step_id = StepId(self.steps.len().checked_sub(1)? as i64);
+        let step = self.steps.last_mut()?;
+        let call_key = std::mem::replace(&mut step.call_key, NO_KEY);
+        let previous_last_step = self.previous_last_steps.last_mut()?.take();
+        if call_key != NO_KEY {
+            let call = &mut self.calls[call_key.0 as usize];
+            call.last_step = previous_last_step;
+            if previous_last_step.is_none() {
+                // it was the only step of the call
+                call.first_step = None;
+            }
+        }
+        Some(step_id)
+    }
+}
+
+/// Serialize a step index: per step, its GEID as a little-endian u64
+/// followed by its call key as a little-endian i64.
+pub fn encode_step_index(steps: &[StepIndexEntry]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(steps.len() * StepIndexEntry::ENCODED_SIZE);
+    for step in steps {
+        bytes.extend_from_slice(&step.geid.to_le_bytes());
+        bytes.extend_from_slice(&step.call_key.0.to_le_bytes());
+    }
+    bytes
+}
+
+pub fn decode_step_index(bytes: &[u8]) -> Result<Vec<StepIndexEntry>, String> {
+    if !bytes.len().is_multiple_of(StepIndexEntry::ENCODED_SIZE) {
+        return Err(format!(
+            "step index size {} is not a multiple of {}",
+            bytes.len(),
+            StepIndexEntry::ENCODED_SIZE
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(StepIndexEntry::ENCODED_SIZE)
+        .map(|entry| StepIndexEntry {
+            geid: u64::from_le_bytes(entry[..8].try_into().unwrap()),
+            call_key: CallKey(i64::from_le_bytes(entry[8..].try_into().unwrap())),
+        })
+        .collect())
+}
@@ -0,0 +1,191 @@
+//! Export to the Chrome Trace Event format, for `chrome://tracing` and the
+//! Perfetto UI.
+//!
+//! [`ChromeTraceExporter`] maps every `Call` to a `B` (begin) and its
+//! `Return` to an `E` (end) duration event on the track of the thread the
+//! call ran on; `Unwind`, `ThreadExit` and `TaskComplete` end the frames
+//! they drop, and the frames still open at the end of the trace end there.
+//! Async tasks get a track of their own, in a separate "async tasks"
+//! process, as their frames outlive suspensions and would otherwise break
+//! the nesting of their thread's track. `RecordEvent`s and `Throw`s become
+//! instant events.
+//!
+//! Events carry no wall-clock time, so the timestamp of each Chrome event
+//! is the GEID of the trace event it comes from: durations are in events,
+//! shown by the viewers as microseconds.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use codetracer_trace_types::{TaskId, ThreadId, TraceLowLevelEvent};
+use serde_json::{Map, Value, json};
+
+use crate::call_stack::CallStack;
+
+const THREADS_PID: u64 = 1;
+const TASKS_PID: u64 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Track {
+    Thread(ThreadId),
+    Task(TaskId),
+}
+
+impl Track {
+    fn ids(self) -> (u64, u64) {
+        match self {
+            Track::Thread(thread_id) => (THREADS_PID, thread_id.0),
+            Track::Task(task_id) => (TASKS_PID, task_id.0),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ChromeTraceExporter {
+    paths: Vec<String>,
+    // name, path id and line of each function
+    functions: Vec<(String, usize, i64)>,
+    variables: Vec<String>,
+    call_stack: CallStack,
+    // the names of the open frames of each track
+    open: BTreeMap<Track, Vec<String>>,
+    named: BTreeSet<Track>,
+    events: Vec<Value>,
+    last_geid: u64,
+}
+
+impl ChromeTraceExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Export a whole trace; the GEID of each event is its index.
+    pub fn export(events: &[TraceLowLevelEvent]) -> Value {
+        let mut exporter = Self::new();
+        for (geid, event) in events.iter().enumerate() {
+            exporter.observe(geid as u64, event);
+        }
+        exporter.finish()
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        self.last_geid = geid;
+        let track = self.current_track();
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.display().to_string()),
+            TraceLowLevelEvent::Function(function) => self.functions.push((function.name.clone(), function.path_id.0, function.line.0)),
+            TraceLowLevelEvent::VariableName(name) | TraceLowLevelEvent::Variable(name) => self.variables.push(name.clone()),
+            TraceLowLevelEvent::ThreadStart(thread_id) => self.name_track(Track::Thread(*thread_id)),
+            TraceLowLevelEvent::Call(call) => {
+                let (name, path, line) = match self.functions.get(call.function_id.0) {
+                    Some((name, path_id, line)) => (name.clone(), self.paths.get(*path_id).cloned(), Some(*line)),
+                    None => (format!("<function {}>", call.function_id.0), None, None),
+                };
+                let arguments: Map<String, Value> = call
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        let name = self
+                            .variables
+                            .get(arg.variable_id.0)
+                            .cloned()
+                            .unwrap_or_else(|| arg.variable_id.0.to_string());
+                        (name, serde_json::to_value(&arg.value).unwrap_or(Value::Null))
+                    })
+                    .collect();
+                self.begin(track, geid, &name, json!({ "path": path, "line": line, "arguments": arguments }));
+            }
+            TraceLowLevelEvent::Return(ret) => {
+                let args = json!({ "return": serde_json::to_value(&ret.return_value).unwrap_or(Value::Null) });
+                self.end(track, geid, 1, Some(args));
+            }
+            TraceLowLevelEvent::Unwind(unwind) => self.end(track, geid, unwind.frames.len(), None),
+            TraceLowLevelEvent::ThreadExit(thread_id) => self.end(Track::Thread(*thread_id), geid, usize::MAX, None),
+            TraceLowLevelEvent::TaskComplete(task_id) => self.end(Track::Task(*task_id), geid, usize::MAX, None),
+            TraceLowLevelEvent::Event(record_event) => {
+                let args = json!({ "content": record_event.content, "metadata": record_event.metadata });
+                self.instant(track, geid, &format!("{:?}", record_event.kind), "event", args);
+            }
+            TraceLowLevelEvent::Throw(throw) => {
+                let args = json!({ "exception": serde_json::to_value(&throw.exception).unwrap_or(Value::Null) });
+                self.instant(track, geid, "throw", "exception", args);
+            }
+            _ => {}
+        }
+        self.call_stack.observe(event);
+    }
+
+    /// End the frames still open and return the Chrome trace JSON.
+    pub fn finish(mut self) -> Value {
+        let end = self.last_geid + 1;
+        let tracks: Vec<Track> = self.open.keys().copied().collect();
+        for track in tracks {
+            self.end(track, end, usize::MAX, None);
+        }
+        json!({
+            "traceEvents": self.events,
+            "displayTimeUnit": "ms",
+            "otherData": { "timestamps": "geid" },
+        })
+    }
+
+    fn current_track(&self) -> Track {
+        match self.call_stack.current_task() {
+            Some(task_id) => Track::Task(task_id),
+            None => Track::Thread(self.call_stack.current_thread()),
+        }
+    }
+
+    // the metadata events naming a track, once
+    fn name_track(&mut self, track: Track) {
+        let first_task = matches!(track, Track::Task(_)) && !self.named.iter().any(|named| matches!(named, Track::Task(_)));
+        if !self.named.insert(track) {
+            return;
+        }
+        let (pid, tid) = track.ids();
+        if first_task {
+            self.events
+                .push(json!({ "name": "process_name", "ph": "M", "pid": pid, "args": { "name": "async tasks" } }));
+        }
+        let name = match track {
+            Track::Thread(thread_id) => format!("thread {}", thread_id.0),
+            Track::Task(task_id) => format!("task {}", task_id.0),
+        };
+        self.events
+            .push(json!({ "name": "thread_name", "ph": "M", "pid": pid, "tid": tid, "args": { "name": name } }));
+    }
+
+    fn begin(&mut self, track: Track, geid: u64, name: &str, args: Value) {
+        self.name_track(track);
+        let (pid, tid) = track.ids();
+        self.events
+            .push(json!({ "name": name, "cat": "call", "ph": "B", "ts": geid, "pid": pid, "tid": tid, "args": args }));
+        self.open.entry(track).or_default().push(name.to_string());
+    }
+
+    // end up to `frames` open frames of `track`, innermost first
+    fn end(&mut self, track: Track, geid: u64, frames: usize, args: Option<Value>) {
+        let Some(open) = self.open.get_mut(&track) else {
+            return;
+        };
+        let (pid, tid) = track.ids();
+        for _ in 0..frames.min(open.len()) {
+            let name = open.pop().unwrap_or_default();
+            let mut event = json!({ "name": name, "cat": "call", "ph": "E", "ts": geid, "pid": pid, "tid": tid });
+            if let Some(args) = &args {
+                event["args"] = args.clone();
+            }
+            self.events.push(event);
+        }
+        if open.is_empty() {
+            self.open.remove(&track);
+        }
+    }
+
+    fn instant(&mut self, track: Track, geid: u64, name: &str, category: &str, args: Value) {
+        self.name_track(track);
+        let (pid, tid) = track.ids();
+        self.events
+            .push(json!({ "name": name, "cat": category, "ph": "i", "s": "t", "ts": geid, "pid": pid, "tid": tid, "args": args }));
+    }
+}
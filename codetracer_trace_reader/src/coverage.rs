@@ -0,0 +1,262 @@
+//! Line and function coverage from a trace.
+//!
+//! Every `Step` is a hit of its line, so [`Coverage`] only has to count them
+//! per source file (through the trace's `Path` records) and per function.
+//! A step is counted in the function whose frame is on top of the stack,
+//! except for the entry step that writers emit right before a `Call`, which
+//! belongs to the callee; a `DropLastStep` takes the hit back. Functions are
+//! counted once per `Call`.
+//!
+//! The result can be written as an lcov tracefile ([`Coverage::to_lcov`])
+//! or as a Cobertura XML report ([`Coverage::to_cobertura`]). A trace only
+//! knows the lines that ran, so both list executed lines only.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use codetracer_trace_types::{FunctionId, Line, PathId, StepRecord, TraceLowLevelEvent};
+
+use crate::call_stack::CallStack;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionCoverage {
+    pub name: String,
+    pub path_id: PathId,
+    pub line: Line,
+    pub calls: u64,
+    /// Hits per line of its own file while the function was running.
+    pub lines: BTreeMap<i64, u64>,
+}
+
+#[derive(Debug, Default)]
+pub struct Coverage {
+    paths: Vec<PathBuf>,
+    // by `PathId`
+    lines: Vec<BTreeMap<i64, u64>>,
+    // by `FunctionId`
+    functions: Vec<FunctionCoverage>,
+    call_stack: CallStack,
+    // the last step, until the next event tells whose it is
+    pending_step: Option<StepRecord>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the coverage of a whole trace.
+    pub fn from_events(events: &[TraceLowLevelEvent]) -> Self {
+        let mut coverage = Self::new();
+        for event in events {
+            coverage.observe(event);
+        }
+        coverage.finish();
+        coverage
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, event: &TraceLowLevelEvent) {
+        if let Some(step) = self.pending_step.take() {
+            match event {
+                TraceLowLevelEvent::Call(call) => self.count_in_function(call.function_id, &step),
+                TraceLowLevelEvent::DropLastStep => {
+                    if let Some(hits) = self.lines.get_mut(step.path_id.0).and_then(|lines| lines.get_mut(&step.line.0)) {
+                        *hits -= 1;
+                    }
+                }
+                _ => self.count_in_current_function(&step),
+            }
+        }
+        self.call_stack.observe(event);
+
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.clone()),
+            TraceLowLevelEvent::Function(function) => self.functions.push(FunctionCoverage {
+                name: function.name.clone(),
+                path_id: function.path_id,
+                line: function.line,
+                calls: 0,
+                lines: BTreeMap::new(),
+            }),
+            TraceLowLevelEvent::Step(step) => {
+                if self.lines.len() <= step.path_id.0 {
+                    self.lines.resize_with(step.path_id.0 + 1, BTreeMap::new);
+                }
+                *self.lines[step.path_id.0].entry(step.line.0).or_default() += 1;
+                self.pending_step = Some(*step);
+            }
+            TraceLowLevelEvent::Call(call) => {
+                if let Some(function) = self.functions.get_mut(call.function_id.0) {
+                    function.calls += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Count the last step of the trace; call once all events are in.
+    pub fn finish(&mut self) {
+        if let Some(step) = self.pending_step.take() {
+            self.count_in_current_function(&step);
+        }
+    }
+
+    /// Hits per line of every file that had any, by path. Paths recorded
+    /// more than once are merged.
+    pub fn files(&self) -> BTreeMap<&Path, BTreeMap<i64, u64>> {
+        let mut files: BTreeMap<&Path, BTreeMap<i64, u64>> = BTreeMap::new();
+        for (path_id, lines) in self.lines.iter().enumerate() {
+            let Some(path) = self.paths.get(path_id) else {
+                continue;
+            };
+            let file = files.entry(path.as_path()).or_default();
+            for (line, hits) in lines.iter().filter(|(_, hits)| **hits > 0) {
+                *file.entry(*line).or_default() += hits;
+            }
+        }
+        files.retain(|_, lines| !lines.is_empty());
+        files
+    }
+
+    /// Every function of the trace, by `FunctionId`.
+    pub fn functions(&self) -> &[FunctionCoverage] {
+        &self.functions
+    }
+
+    pub fn path(&self, path_id: PathId) -> Option<&Path> {
+        self.paths.get(path_id.0).map(PathBuf::as_path)
+    }
+
+    /// The functions defined in `path`, by line.
+    fn functions_in(&self, path: &Path) -> Vec<&FunctionCoverage> {
+        let mut functions: Vec<&FunctionCoverage> = self
+            .functions
+            .iter()
+            .filter(|function| self.path(function.path_id) == Some(path))
+            .collect();
+        functions.sort_by_key(|function| (function.line.0, function.name.as_str()));
+        functions
+    }
+
+    /// An lcov tracefile, with `FN`/`FNDA` records for the functions and
+    /// `DA` records for the lines.
+    pub fn to_lcov(&self) -> String {
+        let mut info = String::new();
+        for (path, lines) in self.files() {
+            _ = writeln!(info, "TN:");
+            _ = writeln!(info, "SF:{}", path.display());
+            let functions = self.functions_in(path);
+            for function in &functions {
+                _ = writeln!(info, "FN:{},{}", function.line.0, function.name);
+            }
+            for function in &functions {
+                _ = writeln!(info, "FNDA:{},{}", function.calls, function.name);
+            }
+            _ = writeln!(info, "FNF:{}", functions.len());
+            _ = writeln!(info, "FNH:{}", functions.iter().filter(|function| function.calls > 0).count());
+            for (line, hits) in &lines {
+                _ = writeln!(info, "DA:{line},{hits}");
+            }
+            _ = writeln!(info, "LF:{}", lines.len());
+            _ = writeln!(info, "LH:{}", lines.len());
+            _ = writeln!(info, "end_of_record");
+        }
+        info
+    }
+
+    /// A Cobertura XML report with one class per file, grouped into
+    /// packages by directory, and one method per function listing the lines
+    /// hit while it ran.
+    pub fn to_cobertura(&self) -> String {
+        let files = self.files();
+        let total_lines: usize = files.values().map(BTreeMap::len).sum();
+        let mut packages: BTreeMap<String, Vec<&Path>> = BTreeMap::new();
+        for path in files.keys() {
+            let package = path.parent().map_or(String::new(), |parent| parent.display().to_string());
+            packages.entry(package).or_default().push(path);
+        }
+
+        let mut xml = String::new();
+        _ = writeln!(xml, r#"<?xml version="1.0" ?>"#);
+        _ = writeln!(
+            xml,
+            r#"<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">"#
+        );
+        _ = writeln!(
+            xml,
+            r#"<coverage line-rate="1" branch-rate="0" lines-covered="{total_lines}" lines-valid="{total_lines}" branches-covered="0" branches-valid="0" complexity="0">"#
+        );
+        _ = writeln!(xml, "  <packages>");
+        for (package, classes) in &packages {
+            _ = writeln!(
+                xml,
+                r#"    <package name="{}" line-rate="1" branch-rate="0" complexity="0">"#,
+                escape_xml(package)
+            );
+            _ = writeln!(xml, "      <classes>");
+            for path in classes {
+                let name = path.file_name().map_or(String::new(), |name| name.to_string_lossy().into_owned());
+                _ = writeln!(
+                    xml,
+                    r#"        <class name="{}" filename="{}" line-rate="1" branch-rate="0" complexity="0">"#,
+                    escape_xml(&name),
+                    escape_xml(&path.display().to_string())
+                );
+                _ = writeln!(xml, "          <methods>");
+                for function in self.functions_in(path) {
+                    let rate = if function.calls > 0 { "1" } else { "0" };
+                    _ = writeln!(
+                        xml,
+                        r#"            <method name="{}" signature="" line-rate="{rate}" branch-rate="0" complexity="0">"#,
+                        escape_xml(&function.name)
+                    );
+                    _ = writeln!(xml, "              <lines>");
+                    if function.lines.is_empty() {
+                        _ = writeln!(xml, r#"                <line number="{}" hits="0"/>"#, function.line.0);
+                    }
+                    for (line, hits) in &function.lines {
+                        _ = writeln!(xml, r#"                <line number="{line}" hits="{hits}"/>"#);
+                    }
+                    _ = writeln!(xml, "              </lines>");
+                    _ = writeln!(xml, "            </method>");
+                }
+                _ = writeln!(xml, "          </methods>");
+                _ = writeln!(xml, "          <lines>");
+                for (line, hits) in &files[path] {
+                    _ = writeln!(xml, r#"            <line number="{line}" hits="{hits}"/>"#);
+                }
+                _ = writeln!(xml, "          </lines>");
+                _ = writeln!(xml, "        </class>");
+            }
+            _ = writeln!(xml, "      </classes>");
+            _ = writeln!(xml, "    </package>");
+        }
+        _ = writeln!(xml, "  </packages>");
+        _ = writeln!(xml, "</coverage>");
+        xml
+    }
+
+    fn count_in_function(&mut self, function_id: FunctionId, step: &StepRecord) {
+        if let Some(function) = self.functions.get_mut(function_id.0)
+            && function.path_id == step.path_id
+        {
+            *function.lines.entry(step.line.0).or_default() += 1;
+        }
+    }
+
+    fn count_in_current_function(&mut self, step: &StepRecord) {
+        if let Some(function_id) = self.call_stack.frames().last().copied() {
+            self.count_in_function(function_id, step);
+        }
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,11 +1,18 @@
-use std::io::{BufRead, BufReader, Cursor};
+use std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom};
 
-use codetracer_ctfs::{ChunkedReader, CtfsReader};
+use codetracer_ctfs::{CHUNK_INDEX_ENTRY_SIZE, ChunkIndexEntry, ChunkedReader, CtfsReader, CtfsWriter};
 use codetracer_trace_format_cbor_zstd::HEADERV1;
-use codetracer_trace_types::TraceLowLevelEvent;
+use codetracer_trace_types::{TraceLowLevelEvent, TraceMetadata};
 use codetracer_trace_writer::ctfs_writer::EventSerializationFormat;
+use codetracer_trace_writer::source_snapshots::{SOURCES_DATA_FILE, SOURCES_INDEX_FILE, SourceSnapshots};
 use zeekstd::Decoder;
 
+use crate::call_tree::{StepIndexEntry, decode_step_index, encode_step_index};
+
+/// Step index added to a container by [`store_step_index`], in the layout
+/// of `call_tree::encode_step_index`.
+pub const STEP_INDEX_FILE: &str = "steps.idx";
+
 fn is_at_eof<R: BufRead>(reader: &mut R) -> std::io::Result<bool> {
     let buffer = reader.fill_buf()?;
     Ok(buffer.is_empty())
@@ -87,6 +94,51 @@ pub fn read_trace_from_ctfs(path: &std::path::Path) -> Result<Vec<TraceLowLevelE
     }
 }
 
+/// Read the source snapshots embedded in a CTFS container, or `None` if the
+/// trace was recorded without them.
+pub fn read_source_snapshots(path: &std::path::Path) -> Result<Option<SourceSnapshots>, Box<dyn std::error::Error>> {
+    let mut reader = CtfsReader::open(path)?;
+    if !reader.list_files().iter().any(|name| name == SOURCES_INDEX_FILE) {
+        return Ok(None);
+    }
+    let index = serde_json::from_slice(&reader.read_file(SOURCES_INDEX_FILE)?)?;
+    let data = reader.read_file(SOURCES_DATA_FILE)?;
+    Ok(Some(SourceSnapshots { index, data }))
+}
+
+/// Read the metadata of a CTFS container, or `None` if the recording
+/// didn't finish and has none yet.
+pub fn read_trace_metadata(path: &std::path::Path) -> Result<Option<TraceMetadata>, Box<dyn std::error::Error>> {
+    let mut reader = CtfsReader::open(path)?;
+    if reader.file_size("meta.json").unwrap_or(0) == 0 {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&reader.read_file("meta.json")?)?))
+}
+
+/// Add a step index (usually `CallTree::steps`) to a finished CTFS
+/// container. A container that already has one is left as it is: the index
+/// only depends on the events, which don't change once written.
+pub fn store_step_index(path: &std::path::Path, steps: &[StepIndexEntry]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = CtfsWriter::open_append(path)?;
+    if writer.find_file(STEP_INDEX_FILE).is_none() {
+        let handle = writer.add_file(STEP_INDEX_FILE)?;
+        writer.write(handle, &encode_step_index(steps))?;
+    }
+    writer.close()?;
+    Ok(())
+}
+
+/// Read the step index stored by [`store_step_index`], or `None` if the
+/// container has none.
+pub fn read_step_index(path: &std::path::Path) -> Result<Option<Vec<StepIndexEntry>>, Box<dyn std::error::Error>> {
+    let mut reader = CtfsReader::open(path)?;
+    if !reader.list_files().iter().any(|name| name == STEP_INDEX_FILE) {
+        return Ok(None);
+    }
+    Ok(Some(decode_step_index(&reader.read_file(STEP_INDEX_FILE)?)?))
+}
+
 /// Seek to a specific event range within a CTFS container.
 ///
 /// Decompresses only the chunk containing `target_event` and returns
@@ -135,3 +187,192 @@ pub fn seek_events_in_ctfs(path: &std::path::Path, target_event: usize, count: u
         }
     }
 }
+
+/// `events.log` past its header, read from the container as it is needed.
+struct EventsLog {
+    reader: CtfsReader,
+    size: u64,
+    position: u64,
+}
+
+impl EventsLog {
+    fn open(path: &std::path::Path) -> Result<(Self, EventSerializationFormat), Box<dyn std::error::Error>> {
+        let mut reader = CtfsReader::open(path)?;
+        let format = detect_format(&mut reader);
+        let size = reader.file_size("events.log").ok_or("CTFS events.log: missing")?;
+        let mut header = vec![0; HEADERV1.len()];
+        if reader.read_at("events.log", 0, &mut header)? < header.len() || header != HEADERV1 {
+            return Err("CTFS events.log: invalid or missing header".into());
+        }
+        let log = EventsLog {
+            reader,
+            size: size - HEADERV1.len() as u64,
+            position: 0,
+        };
+        Ok((log, format))
+    }
+
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), Box<dyn std::error::Error>> {
+        if self.reader.read_at("events.log", HEADERV1.len() as u64 + offset, buf)? < buf.len() {
+            return Err("CTFS events.log: unexpected end of data".into());
+        }
+        Ok(())
+    }
+}
+
+impl Read for EventsLog {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self
+            .reader
+            .read_at("events.log", HEADERV1.len() as u64 + self.position, buf)
+            .map_err(std::io::Error::other)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for EventsLog {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "seek before the start of events.log"))?;
+        Ok(self.position)
+    }
+}
+
+/// Events per decoded window of a container written before chunking.
+const STREAM_WINDOW: u64 = 4096;
+
+enum EventsSource {
+    // the chunk headers and where each chunk's compressed data starts
+    Chunked {
+        log: EventsLog,
+        chunks: Vec<(ChunkIndexEntry, u64)>,
+    },
+    // a zeekstd stream, which can only be decoded from its start; the
+    // stream and the GEID of its next event
+    Stream {
+        path: std::path::PathBuf,
+        stream: Option<(BufReader<Decoder<'static, EventsLog>>, u64)>,
+    },
+}
+
+/// Random access to the events of a CTFS container, one chunk at a time.
+///
+/// Opening reads only the chunk headers of `events.log`;
+/// [`ChunkedEvents::event`] reads and decompresses the chunk holding the
+/// requested GEID and keeps it until an event of another chunk is asked
+/// for, so walking the trace in either direction decodes every chunk once.
+/// Containers written before chunking have no index to seek with: their
+/// stream is decoded from the start, a few thousand events at a time, and
+/// counting their events on opening reads it through once.
+pub struct ChunkedEvents {
+    format: EventSerializationFormat,
+    source: EventsSource,
+    event_count: u64,
+    // the decoded chunk: its first GEID and its events
+    chunk: Option<(u64, Vec<TraceLowLevelEvent>)>,
+}
+
+impl ChunkedEvents {
+    pub fn open(path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let (mut log, format) = EventsLog::open(path)?;
+
+        let mut chunks = vec![];
+        let mut offset = 0;
+        let mut header = [0; CHUNK_INDEX_ENTRY_SIZE];
+        while offset + CHUNK_INDEX_ENTRY_SIZE as u64 <= log.size {
+            log.read_exact_at(offset, &mut header)?;
+            let entry = ChunkedReader::read_header_at(&header, 0)?;
+            let data_offset = offset + CHUNK_INDEX_ENTRY_SIZE as u64;
+            let next = data_offset + entry.compressed_size as u64;
+            if next > log.size {
+                break;
+            }
+            chunks.push((entry, data_offset));
+            offset = next;
+        }
+
+        if chunks.is_empty() {
+            let mut stream = BufReader::new(Decoder::new(log)?);
+            let mut event_count = 0;
+            while !is_at_eof(&mut stream)? {
+                cbor4ii::serde::from_reader::<TraceLowLevelEvent, _>(&mut stream)?;
+                event_count += 1;
+            }
+            return Ok(ChunkedEvents {
+                format,
+                source: EventsSource::Stream {
+                    path: path.to_path_buf(),
+                    stream: None,
+                },
+                event_count,
+                chunk: None,
+            });
+        }
+        let event_count = chunks.iter().map(|(header, _)| header.event_count as u64).sum();
+        Ok(ChunkedEvents {
+            format,
+            source: EventsSource::Chunked { log, chunks },
+            event_count,
+            chunk: None,
+        })
+    }
+
+    pub fn event_count(&self) -> u64 {
+        self.event_count
+    }
+
+    /// The event `geid`, decoding its chunk if it isn't the current one.
+    pub fn event(&mut self, geid: u64) -> Result<&TraceLowLevelEvent, Box<dyn std::error::Error>> {
+        if geid >= self.event_count {
+            return Err(format!("GEID {geid} is past the end of the trace ({} events)", self.event_count).into());
+        }
+        let cached = matches!(&self.chunk, Some((first_geid, events)) if (*first_geid..*first_geid + events.len() as u64).contains(&geid));
+        if !cached {
+            // free the old chunk before decoding the next one
+            self.chunk = None;
+            self.chunk = Some(match &mut self.source {
+                EventsSource::Chunked { log, chunks } => {
+                    let index = chunks.partition_point(|(header, _)| header.first_geid <= geid);
+                    let (header, data_offset) = *index
+                        .checked_sub(1)
+                        .and_then(|index| chunks.get(index))
+                        .ok_or_else(|| format!("no chunk contains GEID {geid}"))?;
+                    let mut compressed = vec![0; header.compressed_size as usize];
+                    log.read_exact_at(data_offset, &mut compressed)?;
+                    let chunk_data = zstd::decode_all(compressed.as_slice())?;
+                    let events = match self.format {
+                        EventSerializationFormat::SplitBinary => codetracer_trace_writer::split_binary::decode_events(&chunk_data),
+                        EventSerializationFormat::Cbor => deserialize_cbor(&chunk_data)?,
+                    };
+                    (header.first_geid, events)
+                }
+                EventsSource::Stream { path, stream } => {
+                    let first_geid = geid - geid % STREAM_WINDOW;
+                    if stream.as_ref().is_none_or(|(_, next_geid)| *next_geid > first_geid) {
+                        let (log, _) = EventsLog::open(path)?;
+                        *stream = Some((BufReader::new(Decoder::new(log)?), 0));
+                    }
+                    let (stream, next_geid) = stream.as_mut().ok_or("no stream opened")?;
+                    let mut events = vec![];
+                    while *next_geid < (first_geid + STREAM_WINDOW).min(self.event_count) {
+                        let event = cbor4ii::serde::from_reader::<TraceLowLevelEvent, _>(&mut *stream)?;
+                        if *next_geid >= first_geid {
+                            events.push(event);
+                        }
+                        *next_geid += 1;
+                    }
+                    (first_geid, events)
+                }
+            });
+        }
+        let (first_geid, events) = self.chunk.as_ref().ok_or("no chunk decoded")?;
+        events
+            .get((geid - first_geid) as usize)
+            .ok_or_else(|| format!("chunk at GEID {first_geid} ends before GEID {geid}").into())
+    }
+}
@@ -1,4 +1,17 @@
+pub mod call_stack;
+pub mod call_tree;
+pub mod chrome_trace;
+pub mod coverage;
+pub mod memory;
+pub mod object_graph;
+pub mod profile;
+pub mod query;
+pub mod replay;
+pub mod trace_diff;
 pub mod trace_readers;
+pub mod trace_slice;
+pub mod validator;
+pub mod variable_history;
 
 #[cfg(target_arch = "wasm32")]
 #[path = "./cbor_zstd_reader_wasm.rs"]
@@ -0,0 +1,193 @@
+//! Reader-side sparse memory model.
+//!
+//! [`MemoryModel`] is fed the events of a trace together with their GEID
+//! (global event id, the event's index in the trace) and keeps the history
+//! of every `MemoryWrite`, so it can answer what the bytes at an address
+//! were at any point of the recording without the recorder ever taking a
+//! full snapshot.
+//!
+//! The model only knows what was written during the recording. Bytes that
+//! were never written are reported as unknown (`None`); for memory mapped
+//! from a binary the caller can fall back to the file contents described by
+//! `codetracer_ctfs::mmap_info::MmapTable`.
+//!
+//! `Alloc` and `Free` only track which heap blocks are live: freeing a block
+//! does not forget its last contents, just as the process' memory doesn't.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use codetracer_trace_types::TraceLowLevelEvent;
+
+// writes are indexed by the pages they touch, so a query only looks at the
+// writes that can overlap it
+const PAGE_SIZE: u64 = 4096;
+
+#[derive(Debug, Default)]
+pub struct MemoryModel {
+    writes: Vec<Write>,
+    // page number -> indices into `writes`, in GEID order
+    pages: HashMap<u64, Vec<usize>>,
+    allocations: Vec<Allocation>,
+    // address -> index into `allocations` of the block currently live there
+    live: HashMap<u64, usize>,
+}
+
+#[derive(Debug)]
+struct Write {
+    geid: u64,
+    address: u64,
+    bytes: Vec<u8>,
+}
+
+/// A heap block and the GEIDs of the events that allocated and freed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    pub address: u64,
+    pub size: u64,
+    pub allocated_at: u64,
+    pub freed_at: Option<u64>,
+}
+
+impl Allocation {
+    fn contains(&self, address: u64) -> bool {
+        address >= self.address && address - self.address < self.size.max(1)
+    }
+
+    fn live_at(&self, geid: u64) -> bool {
+        self.allocated_at <= geid && self.freed_at.is_none_or(|freed_at| freed_at > geid)
+    }
+}
+
+impl MemoryModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the model for a whole trace; the GEID of each event is its index.
+    pub fn from_events(events: &[TraceLowLevelEvent]) -> Self {
+        let mut model = Self::new();
+        for (geid, event) in events.iter().enumerate() {
+            model.observe(geid as u64, event);
+        }
+        model
+    }
+
+    /// Feed the next event of the trace. GEIDs must not decrease.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        match event {
+            TraceLowLevelEvent::MemoryWrite(write) => {
+                debug_assert!(self.writes.last().is_none_or(|last| last.geid <= geid));
+                if write.bytes.is_empty() {
+                    return;
+                }
+                let index = self.writes.len();
+                for page in pages(write.address, write.bytes.len() as u64) {
+                    self.pages.entry(page).or_default().push(index);
+                }
+                self.writes.push(Write {
+                    geid,
+                    address: write.address,
+                    bytes: write.bytes.clone(),
+                });
+            }
+            TraceLowLevelEvent::Alloc(alloc) => {
+                self.live.insert(alloc.address, self.allocations.len());
+                self.allocations.push(Allocation {
+                    address: alloc.address,
+                    size: alloc.size,
+                    allocated_at: geid,
+                    freed_at: None,
+                });
+            }
+            TraceLowLevelEvent::Free(free) => {
+                if let Some(index) = self.live.remove(&free.address) {
+                    self.allocations[index].freed_at = Some(geid);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The `len` bytes starting at `address` as they were once the event
+    /// `geid` had happened; `None` for bytes not written up to that point.
+    pub fn bytes_at(&self, address: u64, len: usize, geid: u64) -> Vec<Option<u8>> {
+        let mut result = vec![None; len];
+        let mut missing = len;
+        let query = address..address.saturating_add(len as u64);
+        for page in pages(address, len as u64) {
+            let Some(indices) = self.pages.get(&page) else {
+                continue;
+            };
+            let page_range = intersect(&query, &(page * PAGE_SIZE..(page + 1).saturating_mul(PAGE_SIZE)));
+            let mut page_missing = (page_range.end - page_range.start) as usize;
+            // newest first: the first write to cover a byte is its value
+            let visible = indices.partition_point(|&index| self.writes[index].geid <= geid);
+            for &index in indices[..visible].iter().rev() {
+                let write = &self.writes[index];
+                let overlap = intersect(&page_range, &(write.address..write.address.saturating_add(write.bytes.len() as u64)));
+                for byte_address in overlap {
+                    let slot = &mut result[(byte_address - address) as usize];
+                    if slot.is_none() {
+                        *slot = Some(write.bytes[(byte_address - write.address) as usize]);
+                        page_missing -= 1;
+                        missing -= 1;
+                    }
+                }
+                if page_missing == 0 {
+                    break;
+                }
+            }
+            if missing == 0 {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Like [`bytes_at`](Self::bytes_at), but only if every byte is known.
+    pub fn read(&self, address: u64, len: usize, geid: u64) -> Option<Vec<u8>> {
+        self.bytes_at(address, len, geid).into_iter().collect()
+    }
+
+    /// The heap block containing `address` that was live at `geid`.
+    pub fn allocation_at(&self, address: u64, geid: u64) -> Option<&Allocation> {
+        self.allocations
+            .iter()
+            .rev()
+            .find(|allocation| allocation.contains(address) && allocation.live_at(geid))
+    }
+
+    /// Every heap block recorded so far, in allocation order.
+    pub fn allocations(&self) -> &[Allocation] {
+        &self.allocations
+    }
+
+    /// GEIDs of the writes that changed any byte of `address..address + len`.
+    pub fn writes_to(&self, address: u64, len: usize) -> Vec<u64> {
+        let query = address..address.saturating_add(len as u64);
+        let mut indices: Vec<usize> = pages(address, len as u64)
+            .filter_map(|page| self.pages.get(&page))
+            .flatten()
+            .copied()
+            .filter(|&index| {
+                let write = &self.writes[index];
+                !intersect(&query, &(write.address..write.address.saturating_add(write.bytes.len() as u64))).is_empty()
+            })
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices.into_iter().map(|index| self.writes[index].geid).collect()
+    }
+}
+
+fn pages(address: u64, len: u64) -> impl Iterator<Item = u64> {
+    let first = address / PAGE_SIZE;
+    let last = if len == 0 { first } else { address.saturating_add(len - 1) / PAGE_SIZE };
+    first..=last
+}
+
+fn intersect(a: &Range<u64>, b: &Range<u64>) -> Range<u64> {
+    let start = a.start.max(b.start);
+    start..a.end.min(b.end).max(start)
+}
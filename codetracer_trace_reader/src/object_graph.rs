@@ -0,0 +1,161 @@
+//! Reader-side resolution of shared objects.
+//!
+//! Writers record a shared heap object once as `ValueRecord::Object` and
+//! refer back to it with `ValueRecord::BackRef` (see
+//! `codetracer_trace_writer::object_identity`). [`ObjectGraph`] is fed the
+//! events in trace order and keeps one node per object: the object's body,
+//! with nested object definitions replaced by back-references, so every
+//! variable that reaches the object shares the same `Arc`.
+//!
+//! Object definitions are only valid until the next `Step`, matching the
+//! writer, which restarts its identity table there.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use codetracer_trace_types::{NONE_TYPE_ID, ObjectId, TraceLowLevelEvent, ValueRecord};
+
+#[derive(Debug, Default)]
+pub struct ObjectGraph {
+    objects: HashMap<ObjectId, Arc<ValueRecord>>,
+}
+
+impl ObjectGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, event: &TraceLowLevelEvent) {
+        match event {
+            TraceLowLevelEvent::Step(_) => self.objects.clear(),
+            TraceLowLevelEvent::Value(full_value) => self.register(&full_value.value),
+            TraceLowLevelEvent::Call(call) => {
+                for arg in &call.args {
+                    self.register(&arg.value);
+                }
+            }
+            TraceLowLevelEvent::Return(ret) => self.register(&ret.return_value),
+            TraceLowLevelEvent::CompoundValue(record) => self.register(&record.value),
+            TraceLowLevelEvent::CellValue(record) => self.register(&record.value),
+            TraceLowLevelEvent::AssignCell(record) => self.register(&record.new_value),
+            _ => {}
+        }
+    }
+
+    /// Record every object definition found in `value`.
+    pub fn register(&mut self, value: &ValueRecord) {
+        self.flatten(value);
+    }
+
+    /// The shared node for `id`, if it was defined since the last step.
+    pub fn get(&self, id: ObjectId) -> Option<Arc<ValueRecord>> {
+        self.objects.get(&id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Expand `value` into a plain tree: `Object` wrappers are removed and
+    /// back-references are replaced by the object they point to. A
+    /// back-reference to an object that encloses it (a cycle), or to an
+    /// object that is not known, is left as a `BackRef`.
+    pub fn resolve(&self, value: &ValueRecord) -> ValueRecord {
+        let mut active = HashSet::new();
+        self.expand(value, &mut active)
+    }
+
+    /// Register nested definitions and return `value` with every `Object`
+    /// replaced by a back-reference to its node.
+    fn flatten(&mut self, value: &ValueRecord) -> ValueRecord {
+        match value {
+            ValueRecord::Object { id, value } => {
+                let type_id = value.type_id().unwrap_or(NONE_TYPE_ID);
+                let body = self.flatten(value);
+                self.objects.insert(*id, Arc::new(body));
+                ValueRecord::BackRef { id: *id, type_id }
+            }
+            other => map_children(other, &mut |child| self.flatten(child)),
+        }
+    }
+
+    fn expand(&self, value: &ValueRecord, active: &mut HashSet<ObjectId>) -> ValueRecord {
+        match value {
+            ValueRecord::Object { id, value } => self.expand_object(*id, value, active),
+            ValueRecord::BackRef { id, .. } if !active.contains(id) => match self.objects.get(id) {
+                Some(body) => self.expand_object(*id, body, active),
+                None => value.clone(),
+            },
+            other => map_children(other, &mut |child| self.expand(child, active)),
+        }
+    }
+
+    fn expand_object(&self, id: ObjectId, body: &ValueRecord, active: &mut HashSet<ObjectId>) -> ValueRecord {
+        active.insert(id);
+        let expanded = self.expand(body, active);
+        active.remove(&id);
+        expanded
+    }
+}
+
+/// Rebuild `value` with `f` applied to each direct child value.
+fn map_children(value: &ValueRecord, f: &mut impl FnMut(&ValueRecord) -> ValueRecord) -> ValueRecord {
+    match value {
+        ValueRecord::Sequence { elements, is_slice, type_id } => ValueRecord::Sequence {
+            elements: elements.iter().map(&mut *f).collect(),
+            is_slice: *is_slice,
+            type_id: *type_id,
+        },
+        ValueRecord::Tuple { elements, type_id } => ValueRecord::Tuple {
+            elements: elements.iter().map(&mut *f).collect(),
+            type_id: *type_id,
+        },
+        ValueRecord::Struct { field_values, type_id } => ValueRecord::Struct {
+            field_values: field_values.iter().map(&mut *f).collect(),
+            type_id: *type_id,
+        },
+        ValueRecord::Map { entries, type_id } => ValueRecord::Map {
+            entries: entries.iter().map(|(key, value)| (f(key), f(value))).collect(),
+            type_id: *type_id,
+        },
+        ValueRecord::Variant {
+            discriminator,
+            contents,
+            type_id,
+        } => ValueRecord::Variant {
+            discriminator: discriminator.clone(),
+            contents: Box::new(f(contents)),
+            type_id: *type_id,
+        },
+        ValueRecord::Reference {
+            dereferenced,
+            address,
+            mutable,
+            type_id,
+        } => ValueRecord::Reference {
+            dereferenced: Box::new(f(dereferenced)),
+            address: *address,
+            mutable: *mutable,
+            type_id: *type_id,
+        },
+        ValueRecord::Elided {
+            partial,
+            original_len,
+            depth_cutoff,
+        } => ValueRecord::Elided {
+            partial: Box::new(f(partial)),
+            original_len: *original_len,
+            depth_cutoff: *depth_cutoff,
+        },
+        ValueRecord::Object { id, value } => ValueRecord::Object {
+            id: *id,
+            value: Box::new(f(value)),
+        },
+        other => other.clone(),
+    }
+}
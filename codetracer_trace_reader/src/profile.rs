@@ -0,0 +1,271 @@
+//! Step-count profiles of a recorded run.
+//!
+//! A trace has no clock, but its `Call`/`Return` structure and `Step`s make
+//! an exact and deterministic profile: [`Profile`] counts every step against
+//! the stack it ran on. Stacks are kept per thread (selected by
+//! `ThreadSwitch`) and per async task, as in `CallStack`; as in `Coverage`,
+//! the entry step a writer emits before a `Call` runs in the callee and a
+//! `DropLastStep` takes its step back.
+//!
+//! The counts can be written as Brendan Gregg's folded stacks
+//! ([`Profile::to_folded`]), the input of `flamegraph.pl` and inferno, or as
+//! an uncompressed pprof protobuf ([`Profile::to_pprof`]).
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use codetracer_trace_types::{StepRecord, ThreadId, TraceLowLevelEvent};
+
+use crate::call_stack::CallStack;
+
+// the steps of one stack, by thread and line of the innermost frame
+type StackSamples = HashMap<(ThreadId, i64), u64>;
+
+#[derive(Debug, Default)]
+pub struct Profile {
+    paths: Vec<PathBuf>,
+    // name, path id and line of each function
+    functions: Vec<(String, usize, i64)>,
+    // by the function ids of the stack, outermost first
+    samples: HashMap<Vec<usize>, StackSamples>,
+    steps: u64,
+    call_stack: CallStack,
+    pending_step: Option<StepRecord>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Profile a whole trace.
+    pub fn from_events(events: &[TraceLowLevelEvent]) -> Self {
+        let mut profile = Self::new();
+        for event in events {
+            profile.observe(event);
+        }
+        profile.finish();
+        profile
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, event: &TraceLowLevelEvent) {
+        if let Some(step) = self.pending_step.take() {
+            match event {
+                TraceLowLevelEvent::Call(call) => {
+                    let mut stack = self.stack();
+                    stack.push(call.function_id.0);
+                    self.count(stack, &step);
+                }
+                TraceLowLevelEvent::DropLastStep => self.steps -= 1,
+                _ => self.count(self.stack(), &step),
+            }
+        }
+        self.call_stack.observe(event);
+
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.clone()),
+            TraceLowLevelEvent::Function(function) => self.functions.push((function.name.clone(), function.path_id.0, function.line.0)),
+            TraceLowLevelEvent::Step(step) => {
+                self.steps += 1;
+                self.pending_step = Some(*step);
+            }
+            _ => {}
+        }
+    }
+
+    /// Count the last step of the trace; call once all events are in.
+    pub fn finish(&mut self) {
+        if let Some(step) = self.pending_step.take() {
+            self.count(self.stack(), &step);
+        }
+    }
+
+    /// The number of steps profiled.
+    pub fn total_steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Folded stacks, one `thread-<id>;outer;...;inner <steps>` line per
+    /// distinct stack, sorted.
+    pub fn to_folded(&self) -> String {
+        let mut folded: BTreeMap<String, u64> = BTreeMap::new();
+        for (stack, samples) in &self.samples {
+            let names: Vec<&str> = stack.iter().map(|function_id| self.function_name(*function_id)).collect();
+            for ((thread_id, _), steps) in samples {
+                let mut line = format!("thread-{}", thread_id.0);
+                for name in &names {
+                    line.push(';');
+                    // `;` separates the frames and the last space the count
+                    line.push_str(&name.replace(';', ":").replace(' ', "_"));
+                }
+                *folded.entry(line).or_default() += steps;
+            }
+        }
+        let mut text = String::new();
+        for (line, steps) in folded {
+            _ = writeln!(text, "{line} {steps}");
+        }
+        text
+    }
+
+    /// A pprof profile with a `steps` sample type. Each sample is a stack of
+    /// one thread, whose innermost location is the line of its steps and
+    /// whose outer ones are the callers' definitions; the thread is a
+    /// `thread` label.
+    pub fn to_pprof(&self) -> Vec<u8> {
+        let mut strings = StringTable::default();
+        strings.index("");
+        let mut profile = vec![];
+
+        let steps = strings.index("steps");
+        let count = strings.index("count");
+        let thread = strings.index("thread");
+        let value_type = message(|fields| {
+            varint_field(fields, 1, steps);
+            varint_field(fields, 2, count);
+        });
+        bytes_field(&mut profile, 1, &value_type);
+
+        // by (function id, line), numbered from 1
+        let mut locations: BTreeMap<(usize, i64), u64> = BTreeMap::new();
+        let mut samples: Vec<_> = self.samples.iter().collect();
+        samples.sort_by(|a, b| a.0.cmp(b.0));
+        for (stack, stack_samples) in samples {
+            let mut stack_samples: Vec<_> = stack_samples.iter().collect();
+            stack_samples.sort_by_key(|(key, _)| **key);
+            for ((thread_id, line), steps) in stack_samples {
+                let mut location_ids = vec![];
+                for (depth, function_id) in stack.iter().enumerate().rev() {
+                    let line = if depth + 1 == stack.len() {
+                        *line
+                    } else {
+                        self.functions.get(*function_id).map_or(0, |(_, _, line)| *line)
+                    };
+                    let next_id = locations.len() as u64 + 1;
+                    location_ids.push(*locations.entry((*function_id, line)).or_insert(next_id));
+                }
+                let sample = message(|fields| {
+                    packed_field(fields, 1, &location_ids);
+                    packed_field(fields, 2, &[*steps]);
+                    let label = message(|label| {
+                        varint_field(label, 1, thread);
+                        varint_field(label, 3, thread_id.0);
+                    });
+                    bytes_field(fields, 3, &label);
+                });
+                bytes_field(&mut profile, 2, &sample);
+            }
+        }
+
+        let mut location_list: Vec<_> = locations.into_iter().collect();
+        location_list.sort_by_key(|(_, id)| *id);
+        let mut used_functions = vec![];
+        for ((function_id, line), id) in location_list {
+            let location = message(|fields| {
+                varint_field(fields, 1, id);
+                let line = message(|line_fields| {
+                    varint_field(line_fields, 1, function_id as u64 + 1);
+                    varint_field(line_fields, 2, line as u64);
+                });
+                bytes_field(fields, 4, &line);
+            });
+            bytes_field(&mut profile, 4, &location);
+            if !used_functions.contains(&function_id) {
+                used_functions.push(function_id);
+            }
+        }
+
+        used_functions.sort();
+        for function_id in used_functions {
+            let name = strings.index(self.function_name(function_id));
+            let (path, start_line) = match self.functions.get(function_id) {
+                Some((_, path_id, line)) => (self.paths.get(*path_id).map(|path| path.display().to_string()), *line),
+                None => (None, 0),
+            };
+            let filename = strings.index(path.as_deref().unwrap_or(""));
+            let function = message(|fields| {
+                varint_field(fields, 1, function_id as u64 + 1);
+                varint_field(fields, 2, name);
+                varint_field(fields, 3, name);
+                varint_field(fields, 4, filename);
+                varint_field(fields, 5, start_line as u64);
+            });
+            bytes_field(&mut profile, 5, &function);
+        }
+
+        for string in &strings.strings {
+            bytes_field(&mut profile, 6, string.as_bytes());
+        }
+        bytes_field(&mut profile, 11, &value_type);
+        varint_field(&mut profile, 12, 1);
+        profile
+    }
+
+    fn stack(&self) -> Vec<usize> {
+        self.call_stack.frames().iter().map(|function_id| function_id.0).collect()
+    }
+
+    fn count(&mut self, stack: Vec<usize>, step: &StepRecord) {
+        let key = (self.call_stack.current_thread(), step.line.0);
+        *self.samples.entry(stack).or_default().entry(key).or_default() += 1;
+    }
+
+    fn function_name(&self, function_id: usize) -> &str {
+        self.functions.get(function_id).map_or("<unknown>", |(name, _, _)| name.as_str())
+    }
+}
+
+// protobuf encoding, just what pprof needs
+
+#[derive(Default)]
+struct StringTable {
+    strings: Vec<String>,
+    indices: HashMap<String, u64>,
+}
+
+impl StringTable {
+    fn index(&mut self, string: &str) -> u64 {
+        if let Some(index) = self.indices.get(string) {
+            return *index;
+        }
+        let index = self.strings.len() as u64;
+        self.strings.push(string.to_string());
+        self.indices.insert(string.to_string(), index);
+        index
+    }
+}
+
+fn message(build: impl FnOnce(&mut Vec<u8>)) -> Vec<u8> {
+    let mut fields = vec![];
+    build(&mut fields);
+    fields
+}
+
+fn varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn varint_field(out: &mut Vec<u8>, field: u64, value: u64) {
+    varint(out, field << 3);
+    varint(out, value);
+}
+
+fn bytes_field(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    varint(out, (field << 3) | 2);
+    varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn packed_field(out: &mut Vec<u8>, field: u64, values: &[u64]) {
+    let mut packed = vec![];
+    for value in values {
+        varint(&mut packed, *value);
+    }
+    bytes_field(out, field, &packed);
+}
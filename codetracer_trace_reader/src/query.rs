@@ -0,0 +1,746 @@
+//! A small query language over trace events.
+//!
+//! A query selects event kinds and filters them with a boolean expression:
+//!
+//! ```text
+//! step where path = "foo.py" and line = 42 and n > 10
+//! return where function = parse and value.kind = Error
+//! call, return where thread != 0
+//! * where geid >= 1000 and geid < 2000
+//! ```
+//!
+//! The kinds are the event variant names, case-insensitive (`step`, `call`,
+//! `value`, `event`, ...); `*` or no kind list at all selects every event.
+//! Conditions compare an operand with a literal using `=`, `!=`, `<`,
+//! `<=`, `>`, `>=` or `~` (contains) and combine with `and`, `or`, `not`
+//! and parentheses. Operands are:
+//!
+//! - `path`, `line`: where the event happened. For a `Call` that is the
+//!   callee's definition, for events other than `Step` the last step.
+//!   `path = "foo.py"` also matches any path ending in `/foo.py`, and
+//!   `path != "foo.py"` matches neither
+//! - `function`: the function of the call the event happened in (for a
+//!   `Call`, the callee)
+//! - `thread`, `geid`, `kind`, `step` (the `StepId`) and `call` (the
+//!   `CallKey`)
+//! - `value`: the value of a `Value`, the return value of a `Return` or the
+//!   exception of a `Throw` or `Catch`
+//! - `content` and `metadata` of an `Event`
+//! - any other name is a variable of the current call, `var.<name>`
+//!   names a variable that clashes with one of the fields above
+//!
+//! Values (`value` and variables) compare as numbers, strings or booleans
+//! depending on their kind; `.kind` gives the kind of a value (`Int`,
+//! `Error`, ...) and `.type` the name of its type. A literal is a number, a
+//! double-quoted string or a bare word, which is a string unless it is
+//! `true` or `false`. A comparison with something the event doesn't have
+//! is false.
+//!
+//! [`QueryEvaluator`] runs a query over events fed in trace order. The
+//! values of a step are only complete once the events registering them
+//! have been seen, so `Step` matches are reported a few events late; the
+//! matches still come out in GEID order.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::PathBuf;
+
+use codetracer_trace_types::{CallKey, NO_KEY, StepId, ThreadId, TraceLowLevelEvent, ValueRecord};
+
+use crate::variable_history::VariableHistory;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryError {
+    pub message: String,
+    /// Byte offset in the query text.
+    pub position: usize,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// Lowercase event kinds; empty for every event.
+    pub kinds: Vec<String>,
+    pub condition: Option<Expr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Operand, Op, Literal),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Path,
+    Line,
+    Function,
+    Thread,
+    Geid,
+    Kind,
+    Step,
+    Call,
+    Content,
+    Metadata,
+    Value,
+    Variable(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueProperty {
+    Itself,
+    Kind,
+    Type,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operand {
+    pub field: Field,
+    /// Only used for `value` and variables.
+    pub property: ValueProperty,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Query, QueryError> {
+        Parser::new(text)?.query()
+    }
+
+    fn selects(&self, kind_name: &str) -> bool {
+        self.kinds.is_empty() || self.kinds.iter().any(|kind| kind.eq_ignore_ascii_case(kind_name))
+    }
+}
+
+// parsing
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Number(String),
+    Op(Op),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    Star,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    end: usize,
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, QueryError> {
+    let bytes = text.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i] as char;
+        let two = text.get(i..i + 2).unwrap_or("");
+        let token = match c {
+            _ if c.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '.' => Token::Dot,
+            '*' => Token::Star,
+            '~' => Token::Op(Op::Contains),
+            _ if two == "==" => Token::Op(Op::Eq),
+            _ if two == "!=" => Token::Op(Op::Ne),
+            _ if two == "<=" => Token::Op(Op::Le),
+            _ if two == ">=" => Token::Op(Op::Ge),
+            '=' => Token::Op(Op::Eq),
+            '<' => Token::Op(Op::Lt),
+            '>' => Token::Op(Op::Gt),
+            '"' => {
+                let mut value = String::new();
+                let mut chars = text[i + 1..].char_indices();
+                loop {
+                    match chars.next() {
+                        Some((offset, '"')) => {
+                            i += offset + 2;
+                            break;
+                        }
+                        Some((_, '\\')) if let Some((_, escaped)) = chars.next() => value.push(escaped),
+                        Some((_, other)) => value.push(other),
+                        None => {
+                            return Err(QueryError {
+                                message: "unterminated string".to_string(),
+                                position: start,
+                            });
+                        }
+                    }
+                }
+                tokens.push((Token::Str(value), start));
+                continue;
+            }
+            _ if c.is_ascii_digit() || (c == '-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)) => {
+                i += 1;
+                while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                    i += 1;
+                }
+                tokens.push((Token::Number(text[start..i].to_string()), start));
+                continue;
+            }
+            _ if c.is_ascii_alphabetic() || c == '_' || !c.is_ascii() => {
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || !bytes[i].is_ascii()) {
+                    i += 1;
+                }
+                tokens.push((Token::Word(text[start..i].to_string()), start));
+                continue;
+            }
+            _ => {
+                return Err(QueryError {
+                    message: format!("unexpected character '{c}'"),
+                    position: start,
+                });
+            }
+        };
+        i += if matches!(two, "==" | "!=" | "<=" | ">=") { 2 } else { 1 };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+impl Parser {
+    fn new(text: &str) -> Result<Self, QueryError> {
+        Ok(Parser {
+            tokens: tokenize(text)?,
+            index: 0,
+            end: text.len(),
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.index).map_or(self.end, |(_, position)| *position)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, QueryError> {
+        Err(QueryError {
+            message: message.into(),
+            position: self.position(),
+        })
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).map(|(token, _)| token.clone());
+        self.index += 1;
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn query(&mut self) -> Result<Query, QueryError> {
+        let mut kinds = vec![];
+        if self.peek() == Some(&Token::Star) {
+            self.next();
+        } else {
+            while !self.peek_keyword("where") {
+                match self.next() {
+                    Some(Token::Word(kind)) => kinds.push(kind.to_ascii_lowercase()),
+                    None => break,
+                    Some(_) => {
+                        self.index -= 1;
+                        return self.error("expected an event kind");
+                    }
+                }
+                if self.peek() == Some(&Token::Comma) {
+                    self.next();
+                } else {
+                    break;
+                }
+            }
+        }
+        let condition = if self.peek_keyword("where") {
+            self.next();
+            Some(self.or()?)
+        } else {
+            None
+        };
+        if self.peek().is_some() {
+            return self.error("unexpected input");
+        }
+        Ok(Query { kinds, condition })
+    }
+
+    fn or(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.and()?;
+        while self.peek_keyword("or") {
+            self.next();
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.not()?;
+        while self.peek_keyword("and") {
+            self.next();
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, QueryError> {
+        if self.peek_keyword("not") {
+            self.next();
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        if self.peek() == Some(&Token::LParen) {
+            self.next();
+            let expr = self.or()?;
+            if self.next() != Some(Token::RParen) {
+                self.index -= 1;
+                return self.error("expected ')'");
+            }
+            return Ok(expr);
+        }
+        let operand = self.operand()?;
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            _ => {
+                self.index -= 1;
+                return self.error("expected a comparison operator");
+            }
+        };
+        let literal = self.literal()?;
+        Ok(Expr::Compare(operand, op, literal))
+    }
+
+    fn operand(&mut self) -> Result<Operand, QueryError> {
+        let Some(Token::Word(name)) = self.next() else {
+            self.index -= 1;
+            return self.error("expected a field or variable name");
+        };
+        let field = match name.as_str() {
+            "path" => Field::Path,
+            "line" => Field::Line,
+            "function" => Field::Function,
+            "thread" => Field::Thread,
+            "geid" => Field::Geid,
+            "kind" => Field::Kind,
+            "step" => Field::Step,
+            "call" => Field::Call,
+            "content" => Field::Content,
+            "metadata" => Field::Metadata,
+            "value" => Field::Value,
+            "var" if self.peek() == Some(&Token::Dot) => {
+                self.next();
+                match self.next() {
+                    Some(Token::Word(variable)) => Field::Variable(variable),
+                    _ => {
+                        self.index -= 1;
+                        return self.error("expected a variable name");
+                    }
+                }
+            }
+            _ => Field::Variable(name),
+        };
+        let mut property = ValueProperty::Itself;
+        if matches!(field, Field::Value | Field::Variable(_)) && self.peek() == Some(&Token::Dot) {
+            self.next();
+            property = match self.next() {
+                Some(Token::Word(word)) if word == "kind" => ValueProperty::Kind,
+                Some(Token::Word(word)) if word == "type" => ValueProperty::Type,
+                _ => {
+                    self.index -= 1;
+                    return self.error("expected 'kind' or 'type'");
+                }
+            };
+        }
+        Ok(Operand { field, property })
+    }
+
+    fn literal(&mut self) -> Result<Literal, QueryError> {
+        match self.next() {
+            Some(Token::Str(text)) => Ok(Literal::Str(text)),
+            Some(Token::Word(word)) if word == "true" => Ok(Literal::Bool(true)),
+            Some(Token::Word(word)) if word == "false" => Ok(Literal::Bool(false)),
+            Some(Token::Word(word)) => Ok(Literal::Str(word)),
+            Some(Token::Number(number)) => {
+                if let Ok(int) = number.parse::<i128>() {
+                    Ok(Literal::Int(int))
+                } else if let Ok(float) = number.parse::<f64>() {
+                    Ok(Literal::Float(float))
+                } else {
+                    self.index -= 1;
+                    self.error(format!("invalid number '{number}'"))
+                }
+            }
+            _ => {
+                self.index -= 1;
+                self.error("expected a literal")
+            }
+        }
+    }
+}
+
+// evaluation
+
+/// An event matching a query, with the context the query saw it in.
+#[derive(Debug, Clone)]
+pub struct QueryMatch {
+    pub geid: u64,
+    pub path: Option<PathBuf>,
+    pub line: Option<i64>,
+    pub function: Option<String>,
+    pub thread_id: ThreadId,
+    pub call_key: CallKey,
+    /// The current step; the step itself for a `Step`.
+    pub step_id: Option<StepId>,
+    pub event: TraceLowLevelEvent,
+}
+
+// a comparable view of an operand
+#[derive(Debug, Clone, PartialEq)]
+enum Scalar {
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+#[derive(Debug)]
+enum Queued {
+    // a selected step, evaluated once its values are in; `until` is the
+    // last event before the next step
+    Step { found: QueryMatch, until: Option<u64> },
+    Ready(QueryMatch),
+}
+
+#[derive(Debug)]
+pub struct QueryEvaluator {
+    query: Query,
+    history: VariableHistory,
+    paths: Vec<PathBuf>,
+    // name, path id and line of each function
+    functions: Vec<(String, usize, i64)>,
+    type_names: Vec<String>,
+    location: Option<(usize, i64)>,
+    queue: VecDeque<Queued>,
+}
+
+impl QueryEvaluator {
+    pub fn new(query: Query) -> Self {
+        QueryEvaluator {
+            query,
+            history: VariableHistory::new(),
+            paths: vec![],
+            functions: vec![],
+            type_names: vec![],
+            location: None,
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Run `query` over a whole trace; the GEID of each event is its index.
+    pub fn run(query: Query, events: &[TraceLowLevelEvent]) -> Vec<QueryMatch> {
+        let mut evaluator = Self::new(query);
+        let mut matches = vec![];
+        for (geid, event) in events.iter().enumerate() {
+            matches.extend(evaluator.observe(geid as u64, event));
+        }
+        matches.extend(evaluator.finish());
+        matches
+    }
+
+    /// Feed the next event of the trace and get the matches it completed,
+    /// in GEID order.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) -> Vec<QueryMatch> {
+        let selected = self.query.selects(event.kind_name());
+        // a `Return` is evaluated in the call it leaves
+        let mut context = if selected && !matches!(event, TraceLowLevelEvent::Call(_) | TraceLowLevelEvent::Step(_)) {
+            Some(self.context(geid, event))
+        } else {
+            None
+        };
+
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.clone()),
+            TraceLowLevelEvent::Function(function) => self.functions.push((function.name.clone(), function.path_id.0, function.line.0)),
+            TraceLowLevelEvent::Type(type_record) => self.type_names.push(type_record.lang_type.clone()),
+            TraceLowLevelEvent::Step(step) => self.location = Some((step.path_id.0, step.line.0)),
+            _ => {}
+        }
+        self.history.observe(geid, event);
+
+        let mut matches = vec![];
+        match event {
+            // the values registered before a step can still turn out to be
+            // the arguments of a call, see `VariableHistory`
+            TraceLowLevelEvent::Value(_)
+            | TraceLowLevelEvent::VariableName(_)
+            | TraceLowLevelEvent::Variable(_)
+            | TraceLowLevelEvent::Type(_)
+            | TraceLowLevelEvent::Path(_)
+            | TraceLowLevelEvent::Function(_) => {}
+            TraceLowLevelEvent::Step(_) => {
+                for queued in &mut self.queue {
+                    if let Queued::Step { until: until @ None, .. } = queued {
+                        *until = Some(geid.saturating_sub(1));
+                    }
+                }
+                self.flush(geid, true, &mut matches)
+            }
+            // the arguments of a call are values of its entry step
+            TraceLowLevelEvent::Call(_) => self.flush(geid, false, &mut matches),
+            _ => self.flush(geid.saturating_sub(1), false, &mut matches),
+        }
+
+        if selected {
+            match event {
+                TraceLowLevelEvent::Step(_) => {
+                    let context = self.context(geid, event);
+                    self.queue.push_back(Queued::Step { found: context, until: None });
+                }
+                TraceLowLevelEvent::Call(_) => context = Some(self.context(geid, event)),
+                _ => {}
+            }
+            if let Some(context) = context
+                && self.matches(&context, geid)
+            {
+                if self.queue.is_empty() {
+                    matches.push(context);
+                } else {
+                    self.queue.push_back(Queued::Ready(context));
+                }
+            }
+        }
+        matches
+    }
+
+    /// The matches still held back at the end of the trace.
+    pub fn finish(&mut self) -> Vec<QueryMatch> {
+        let mut matches = vec![];
+        self.flush(u64::MAX, false, &mut matches);
+        matches
+    }
+
+    // evaluate the queued steps, except the last one when `keep_last_step`,
+    // and release everything before it; steps without an `until` see the
+    // values as of `geid`
+    fn flush(&mut self, geid: u64, keep_last_step: bool, matches: &mut Vec<QueryMatch>) {
+        let keep_from = if keep_last_step {
+            self.queue.iter().rposition(|queued| matches!(queued, Queued::Step { .. }))
+        } else {
+            None
+        }
+        .unwrap_or(self.queue.len());
+        for queued in self.queue.drain(..keep_from).collect::<Vec<_>>() {
+            match queued {
+                Queued::Ready(found) => matches.push(found),
+                Queued::Step { found: mut step, until } => {
+                    // an entry step belongs to the callee
+                    if let Some(step_id) = step.step_id {
+                        step.call_key = self.history.call_tree().call_of_step(step_id);
+                        step.function = self.function_of(step.call_key);
+                    }
+                    if self.matches(&step, until.unwrap_or(geid)) {
+                        matches.push(step);
+                    }
+                }
+            }
+        }
+    }
+
+    fn context(&self, geid: u64, event: &TraceLowLevelEvent) -> QueryMatch {
+        let call_tree = self.history.call_tree();
+        let call_key = call_tree.current_call();
+        let mut location = self.location;
+        if let TraceLowLevelEvent::Call(call) = event {
+            location = self.functions.get(call.function_id.0).map(|(_, path_id, line)| (*path_id, *line));
+        }
+        QueryMatch {
+            geid,
+            path: location.and_then(|(path_id, _)| self.paths.get(path_id).cloned()),
+            line: location.map(|(_, line)| line),
+            function: self.function_of(call_key),
+            thread_id: call_tree.current_thread(),
+            call_key,
+            step_id: call_tree.steps().len().checked_sub(1).map(|index| StepId(index as i64)),
+            event: event.clone(),
+        }
+    }
+
+    fn function_of(&self, call_key: CallKey) -> Option<String> {
+        let call = self.history.call_tree().call(call_key)?;
+        self.functions.get(call.function_id.0).map(|(name, _, _)| name.clone())
+    }
+
+    fn matches(&self, found: &QueryMatch, geid: u64) -> bool {
+        match &self.query.condition {
+            Some(condition) => self.eval(condition, found, geid),
+            None => true,
+        }
+    }
+
+    fn eval(&self, expr: &Expr, found: &QueryMatch, geid: u64) -> bool {
+        match expr {
+            Expr::And(left, right) => self.eval(left, found, geid) && self.eval(right, found, geid),
+            Expr::Or(left, right) => self.eval(left, found, geid) || self.eval(right, found, geid),
+            Expr::Not(inner) => !self.eval(inner, found, geid),
+            Expr::Compare(operand, op, literal) => {
+                if operand.field == Field::Path
+                    && let (Some(path), Op::Eq | Op::Ne, Literal::Str(expected)) = (&found.path, op, literal)
+                {
+                    let same = path.to_string_lossy() == *expected || path.ends_with(expected);
+                    return same == (*op == Op::Eq);
+                }
+                match self.operand(operand, found, geid) {
+                    Some(scalar) => compare(&scalar, *op, literal),
+                    None => false,
+                }
+            }
+        }
+    }
+
+    fn operand(&self, operand: &Operand, found: &QueryMatch, geid: u64) -> Option<Scalar> {
+        let value = match &operand.field {
+            Field::Path => return found.path.as_ref().map(|path| Scalar::Str(path.to_string_lossy().into_owned())),
+            Field::Line => return found.line.map(|line| Scalar::Int(line as i128)),
+            Field::Function => return found.function.clone().map(Scalar::Str),
+            Field::Thread => return Some(Scalar::Int(found.thread_id.0 as i128)),
+            Field::Geid => return Some(Scalar::Int(found.geid as i128)),
+            Field::Kind => return Some(Scalar::Str(found.event.kind_name().to_string())),
+            Field::Step => return found.step_id.map(|step_id| Scalar::Int(step_id.0 as i128)),
+            Field::Call => return (found.call_key != NO_KEY).then_some(Scalar::Int(found.call_key.0 as i128)),
+            Field::Content | Field::Metadata => {
+                let TraceLowLevelEvent::Event(record_event) = &found.event else {
+                    return None;
+                };
+                let text = if operand.field == Field::Content {
+                    &record_event.content
+                } else {
+                    &record_event.metadata
+                };
+                return Some(Scalar::Str(text.clone()));
+            }
+            Field::Value => match &found.event {
+                TraceLowLevelEvent::Value(full_value) => &full_value.value,
+                TraceLowLevelEvent::Return(ret) => &ret.return_value,
+                TraceLowLevelEvent::Throw(throw) => &throw.exception,
+                TraceLowLevelEvent::Catch(catch) => &catch.exception,
+                _ => return None,
+            },
+            Field::Variable(name) => {
+                let variable_id = self.history.variable_id(name)?;
+                self.history.value_at(variable_id, found.call_key, geid)?
+            }
+        };
+        let value = unwrap_value(value);
+        match operand.property {
+            ValueProperty::Itself => scalar_of(value),
+            ValueProperty::Kind => Some(Scalar::Str(value.kind_name().to_string())),
+            ValueProperty::Type => {
+                let type_id = value.type_id()?;
+                self.type_names.get(type_id.0).cloned().map(Scalar::Str)
+            }
+        }
+    }
+}
+
+// look through the wrappers that don't change what a value is
+fn unwrap_value(value: &ValueRecord) -> &ValueRecord {
+    match value {
+        ValueRecord::Reference { dereferenced, .. } => unwrap_value(dereferenced),
+        ValueRecord::Object { value, .. } => unwrap_value(value),
+        ValueRecord::Elided { partial, .. } => unwrap_value(partial),
+        _ => value,
+    }
+}
+
+fn scalar_of(value: &ValueRecord) -> Option<Scalar> {
+    Some(match value {
+        ValueRecord::Int { i, .. } => Scalar::Int(*i as i128),
+        ValueRecord::UInt { u, .. } => Scalar::Int(*u as i128),
+        ValueRecord::Int128 { i, .. } => Scalar::Int(*i),
+        ValueRecord::UInt128 { u, .. } => match i128::try_from(*u) {
+            Ok(u) => Scalar::Int(u),
+            Err(_) => Scalar::Float(*u as f64),
+        },
+        ValueRecord::Float { f, .. } => Scalar::Float(*f),
+        ValueRecord::Bool { b, .. } => Scalar::Bool(*b),
+        ValueRecord::String { text, .. } => Scalar::Str(text.clone()),
+        ValueRecord::Char { c, .. } => Scalar::Str(c.to_string()),
+        ValueRecord::Raw { r, .. } => Scalar::Str(r.clone()),
+        ValueRecord::Error { msg, .. } => Scalar::Str(msg.clone()),
+        _ => return None,
+    })
+}
+
+/// Compare a value with a literal the way a query condition on a variable
+/// does.
+pub(crate) fn value_matches(value: &ValueRecord, op: Op, literal: &Literal) -> bool {
+    scalar_of(unwrap_value(value)).is_some_and(|scalar| compare(&scalar, op, literal))
+}
+
+fn compare(scalar: &Scalar, op: Op, literal: &Literal) -> bool {
+    use std::cmp::Ordering;
+
+    let ordering = match (scalar, literal) {
+        (Scalar::Int(a), Literal::Int(b)) => Some(a.cmp(b)),
+        (Scalar::Int(a), Literal::Float(b)) => (*a as f64).partial_cmp(b),
+        (Scalar::Float(a), Literal::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (Scalar::Float(a), Literal::Float(b)) => a.partial_cmp(b),
+        (Scalar::Str(a), Literal::Str(b)) => {
+            if op == Op::Contains {
+                return a.contains(b.as_str());
+            }
+            Some(a.as_str().cmp(b.as_str()))
+        }
+        (Scalar::Bool(a), Literal::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    };
+    let Some(ordering) = ordering else {
+        return false;
+    };
+    match op {
+        Op::Eq => ordering == Ordering::Equal,
+        Op::Ne => ordering != Ordering::Equal,
+        Op::Lt => ordering == Ordering::Less,
+        Op::Le => ordering != Ordering::Greater,
+        Op::Gt => ordering == Ordering::Greater,
+        Op::Ge => ordering != Ordering::Less,
+        // only strings contain anything
+        Op::Contains => false,
+    }
+}
@@ -0,0 +1,471 @@
+//! Headless replay of a recorded trace.
+//!
+//! [`ReplayCursor`] is a position in a trace that moves the way a debugger
+//! does: forwards and backwards by step, over and out of calls, and on to
+//! the next or previous breakpoint or watchpoint hit. Positions are the
+//! steps of the step index (see `CallTree`); at each one the cursor gives
+//! the call stack and the locals of every frame on it.
+//!
+//! Creating a cursor reads the trace once, to build the call tree and the
+//! variable histories ([`VariableHistory`]). After that the events are only
+//! read again for the locations of steps, through an [`EventSource`]. For a
+//! CTFS container that is `ctfs_reader::ChunkedEvents`, which decompresses
+//! the one chunk holding the step, so in a chunked container moving
+//! backwards costs a chunk at most, never a replay from the start of the
+//! trace.
+//!
+//! The values of a step are the ones registered between it and the next
+//! step. A watchpoint hits at the step whose values change the watched
+//! variable, in whichever call it lives; its condition, if any, is checked
+//! against the new value. `step_in` and `reverse_step_in` always stop at
+//! the adjacent step, every other move stops early at a breakpoint or
+//! watchpoint hit.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use codetracer_trace_types::{CallKey, NO_KEY, ObjectId, StepId, ThreadId, TraceLowLevelEvent, TypeId, TypeRecord, ValueRecord, VariableId};
+
+use crate::object_graph::ObjectGraph;
+use crate::query::{Literal, Op, value_matches};
+use crate::variable_history::VariableHistory;
+
+/// Where a [`ReplayCursor`] reads the events of its trace from.
+pub trait EventSource {
+    fn event_count(&self) -> u64;
+
+    /// `None` past the end of the trace or when the event can't be read.
+    fn event(&mut self, geid: u64) -> Option<TraceLowLevelEvent>;
+}
+
+impl EventSource for Vec<TraceLowLevelEvent> {
+    fn event_count(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn event(&mut self, geid: u64) -> Option<TraceLowLevelEvent> {
+        usize::try_from(geid).ok().and_then(|index| self.get(index)).cloned()
+    }
+}
+
+impl<S: EventSource + ?Sized> EventSource for Box<S> {
+    fn event_count(&self) -> u64 {
+        (**self).event_count()
+    }
+
+    fn event(&mut self, geid: u64) -> Option<TraceLowLevelEvent> {
+        (**self).event(geid)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl EventSource for crate::ctfs_reader::ChunkedEvents {
+    fn event_count(&self) -> u64 {
+        crate::ctfs_reader::ChunkedEvents::event_count(self)
+    }
+
+    fn event(&mut self, geid: u64) -> Option<TraceLowLevelEvent> {
+        crate::ctfs_reader::ChunkedEvents::event(self, geid).ok().cloned()
+    }
+}
+
+/// Identifies a breakpoint or a watchpoint of a cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BreakpointId(pub usize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    /// Every step at `line` of `path`. `path` also matches the paths
+    /// ending in it, so `main.py` matches `/src/main.py`.
+    Line { path: PathBuf, line: i64 },
+    /// The entry step of every call of the function with this name.
+    Function(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watchpoint {
+    pub variable: String,
+    /// Only hit when the new value satisfies it, compared the way a query
+    /// compares a variable: `Some((Op::Gt, Literal::Int(10)))`.
+    pub condition: Option<(Op, Literal)>,
+}
+
+/// Why a move of the cursor stopped where it did.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    /// The move reached the step it was after.
+    Step,
+    Breakpoint(BreakpointId),
+    Watchpoint(BreakpointId),
+    /// The move ran past the last step and stopped there.
+    End,
+    /// The move ran back past the first step and stopped there.
+    Start,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub call_key: CallKey,
+    pub function: String,
+    /// The current step for the innermost frame, the step that made the
+    /// call above it for the others.
+    pub location: Option<Location>,
+}
+
+pub struct ReplayCursor<S = Vec<TraceLowLevelEvent>> {
+    source: S,
+    history: VariableHistory,
+    paths: Vec<PathBuf>,
+    function_names: Vec<String>,
+    types: Vec<TypeRecord>,
+    // every object of the trace: ids are never reused, so a value read
+    // back from an earlier step can still be resolved
+    objects: ObjectGraph,
+    event_count: u64,
+    // index in the step index; 0 for a trace without steps
+    position: usize,
+    breakpoints: Vec<(BreakpointId, Breakpoint)>,
+    watchpoints: Vec<(BreakpointId, Watchpoint)>,
+    next_id: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ReplayCursor<crate::ctfs_reader::ChunkedEvents> {
+    /// Open a `.ct` container, reading its events a chunk at a time.
+    pub fn open(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::new(crate::ctfs_reader::ChunkedEvents::open(path)?))
+    }
+}
+
+impl<S: EventSource> ReplayCursor<S> {
+    /// Index the trace of `source` and stop at its first step.
+    pub fn new(mut source: S) -> Self {
+        let mut history = VariableHistory::new();
+        let mut paths = vec![];
+        let mut function_names = vec![];
+        let mut types = vec![];
+        let mut objects = ObjectGraph::new();
+        let mut event_count = 0;
+        for geid in 0..source.event_count() {
+            let Some(event) = source.event(geid) else {
+                break;
+            };
+            match &event {
+                TraceLowLevelEvent::Path(path) => paths.push(path.clone()),
+                TraceLowLevelEvent::Function(function) => function_names.push(function.name.clone()),
+                TraceLowLevelEvent::Type(type_record) => types.push(type_record.clone()),
+                TraceLowLevelEvent::Step(_) => {}
+                _ => objects.observe(&event),
+            }
+            history.observe(geid, &event);
+            event_count += 1;
+        }
+        ReplayCursor {
+            source,
+            history,
+            paths,
+            function_names,
+            types,
+            objects,
+            event_count,
+            position: 0,
+            breakpoints: vec![],
+            watchpoints: vec![],
+            next_id: 0,
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> BreakpointId {
+        let id = self.new_id();
+        self.breakpoints.push((id, breakpoint));
+        id
+    }
+
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) -> BreakpointId {
+        let id = self.new_id();
+        self.watchpoints.push((id, watchpoint));
+        id
+    }
+
+    /// Remove a breakpoint or a watchpoint; false if there is none with
+    /// `id`.
+    pub fn remove_breakpoint(&mut self, id: BreakpointId) -> bool {
+        let count = self.breakpoints.len() + self.watchpoints.len();
+        self.breakpoints.retain(|(known, _)| *known != id);
+        self.watchpoints.retain(|(known, _)| *known != id);
+        count != self.breakpoints.len() + self.watchpoints.len()
+    }
+
+    /// Remove every breakpoint and watchpoint.
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+        self.watchpoints.clear();
+    }
+
+    /// The current step; `None` for a trace without steps.
+    pub fn step_id(&self) -> Option<StepId> {
+        (self.position < self.step_count()).then_some(StepId(self.position as i64))
+    }
+
+    /// GEID of the current step.
+    pub fn geid(&self) -> Option<u64> {
+        self.history.call_tree().steps().get(self.position).map(|step| step.geid)
+    }
+
+    /// The call of the current step; `NO_KEY` outside of any call.
+    pub fn call_key(&self) -> CallKey {
+        self.call_of(self.position)
+    }
+
+    /// The thread of the current step.
+    pub fn thread_id(&self) -> Option<ThreadId> {
+        self.history.call_tree().call(self.call_key()).map(|call| call.thread_id)
+    }
+
+    /// The variable histories and the call tree of the trace.
+    pub fn history(&self) -> &VariableHistory {
+        &self.history
+    }
+
+    pub fn type_record(&self, type_id: TypeId) -> Option<&TypeRecord> {
+        self.types.get(type_id.0)
+    }
+
+    /// The object a `ValueRecord::BackRef` with `id` points to, with the
+    /// objects nested in it left as back-references.
+    pub fn object(&self, id: ObjectId) -> Option<Arc<ValueRecord>> {
+        self.objects.get(id)
+    }
+
+    pub fn location(&mut self) -> Option<Location> {
+        self.step_location(self.position)
+    }
+
+    /// The call stack at the current step, innermost frame first.
+    pub fn stack(&mut self) -> Vec<Frame> {
+        if self.step_id().is_none() {
+            return vec![];
+        }
+        let mut frames = vec![];
+        let mut call_key = self.call_key();
+        let mut location = self.location();
+        while let Some(call) = self.history.call_tree().call(call_key) {
+            let (function_id, parent_key, call_geid) = (call.function_id, call.parent_key, call.call_geid);
+            let function = self
+                .function_names
+                .get(function_id.0)
+                .cloned()
+                .unwrap_or_else(|| format!("<function {}>", function_id.0));
+            frames.push(Frame {
+                call_key,
+                function,
+                location,
+            });
+            location = self.call_site(parent_key, call_geid).and_then(|step| self.step_location(step));
+            call_key = parent_key;
+        }
+        frames
+    }
+
+    /// The variables of the current call with their values at the current
+    /// step.
+    pub fn locals(&self) -> Vec<(String, ValueRecord)> {
+        self.frame_locals(self.call_key())
+    }
+
+    /// The variables of `call_key`, one of the calls on the stack, with
+    /// their values at the current step.
+    pub fn frame_locals(&self, call_key: CallKey) -> Vec<(String, ValueRecord)> {
+        if self.step_id().is_none() {
+            return vec![];
+        }
+        let geid = self.values_end(self.position);
+        (0..)
+            .map(VariableId)
+            .map_while(|variable_id| self.history.variable_name(variable_id).map(|name| (variable_id, name)))
+            .filter_map(|(variable_id, name)| {
+                let value = self.history.value_at(variable_id, call_key, geid)?;
+                Some((name.to_string(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// Move to the next step, entering calls.
+    pub fn step_in(&mut self) -> StopReason {
+        self.run(true, |_, _| true)
+    }
+
+    /// Move to the next step of the current call, or of a caller once it
+    /// has returned.
+    pub fn step_over(&mut self) -> StopReason {
+        let call_key = self.call_key();
+        self.run(true, |cursor, step| {
+            let step_call = cursor.call_of(step);
+            step_call == call_key || cursor.is_caller(step_call, call_key)
+        })
+    }
+
+    /// Move to the first step after the current call has returned.
+    pub fn step_out(&mut self) -> StopReason {
+        let call_key = self.call_key();
+        self.run(true, |cursor, step| cursor.is_caller(cursor.call_of(step), call_key))
+    }
+
+    /// Move to the previous step, entering the calls it finished.
+    pub fn reverse_step_in(&mut self) -> StopReason {
+        self.run(false, |_, _| true)
+    }
+
+    /// Move to the previous step of the current call, or of the caller
+    /// before it was called.
+    pub fn reverse_step(&mut self) -> StopReason {
+        let call_key = self.call_key();
+        self.run(false, |cursor, step| {
+            let step_call = cursor.call_of(step);
+            step_call == call_key || cursor.is_caller(step_call, call_key)
+        })
+    }
+
+    /// Move back to the step that made the current call.
+    pub fn reverse_step_out(&mut self) -> StopReason {
+        let call_key = self.call_key();
+        self.run(false, |cursor, step| cursor.is_caller(cursor.call_of(step), call_key))
+    }
+
+    /// Move forwards to the next breakpoint or watchpoint hit.
+    pub fn continue_forward(&mut self) -> StopReason {
+        self.run(true, |_, _| false)
+    }
+
+    /// Move backwards to the previous breakpoint or watchpoint hit.
+    pub fn reverse_continue(&mut self) -> StopReason {
+        self.run(false, |_, _| false)
+    }
+
+    fn new_id(&mut self) -> BreakpointId {
+        self.next_id += 1;
+        BreakpointId(self.next_id)
+    }
+
+    fn step_count(&self) -> usize {
+        self.history.call_tree().steps().len()
+    }
+
+    fn call_of(&self, step: usize) -> CallKey {
+        self.history.call_tree().call_of_step(StepId(step as i64))
+    }
+
+    // whether `caller` is on the stack below `call_key`
+    fn is_caller(&self, caller: CallKey, call_key: CallKey) -> bool {
+        let call_tree = self.history.call_tree();
+        let mut current = call_tree.call(call_key).map_or(NO_KEY, |call| call.parent_key);
+        while current != NO_KEY {
+            if current == caller {
+                return true;
+            }
+            current = call_tree.call(current).map_or(NO_KEY, |call| call.parent_key);
+        }
+        false
+    }
+
+    // the last step of `caller` before the call at `call_geid`
+    fn call_site(&self, caller: CallKey, call_geid: u64) -> Option<usize> {
+        let caller_geid = self.history.call_tree().call(caller)?.call_geid;
+        let steps = self.history.call_tree().steps();
+        let before = steps.partition_point(|step| step.geid < call_geid);
+        steps[..before]
+            .iter()
+            .rposition(|step| step.call_key == caller || step.geid < caller_geid)
+            .filter(|&index| steps[index].call_key == caller)
+    }
+
+    // GEID of the last event registering the values of `step`
+    fn values_end(&self, step: usize) -> u64 {
+        match self.history.call_tree().steps().get(step + 1) {
+            Some(next) => next.geid - 1,
+            None => self.event_count.saturating_sub(1),
+        }
+    }
+
+    fn step_location(&mut self, step: usize) -> Option<Location> {
+        let geid = self.history.call_tree().steps().get(step)?.geid;
+        let TraceLowLevelEvent::Step(step) = self.source.event(geid)? else {
+            return None;
+        };
+        Some(Location {
+            path: self.paths.get(step.path_id.0)?.clone(),
+            line: step.line.0,
+        })
+    }
+
+    fn run(&mut self, forward: bool, stop_at: impl Fn(&Self, usize) -> bool) -> StopReason {
+        loop {
+            if forward && self.position + 1 >= self.step_count() {
+                return StopReason::End;
+            }
+            if !forward && self.position == 0 {
+                return StopReason::Start;
+            }
+            self.position = if forward { self.position + 1 } else { self.position - 1 };
+            if let Some(reason) = self.hit(self.position) {
+                return reason;
+            }
+            if stop_at(self, self.position) {
+                return StopReason::Step;
+            }
+        }
+    }
+
+    // the first breakpoint or watchpoint hit at `step`
+    fn hit(&mut self, step: usize) -> Option<StopReason> {
+        let call_key = self.call_of(step);
+        let location = if self
+            .breakpoints
+            .iter()
+            .any(|(_, breakpoint)| matches!(breakpoint, Breakpoint::Line { .. }))
+        {
+            self.step_location(step)
+        } else {
+            None
+        };
+        let call_tree = self.history.call_tree();
+        for (id, breakpoint) in &self.breakpoints {
+            let hit = match breakpoint {
+                Breakpoint::Line { path, line } => location
+                    .as_ref()
+                    .is_some_and(|location| location.line == *line && (location.path == *path || location.path.ends_with(path))),
+                Breakpoint::Function(name) => call_tree
+                    .call(call_key)
+                    .is_some_and(|call| call.first_step == Some(StepId(step as i64)) && self.function_names.get(call.function_id.0) == Some(name)),
+            };
+            if hit {
+                return Some(StopReason::Breakpoint(*id));
+            }
+        }
+
+        let step_id = Some(StepId(step as i64));
+        for (id, watchpoint) in &self.watchpoints {
+            let timeline = self.history.timeline_by_name(&watchpoint.variable);
+            let first = timeline.partition_point(|change| change.step_id < step_id);
+            let hit =
+                timeline[first..]
+                    .iter()
+                    .take_while(|change| change.step_id == step_id)
+                    .any(|change| match (&watchpoint.condition, &change.value) {
+                        (None, _) => true,
+                        (Some((op, literal)), Some(value)) => value_matches(value, *op, literal),
+                        (Some(_), None) => false,
+                    });
+            if hit {
+                return Some(StopReason::Watchpoint(*id));
+            }
+        }
+        None
+    }
+}
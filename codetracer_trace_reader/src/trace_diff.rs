@@ -0,0 +1,406 @@
+//! Diffing two recordings of the same program.
+//!
+//! Two traces recorded independently intern their paths, functions, types
+//! and variable names in whatever order the recorder met them, so their
+//! events can't be compared directly. [`DiffNormalizer`] turns the events
+//! of one trace into [`DiffEntry`]s, which name everything instead: a step
+//! is a path and a line, a value is a variable name and a rendering of the
+//! value with its type names, a call is a function name and its arguments.
+//! Definitions, memory and other bookkeeping events don't produce entries.
+//!
+//! [`TraceDiff::compute`] walks the entries of both traces in lockstep, so
+//! they stay aligned by call structure and steps as long as the executions
+//! agree, and reports the first entry where they don't, together with the
+//! call stack and the last step both traces shared. It then reads both
+//! traces to the end to compare how often each function was called.
+//!
+//! Addresses (of references and cells) and object ids are left out of the
+//! renderings, as they differ between runs of the same program.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use codetracer_trace_types::{EventLogKind, FullValueRecord, ThreadId, TraceLowLevelEvent, ValueRecord};
+
+use crate::call_stack::CallStack;
+
+/// A trace event with its interned ids replaced by what they name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffEntry {
+    /// `thread` counts the threads in the order they first appeared.
+    Step {
+        thread: usize,
+        path: String,
+        line: i64,
+    },
+    Value {
+        name: String,
+        value: String,
+    },
+    Call {
+        function: String,
+        args: Vec<(String, String)>,
+    },
+    Return {
+        value: String,
+    },
+    Throw {
+        exception: String,
+    },
+    Catch {
+        exception: String,
+    },
+    Event {
+        kind: EventLogKind,
+        content: String,
+    },
+}
+
+impl fmt::Display for DiffEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffEntry::Step { thread, path, line } => write!(f, "step {path}:{line} (thread {thread})"),
+            DiffEntry::Value { name, value } => write!(f, "value {name} = {value}"),
+            DiffEntry::Call { function, args } => {
+                let args: Vec<String> = args.iter().map(|(name, value)| format!("{name}={value}")).collect();
+                write!(f, "call {function}({})", args.join(", "))
+            }
+            DiffEntry::Return { value } => write!(f, "return {value}"),
+            DiffEntry::Throw { exception } => write!(f, "throw {exception}"),
+            DiffEntry::Catch { exception } => write!(f, "catch {exception}"),
+            DiffEntry::Event { kind, content } => write!(f, "event {kind:?}: {content}"),
+        }
+    }
+}
+
+/// Turns the events of one trace into [`DiffEntry`]s.
+#[derive(Debug, Default)]
+pub struct DiffNormalizer {
+    paths: Vec<String>,
+    functions: Vec<String>,
+    types: Vec<String>,
+    variables: Vec<String>,
+    threads: Vec<ThreadId>,
+    call_stack: CallStack,
+    call_counts: BTreeMap<String, usize>,
+}
+
+impl DiffNormalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next event of the trace; returns its entry, if it has one.
+    pub fn observe(&mut self, event: &TraceLowLevelEvent) -> Option<DiffEntry> {
+        self.call_stack.observe(event);
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.display().to_string()),
+            TraceLowLevelEvent::Function(function) => self.functions.push(function.name.clone()),
+            TraceLowLevelEvent::Type(type_record) => self.types.push(type_record.lang_type.clone()),
+            TraceLowLevelEvent::VariableName(name) | TraceLowLevelEvent::Variable(name) => self.variables.push(name.clone()),
+            TraceLowLevelEvent::ThreadStart(thread_id) | TraceLowLevelEvent::ThreadSwitch(thread_id) => {
+                self.thread_index(*thread_id);
+            }
+            TraceLowLevelEvent::Step(step) => {
+                return Some(DiffEntry::Step {
+                    thread: self.thread_index(self.call_stack.current_thread()),
+                    path: self.name(&self.paths, step.path_id.0),
+                    line: step.line.0,
+                });
+            }
+            TraceLowLevelEvent::Value(full_value) => return Some(self.value_entry(full_value)),
+            TraceLowLevelEvent::Call(call) => {
+                let function = self.name(&self.functions, call.function_id.0);
+                *self.call_counts.entry(function.clone()).or_default() += 1;
+                let args = call
+                    .args
+                    .iter()
+                    .map(|arg| (self.name(&self.variables, arg.variable_id.0), self.render(&arg.value)))
+                    .collect();
+                return Some(DiffEntry::Call { function, args });
+            }
+            TraceLowLevelEvent::Return(ret) => {
+                return Some(DiffEntry::Return {
+                    value: self.render(&ret.return_value),
+                });
+            }
+            TraceLowLevelEvent::Throw(throw) => {
+                return Some(DiffEntry::Throw {
+                    exception: self.render(&throw.exception),
+                });
+            }
+            TraceLowLevelEvent::Catch(catch) => {
+                return Some(DiffEntry::Catch {
+                    exception: self.render(&catch.exception),
+                });
+            }
+            TraceLowLevelEvent::Event(record_event) => {
+                return Some(DiffEntry::Event {
+                    kind: record_event.kind,
+                    content: record_event.content.clone(),
+                });
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// The function names on the current stack, outermost first.
+    pub fn stack(&self) -> Vec<String> {
+        self.call_stack
+            .frames()
+            .iter()
+            .map(|function_id| self.name(&self.functions, function_id.0))
+            .collect()
+    }
+
+    /// The calls seen so far, by function name.
+    pub fn call_counts(&self) -> &BTreeMap<String, usize> {
+        &self.call_counts
+    }
+
+    /// Render a value with type names instead of type ids.
+    pub fn render(&self, value: &ValueRecord) -> String {
+        let mut text = String::new();
+        self.render_into(value, &mut text);
+        text
+    }
+
+    fn value_entry(&self, full_value: &FullValueRecord) -> DiffEntry {
+        DiffEntry::Value {
+            name: self.name(&self.variables, full_value.variable_id.0),
+            value: self.render(&full_value.value),
+        }
+    }
+
+    fn thread_index(&mut self, thread_id: ThreadId) -> usize {
+        match self.threads.iter().position(|known| *known == thread_id) {
+            Some(index) => index,
+            None => {
+                self.threads.push(thread_id);
+                self.threads.len() - 1
+            }
+        }
+    }
+
+    // an undefined id still has to compare equal to the same undefined id
+    fn name(&self, names: &[String], id: usize) -> String {
+        names.get(id).cloned().unwrap_or_else(|| format!("<undefined {id}>"))
+    }
+
+    fn render_list(&self, open: &str, values: &[ValueRecord], close: &str, text: &mut String) {
+        text.push_str(open);
+        for (index, value) in values.iter().enumerate() {
+            if index > 0 {
+                text.push_str(", ");
+            }
+            self.render_into(value, text);
+        }
+        text.push_str(close);
+    }
+
+    fn render_into(&self, value: &ValueRecord, text: &mut String) {
+        use std::fmt::Write;
+
+        let type_name = |type_id: &codetracer_trace_types::TypeId| self.name(&self.types, type_id.0);
+        match value {
+            ValueRecord::Int { i, type_id } => _ = write!(text, "{i}: {}", type_name(type_id)),
+            ValueRecord::UInt { u, type_id } => _ = write!(text, "{u}: {}", type_name(type_id)),
+            ValueRecord::Int128 { i, type_id } => _ = write!(text, "{i}: {}", type_name(type_id)),
+            ValueRecord::UInt128 { u, type_id } => _ = write!(text, "{u}: {}", type_name(type_id)),
+            ValueRecord::Float { f, type_id } => _ = write!(text, "{f:?}: {}", type_name(type_id)),
+            ValueRecord::Bool { b, type_id } => _ = write!(text, "{b}: {}", type_name(type_id)),
+            ValueRecord::String { text: string, type_id } => _ = write!(text, "{string:?}: {}", type_name(type_id)),
+            ValueRecord::Char { c, type_id } => _ = write!(text, "{c:?}: {}", type_name(type_id)),
+            ValueRecord::Raw { r, type_id } => _ = write!(text, "{r}: {}", type_name(type_id)),
+            ValueRecord::Error { msg, type_id } => _ = write!(text, "{} error {msg:?}", type_name(type_id)),
+            ValueRecord::None { type_id } => _ = write!(text, "{} none", type_name(type_id)),
+            ValueRecord::BigInt { b, negative, type_id } => {
+                let digits: String = b.iter().map(|byte| format!("{byte:02x}")).collect();
+                let sign = if *negative { "-" } else { "" };
+                _ = write!(text, "{sign}0x{digits}: {}", type_name(type_id));
+            }
+            ValueRecord::Bytes { data, type_id } => {
+                let digits: String = data.iter().map(|byte| format!("{byte:02x}")).collect();
+                _ = write!(text, "bytes {digits}: {}", type_name(type_id));
+            }
+            ValueRecord::Sequence { elements, type_id, .. } => {
+                text.push_str(&type_name(type_id));
+                self.render_list("[", elements, "]", text);
+            }
+            ValueRecord::Tuple { elements, type_id } => {
+                text.push_str(&type_name(type_id));
+                self.render_list("(", elements, ")", text);
+            }
+            ValueRecord::Struct { field_values, type_id } => {
+                text.push_str(&type_name(type_id));
+                self.render_list("{", field_values, "}", text);
+            }
+            ValueRecord::Map { entries, type_id } => {
+                text.push_str(&type_name(type_id));
+                text.push('{');
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        text.push_str(", ");
+                    }
+                    self.render_into(key, text);
+                    text.push_str(" => ");
+                    self.render_into(value, text);
+                }
+                text.push('}');
+            }
+            ValueRecord::Variant {
+                discriminator,
+                contents,
+                type_id,
+            } => {
+                _ = write!(text, "{}::{discriminator}(", type_name(type_id));
+                self.render_into(contents, text);
+                text.push(')');
+            }
+            ValueRecord::Reference { dereferenced, mutable, .. } => {
+                text.push_str(if *mutable { "&mut " } else { "&" });
+                self.render_into(dereferenced, text);
+            }
+            ValueRecord::Cell { .. } => text.push_str("<cell>"),
+            ValueRecord::Elided { partial, original_len, .. } => {
+                self.render_into(partial, text);
+                _ = write!(text, " (elided from {original_len})");
+            }
+            ValueRecord::Object { value, .. } => self.render_into(value, text),
+            ValueRecord::BackRef { type_id, .. } => _ = write!(text, "<shared {}>", type_name(type_id)),
+        }
+    }
+}
+
+/// A function called a different number of times in the two traces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallCountDiff {
+    pub function: String,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// Where two traces stopped agreeing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// The differing entries; `None` for a trace that ended there.
+    pub left: Option<DiffEntry>,
+    pub right: Option<DiffEntry>,
+    pub left_geid: Option<u64>,
+    pub right_geid: Option<u64>,
+    /// The path and line of the last step both traces made.
+    pub last_step: Option<(String, i64)>,
+    /// The function names on the stack both traces were in.
+    pub stack: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceDiff {
+    /// The entries the traces agreed on, from the start.
+    pub matching_entries: usize,
+    /// The steps among them.
+    pub matching_steps: usize,
+    pub divergence: Option<Divergence>,
+    /// By function name.
+    pub call_counts: Vec<CallCountDiff>,
+}
+
+impl TraceDiff {
+    pub fn compute(left: &[TraceLowLevelEvent], right: &[TraceLowLevelEvent]) -> Self {
+        Self::compute_streams(left.iter(), right.iter())
+    }
+
+    /// Diff two event streams, each in trace order.
+    pub fn compute_streams<'a>(
+        left: impl IntoIterator<Item = &'a TraceLowLevelEvent>,
+        right: impl IntoIterator<Item = &'a TraceLowLevelEvent>,
+    ) -> Self {
+        let mut left_normalizer = DiffNormalizer::new();
+        let mut right_normalizer = DiffNormalizer::new();
+        let mut left_events = left.into_iter().enumerate();
+        let mut right_events = right.into_iter().enumerate();
+        let mut matching_entries = 0;
+        let mut matching_steps = 0;
+        let mut divergence = None;
+        // what both traces agree on so far
+        let mut last_step = None;
+        let mut stack = vec![];
+        let mut thread = 0;
+
+        while divergence.is_none() {
+            let left_entry = next_entry(&mut left_events, &mut left_normalizer);
+            let right_entry = next_entry(&mut right_events, &mut right_normalizer);
+            match (left_entry, right_entry) {
+                (None, None) => break,
+                (Some((_, left_entry)), Some((_, right_entry))) if left_entry == right_entry => {
+                    matching_entries += 1;
+                    let mut switched = false;
+                    if let DiffEntry::Step {
+                        thread: step_thread,
+                        path,
+                        line,
+                    } = left_entry
+                    {
+                        matching_steps += 1;
+                        last_step = Some((path, line));
+                        switched = step_thread != thread;
+                        thread = step_thread;
+                    }
+                    if switched || left_normalizer.call_stack.depth() != stack.len() {
+                        stack = left_normalizer.stack();
+                    }
+                }
+                (left_entry, right_entry) => {
+                    divergence = Some(Divergence {
+                        left_geid: left_entry.as_ref().map(|(geid, _)| *geid),
+                        right_geid: right_entry.as_ref().map(|(geid, _)| *geid),
+                        left: left_entry.map(|(_, entry)| entry),
+                        right: right_entry.map(|(_, entry)| entry),
+                        last_step: last_step.clone(),
+                        stack: stack.clone(),
+                    });
+                }
+            }
+        }
+
+        // the rest only matters for the call counts
+        for (_, event) in left_events {
+            left_normalizer.observe(event);
+        }
+        for (_, event) in right_events {
+            right_normalizer.observe(event);
+        }
+        let mut functions: Vec<&String> = left_normalizer
+            .call_counts()
+            .keys()
+            .chain(right_normalizer.call_counts().keys())
+            .collect();
+        functions.sort();
+        functions.dedup();
+        let call_counts = functions
+            .into_iter()
+            .map(|function| CallCountDiff {
+                function: function.clone(),
+                left: left_normalizer.call_counts().get(function).copied().unwrap_or(0),
+                right: right_normalizer.call_counts().get(function).copied().unwrap_or(0),
+            })
+            .filter(|counts| counts.left != counts.right)
+            .collect();
+
+        TraceDiff {
+            matching_entries,
+            matching_steps,
+            divergence,
+            call_counts,
+        }
+    }
+
+    pub fn is_equivalent(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+fn next_entry<'a>(events: &mut impl Iterator<Item = (usize, &'a TraceLowLevelEvent)>, normalizer: &mut DiffNormalizer) -> Option<(u64, DiffEntry)> {
+    events.find_map(|(geid, event)| normalizer.observe(event).map(|entry| (geid as u64, entry)))
+}
@@ -0,0 +1,574 @@
+//! Cutting a GEID range out of a trace.
+//!
+//! [`TraceSlicer`] keeps the events `[from, to)` of a trace and turns them
+//! into a trace of their own, one a reader can load without the rest:
+//!
+//! - the `Path`, `Function`, `Type` and `VariableName` definitions the
+//!   slice uses, wherever they were in the original, come first. Ids are
+//!   positions in these lists, so they are renumbered, keeping their order;
+//!   the toplevel function and the `None` type keep id 0
+//! - synthetic `Call`s then reopen the calls that were open at `from`, in
+//!   every thread and async task, with their original arguments, so the
+//!   slice's `Return`s have calls to leave. The thread and task that were
+//!   active at `from` are active again when the slice's own events start
+//! - when the slice doesn't start with a `Step`, the last step of the active
+//!   thread or task is repeated, so its first events have a location. As
+//!   that step ends the scope of the objects defined before `from`, the
+//!   first back-reference to each of them becomes its definition again
+//!
+//! The slicer has to see every event before `to` to know the definitions
+//! and the open calls, but only keeps the definitions and the slice itself.
+//! For a chunked CTFS container, [`slice_ctfs`] reads the events a chunk at
+//! a time and doesn't read anything past `to`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+
+use codetracer_trace_types::{
+    CallRecord, FunctionId, FunctionRecord, NONE_TYPE_ID, ObjectId, PathId, RValue, StepRecord, TOP_LEVEL_FUNCTION_ID, TaskId, ThreadId,
+    TraceLowLevelEvent, TypeId, TypeRecord, TypeSpecificInfo, ValueRecord, VariableId,
+};
+
+use crate::call_stack::CallStack;
+use crate::object_graph::ObjectGraph;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Context {
+    Thread(ThreadId),
+    Task(TaskId),
+}
+
+// a reference to an interned id inside an event
+enum IdMut<'a> {
+    Path(&'a mut PathId),
+    Function(&'a mut FunctionId),
+    Type(&'a mut TypeId),
+    Variable(&'a mut VariableId),
+}
+
+// the ids used by the slice, by kind
+#[derive(Debug, Default)]
+struct References {
+    paths: BTreeSet<usize>,
+    functions: BTreeSet<usize>,
+    types: BTreeSet<usize>,
+    variables: BTreeSet<usize>,
+}
+
+impl References {
+    fn add(&mut self, id: IdMut) {
+        match id {
+            IdMut::Path(path_id) => self.paths.insert(path_id.0),
+            IdMut::Function(function_id) => self.functions.insert(function_id.0),
+            IdMut::Type(type_id) => self.types.insert(type_id.0),
+            IdMut::Variable(variable_id) => self.variables.insert(variable_id.0),
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct TraceSlicer {
+    from: u64,
+    to: u64,
+    paths: Vec<PathBuf>,
+    functions: Vec<FunctionRecord>,
+    types: Vec<TypeRecord>,
+    variables: Vec<String>,
+    // the state at `from`
+    call_stack: CallStack,
+    open: BTreeMap<Context, Vec<CallRecord>>,
+    running: BTreeMap<ThreadId, Vec<TaskId>>,
+    started: BTreeSet<ThreadId>,
+    last_steps: HashMap<Context, StepRecord>,
+    objects: ObjectGraph,
+    events: Vec<TraceLowLevelEvent>,
+}
+
+impl TraceSlicer {
+    /// A slicer keeping the events with `from <= geid < to`.
+    pub fn new(from: u64, to: u64) -> Self {
+        TraceSlicer {
+            from,
+            to,
+            paths: vec![],
+            functions: vec![],
+            types: vec![],
+            variables: vec![],
+            call_stack: CallStack::new(),
+            open: BTreeMap::new(),
+            running: BTreeMap::new(),
+            started: BTreeSet::new(),
+            last_steps: HashMap::new(),
+            objects: ObjectGraph::new(),
+            events: vec![],
+        }
+    }
+
+    /// Slice a whole trace; the GEID of each event is its index.
+    pub fn slice(events: &[TraceLowLevelEvent], from: u64, to: u64) -> Vec<TraceLowLevelEvent> {
+        let mut slicer = Self::new(from, to);
+        for (geid, event) in events.iter().enumerate().take(usize::try_from(to).unwrap_or(usize::MAX)) {
+            slicer.observe(geid as u64, event);
+        }
+        slicer.finish()
+    }
+
+    /// Feed the next event of the trace. Events from `to` on are ignored.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        if geid >= self.to {
+            return;
+        }
+        match event {
+            TraceLowLevelEvent::Path(path) => self.paths.push(path.clone()),
+            TraceLowLevelEvent::Function(function) => self.functions.push(function.clone()),
+            TraceLowLevelEvent::Type(type_record) => self.types.push(type_record.clone()),
+            TraceLowLevelEvent::VariableName(name) | TraceLowLevelEvent::Variable(name) => self.variables.push(name.clone()),
+            _ if geid >= self.from => self.events.push(event.clone()),
+            _ => self.follow(event),
+        }
+    }
+
+    /// The slice: the definitions it uses, the calls open at `from`, then
+    /// its own events.
+    pub fn finish(self) -> Vec<TraceLowLevelEvent> {
+        let mut prelude = self.prelude();
+        let mut events = self.events;
+
+        // until the slice's own first step, it can refer back to objects
+        // defined before `from`
+        let mut defined = HashSet::new();
+        let mut objects = Some(&self.objects);
+        let own_events = prelude.len();
+        for (index, event) in prelude.iter_mut().chain(events.iter_mut()).enumerate() {
+            if matches!(event, TraceLowLevelEvent::Step(_)) {
+                defined.clear();
+                if index >= own_events {
+                    objects = None;
+                }
+            }
+            let Some(objects) = objects else {
+                break;
+            };
+            visit_event_values(event, &mut |value| define_objects(value, objects, &mut defined));
+        }
+
+        let mut references = References::default();
+        for event in prelude.iter_mut().chain(events.iter_mut()) {
+            visit_event(event, &mut |id| references.add(id));
+        }
+        if !self.functions.is_empty() {
+            references.functions.insert(TOP_LEVEL_FUNCTION_ID.0);
+        }
+        if !self.types.is_empty() {
+            references.types.insert(NONE_TYPE_ID.0);
+        }
+        references.functions.retain(|function_id| *function_id < self.functions.len());
+        for function_id in &references.functions {
+            references.paths.insert(self.functions[*function_id].path_id.0);
+        }
+        let mut pending: Vec<usize> = references.types.iter().copied().collect();
+        while let Some(type_id) = pending.pop() {
+            let Some(type_record) = self.types.get(type_id) else {
+                continue;
+            };
+            let mut type_record = type_record.clone();
+            visit_type(&mut type_record, &mut |id| {
+                if let IdMut::Type(referenced) = id
+                    && references.types.insert(referenced.0)
+                {
+                    pending.push(referenced.0);
+                }
+            });
+        }
+        references.paths.retain(|path_id| *path_id < self.paths.len());
+        references.types.retain(|type_id| *type_id < self.types.len());
+        references.variables.retain(|variable_id| *variable_id < self.variables.len());
+
+        // the new id of each kept one is its position among them
+        let new_ids = |kept: &BTreeSet<usize>| -> HashMap<usize, usize> { kept.iter().enumerate().map(|(new, old)| (*old, new)).collect() };
+        let (paths, functions, types, variables) = (
+            new_ids(&references.paths),
+            new_ids(&references.functions),
+            new_ids(&references.types),
+            new_ids(&references.variables),
+        );
+        let renumber = |new_ids: &HashMap<usize, usize>, id: &mut usize| {
+            if let Some(new) = new_ids.get(id) {
+                *id = *new;
+            }
+        };
+        let mut remap = |id: IdMut| match id {
+            IdMut::Path(path_id) => renumber(&paths, &mut path_id.0),
+            IdMut::Function(function_id) => renumber(&functions, &mut function_id.0),
+            IdMut::Type(type_id) => renumber(&types, &mut type_id.0),
+            IdMut::Variable(variable_id) => renumber(&variables, &mut variable_id.0),
+        };
+
+        let mut slice = vec![];
+        slice.extend(
+            references
+                .paths
+                .iter()
+                .map(|path_id| TraceLowLevelEvent::Path(self.paths[*path_id].clone())),
+        );
+        for function_id in &references.functions {
+            let mut function = self.functions[*function_id].clone();
+            remap(IdMut::Path(&mut function.path_id));
+            slice.push(TraceLowLevelEvent::Function(function));
+        }
+        for type_id in &references.types {
+            let mut type_record = self.types[*type_id].clone();
+            visit_type(&mut type_record, &mut remap);
+            slice.push(TraceLowLevelEvent::Type(type_record));
+        }
+        slice.extend(
+            references
+                .variables
+                .iter()
+                .map(|variable_id| TraceLowLevelEvent::VariableName(self.variables[*variable_id].clone())),
+        );
+        for mut event in prelude.into_iter().chain(events) {
+            visit_event(&mut event, &mut remap);
+            slice.push(event);
+        }
+        slice
+    }
+
+    // keep track of the open calls before `from`
+    fn follow(&mut self, event: &TraceLowLevelEvent) {
+        let context = match self.call_stack.current_task() {
+            Some(task_id) => Context::Task(task_id),
+            None => Context::Thread(self.call_stack.current_thread()),
+        };
+        match event {
+            TraceLowLevelEvent::Step(step) => {
+                self.last_steps.insert(context, *step);
+            }
+            TraceLowLevelEvent::DropLastStep => {
+                self.last_steps.remove(&context);
+            }
+            TraceLowLevelEvent::Call(call) => {
+                // the call is reopened after other steps, so it has to
+                // define the objects its arguments refer back to
+                let mut call = call.clone();
+                let mut defined = HashSet::new();
+                for arg in &mut call.args {
+                    define_objects(&mut arg.value, &self.objects, &mut defined);
+                }
+                self.open.entry(context).or_default().push(call);
+            }
+            TraceLowLevelEvent::Return(_) => {
+                if let Some(open) = self.open.get_mut(&context) {
+                    open.pop();
+                }
+            }
+            TraceLowLevelEvent::Unwind(unwind) => {
+                if let Some(open) = self.open.get_mut(&context) {
+                    open.truncate(open.len().saturating_sub(unwind.frames.len()));
+                }
+            }
+            TraceLowLevelEvent::ThreadStart(thread_id) => {
+                self.started.insert(*thread_id);
+            }
+            TraceLowLevelEvent::ThreadExit(thread_id) => {
+                self.started.remove(thread_id);
+                self.open.remove(&Context::Thread(*thread_id));
+                self.running.remove(thread_id);
+            }
+            TraceLowLevelEvent::TaskResume(task_id) => {
+                self.running.entry(self.call_stack.current_thread()).or_default().push(*task_id);
+            }
+            TraceLowLevelEvent::TaskSuspend(task_id) | TraceLowLevelEvent::TaskComplete(task_id) => {
+                if let Some(running) = self.running.get_mut(&self.call_stack.current_thread()) {
+                    running.retain(|running| running != task_id);
+                }
+                if matches!(event, TraceLowLevelEvent::TaskComplete(_)) {
+                    self.open.remove(&Context::Task(*task_id));
+                }
+            }
+            _ => {}
+        }
+        self.call_stack.observe(event);
+        self.objects.observe(event);
+    }
+
+    // the events bringing a fresh trace to the state at `from`, with the
+    // original ids
+    fn prelude(&self) -> Vec<TraceLowLevelEvent> {
+        let current_thread = self.call_stack.current_thread();
+        let frames = |context: Context| self.open.get(&context).map(Vec::as_slice).unwrap_or(&[]);
+        let calls = |context: Context| frames(context).iter().cloned().map(TraceLowLevelEvent::Call);
+        let running: BTreeSet<TaskId> = self.running.values().flatten().copied().collect();
+
+        let mut threads: BTreeSet<ThreadId> = self.started.clone();
+        threads.extend(self.open.keys().filter_map(|context| match context {
+            Context::Thread(thread_id) => Some(*thread_id),
+            Context::Task(_) => None,
+        }));
+        threads.extend(
+            self.running
+                .iter()
+                .filter(|(_, tasks)| !tasks.is_empty())
+                .map(|(thread_id, _)| *thread_id),
+        );
+        threads.remove(&current_thread);
+
+        let mut prelude = vec![];
+        let mut active = ThreadId(0);
+        for thread_id in threads.into_iter().chain([current_thread]) {
+            if self.started.contains(&thread_id) {
+                prelude.push(TraceLowLevelEvent::ThreadStart(thread_id));
+            }
+            if thread_id != active {
+                prelude.push(TraceLowLevelEvent::ThreadSwitch(thread_id));
+                active = thread_id;
+            }
+            prelude.extend(calls(Context::Thread(thread_id)));
+            if thread_id == current_thread {
+                // the suspended tasks with calls, resumed just long enough
+                // to reopen them
+                for context in self.open.keys() {
+                    if let Context::Task(task_id) = *context
+                        && !running.contains(&task_id)
+                        && !frames(*context).is_empty()
+                    {
+                        prelude.push(TraceLowLevelEvent::TaskSpawn(task_id));
+                        prelude.push(TraceLowLevelEvent::TaskResume(task_id));
+                        prelude.extend(calls(*context));
+                        prelude.push(TraceLowLevelEvent::TaskSuspend(task_id));
+                    }
+                }
+            }
+            for task_id in self.running.get(&thread_id).map(Vec::as_slice).unwrap_or(&[]) {
+                prelude.push(TraceLowLevelEvent::TaskSpawn(*task_id));
+                prelude.push(TraceLowLevelEvent::TaskResume(*task_id));
+                prelude.extend(calls(Context::Task(*task_id)));
+            }
+        }
+
+        let current = match self.running.get(&current_thread).and_then(|tasks| tasks.last()) {
+            Some(task_id) => Context::Task(*task_id),
+            None => Context::Thread(current_thread),
+        };
+        if !matches!(self.events.first(), Some(TraceLowLevelEvent::Step(_)))
+            && let Some(step) = self.last_steps.get(&current)
+        {
+            prelude.push(TraceLowLevelEvent::Step(*step));
+        }
+        prelude
+    }
+}
+
+/// Slice the events `[from, to)` out of a CTFS container, decoding it a
+/// chunk at a time.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn slice_ctfs(path: &std::path::Path, from: u64, to: u64) -> Result<Vec<TraceLowLevelEvent>, Box<dyn std::error::Error>> {
+    let mut events = crate::ctfs_reader::ChunkedEvents::open(path)?;
+    let mut slicer = TraceSlicer::new(from, to);
+    for geid in 0..to.min(events.event_count()) {
+        slicer.observe(geid, events.event(geid)?);
+    }
+    Ok(slicer.finish())
+}
+
+// turn the first back-reference to each object `defined` doesn't have yet
+// into the object's definition from `objects`
+fn define_objects(value: &mut ValueRecord, objects: &ObjectGraph, defined: &mut HashSet<ObjectId>) {
+    match value {
+        ValueRecord::Object { id, .. } => {
+            defined.insert(*id);
+        }
+        ValueRecord::BackRef { id, .. } if !defined.contains(id) => {
+            let id = *id;
+            if let Some(body) = objects.get(id) {
+                defined.insert(id);
+                *value = ValueRecord::Object {
+                    id,
+                    value: Box::new((*body).clone()),
+                };
+            }
+        }
+        _ => {}
+    }
+    match value {
+        ValueRecord::Sequence { elements, .. } | ValueRecord::Tuple { elements, .. } => {
+            for element in elements {
+                define_objects(element, objects, defined);
+            }
+        }
+        ValueRecord::Struct { field_values, .. } => {
+            for field_value in field_values {
+                define_objects(field_value, objects, defined);
+            }
+        }
+        ValueRecord::Map { entries, .. } => {
+            for (key, value) in entries {
+                define_objects(key, objects, defined);
+                define_objects(value, objects, defined);
+            }
+        }
+        ValueRecord::Variant { contents: value, .. }
+        | ValueRecord::Reference { dereferenced: value, .. }
+        | ValueRecord::Elided { partial: value, .. }
+        | ValueRecord::Object { value, .. } => define_objects(value, objects, defined),
+        _ => {}
+    }
+}
+
+fn visit_event_values(event: &mut TraceLowLevelEvent, visit: &mut impl FnMut(&mut ValueRecord)) {
+    match event {
+        TraceLowLevelEvent::Value(full_value) => visit(&mut full_value.value),
+        TraceLowLevelEvent::Call(call) => {
+            for arg in &mut call.args {
+                visit(&mut arg.value);
+            }
+        }
+        TraceLowLevelEvent::Return(ret) => visit(&mut ret.return_value),
+        TraceLowLevelEvent::Throw(throw) => visit(&mut throw.exception),
+        TraceLowLevelEvent::Catch(catch) => visit(&mut catch.exception),
+        TraceLowLevelEvent::Event(record_event) => {
+            for attribute in &mut record_event.attributes {
+                visit(&mut attribute.value);
+            }
+        }
+        TraceLowLevelEvent::CompoundValue(record) => visit(&mut record.value),
+        TraceLowLevelEvent::CellValue(record) => visit(&mut record.value),
+        TraceLowLevelEvent::AssignCell(record) => visit(&mut record.new_value),
+        _ => {}
+    }
+}
+
+fn visit_event(event: &mut TraceLowLevelEvent, visit: &mut impl FnMut(IdMut)) {
+    match event {
+        TraceLowLevelEvent::Step(step) => visit(IdMut::Path(&mut step.path_id)),
+        TraceLowLevelEvent::Function(function) => visit(IdMut::Path(&mut function.path_id)),
+        TraceLowLevelEvent::Type(type_record) => visit_type(type_record, visit),
+        TraceLowLevelEvent::Value(full_value) => {
+            visit(IdMut::Variable(&mut full_value.variable_id));
+            visit_value(&mut full_value.value, visit);
+        }
+        TraceLowLevelEvent::Call(call) => {
+            visit(IdMut::Function(&mut call.function_id));
+            for arg in &mut call.args {
+                visit(IdMut::Variable(&mut arg.variable_id));
+                visit_value(&mut arg.value, visit);
+            }
+        }
+        TraceLowLevelEvent::Return(ret) => visit_value(&mut ret.return_value, visit),
+        TraceLowLevelEvent::Throw(throw) => visit_value(&mut throw.exception, visit),
+        TraceLowLevelEvent::Catch(catch) => visit_value(&mut catch.exception, visit),
+        TraceLowLevelEvent::Unwind(unwind) => {
+            for function_id in &mut unwind.frames {
+                visit(IdMut::Function(function_id));
+            }
+        }
+        TraceLowLevelEvent::Event(record_event) => {
+            for attribute in &mut record_event.attributes {
+                visit_value(&mut attribute.value, visit);
+            }
+        }
+        TraceLowLevelEvent::BindVariable(bind) => visit(IdMut::Variable(&mut bind.variable_id)),
+        TraceLowLevelEvent::Assignment(assignment) => {
+            visit(IdMut::Variable(&mut assignment.to));
+            match &mut assignment.from {
+                RValue::Simple(variable_id) => visit(IdMut::Variable(variable_id)),
+                RValue::Compound(variable_ids) => {
+                    for variable_id in variable_ids {
+                        visit(IdMut::Variable(variable_id));
+                    }
+                }
+            }
+        }
+        TraceLowLevelEvent::DropVariables(variable_ids) => {
+            for variable_id in variable_ids {
+                visit(IdMut::Variable(variable_id));
+            }
+        }
+        TraceLowLevelEvent::DropVariable(variable_id) => visit(IdMut::Variable(variable_id)),
+        TraceLowLevelEvent::VariableCell(record) => visit(IdMut::Variable(&mut record.variable_id)),
+        TraceLowLevelEvent::CompoundValue(record) => visit_value(&mut record.value, visit),
+        TraceLowLevelEvent::CellValue(record) => visit_value(&mut record.value, visit),
+        TraceLowLevelEvent::AssignCell(record) => visit_value(&mut record.new_value, visit),
+        _ => {}
+    }
+}
+
+fn visit_type(type_record: &mut TypeRecord, visit: &mut impl FnMut(IdMut)) {
+    match &mut type_record.specific_info {
+        TypeSpecificInfo::None => {}
+        TypeSpecificInfo::Struct { fields } => {
+            for field in fields {
+                visit(IdMut::Type(&mut field.type_id));
+            }
+        }
+        TypeSpecificInfo::Pointer { dereference_type_id } => visit(IdMut::Type(dereference_type_id)),
+        TypeSpecificInfo::Collection { element_type_id } => visit(IdMut::Type(element_type_id)),
+        TypeSpecificInfo::Table { key_type_id, value_type_id } => {
+            visit(IdMut::Type(key_type_id));
+            visit(IdMut::Type(value_type_id));
+        }
+        TypeSpecificInfo::Variants { variants } => {
+            for variant in variants {
+                visit(IdMut::Type(&mut variant.type_id));
+            }
+        }
+        TypeSpecificInfo::Function {
+            param_type_ids,
+            return_type_id,
+        } => {
+            for type_id in param_type_ids {
+                visit(IdMut::Type(type_id));
+            }
+            visit(IdMut::Type(return_type_id));
+        }
+    }
+}
+
+fn visit_value(value: &mut ValueRecord, visit: &mut impl FnMut(IdMut)) {
+    match value {
+        ValueRecord::Int { type_id, .. }
+        | ValueRecord::UInt { type_id, .. }
+        | ValueRecord::Int128 { type_id, .. }
+        | ValueRecord::UInt128 { type_id, .. }
+        | ValueRecord::Float { type_id, .. }
+        | ValueRecord::Bool { type_id, .. }
+        | ValueRecord::String { type_id, .. }
+        | ValueRecord::Raw { type_id, .. }
+        | ValueRecord::Error { type_id, .. }
+        | ValueRecord::None { type_id }
+        | ValueRecord::BigInt { type_id, .. }
+        | ValueRecord::Char { type_id, .. }
+        | ValueRecord::Bytes { type_id, .. }
+        | ValueRecord::BackRef { type_id, .. } => visit(IdMut::Type(type_id)),
+        ValueRecord::Sequence { elements, type_id, .. } | ValueRecord::Tuple { elements, type_id } => {
+            visit(IdMut::Type(type_id));
+            for element in elements {
+                visit_value(element, visit);
+            }
+        }
+        ValueRecord::Struct { field_values, type_id } => {
+            visit(IdMut::Type(type_id));
+            for field_value in field_values {
+                visit_value(field_value, visit);
+            }
+        }
+        ValueRecord::Map { entries, type_id } => {
+            visit(IdMut::Type(type_id));
+            for (key, value) in entries {
+                visit_value(key, visit);
+                visit_value(value, visit);
+            }
+        }
+        ValueRecord::Variant { contents, type_id, .. } => {
+            visit(IdMut::Type(type_id));
+            visit_value(contents, visit);
+        }
+        ValueRecord::Reference { dereferenced, type_id, .. } => {
+            visit(IdMut::Type(type_id));
+            visit_value(dereferenced, visit);
+        }
+        ValueRecord::Cell { .. } => {}
+        ValueRecord::Elided { partial, .. } => visit_value(partial, visit),
+        ValueRecord::Object { value, .. } => visit_value(value, visit),
+    }
+}
@@ -0,0 +1,368 @@
+//! Semantic validation of a trace.
+//!
+//! The trace formats only check that each event decodes; they don't check
+//! that the events make sense together. [`Validator`] is fed the events in
+//! trace order together with their GEID (the event's index in the trace)
+//! and reports every place where a trace breaks the rules readers rely on:
+//!
+//! - `Path`, `Function`, `Type` and `VariableName` interning events come
+//!   before any event that uses the id they define
+//! - `Return` and `Unwind` only leave calls that are open in the current
+//!   thread (or task), and `Unwind` names the functions it leaves
+//! - `DropLastStep` has a step to drop
+//! - a `BackRef` points at an object defined since the last `Step`
+//!
+//! Ids inside `Type` records may refer to types registered later, as a
+//! recursive type has to, so those are only checked once the whole trace
+//! was seen.
+//!
+//! Calls still open when the trace ends are fine (the toplevel call never
+//! returns), but a thread that exits with open calls is reported as a
+//! warning.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use codetracer_trace_types::{FunctionId, ObjectId, PathId, RValue, ThreadId, TraceLowLevelEvent, TypeId, TypeSpecificInfo, ValueRecord, VariableId};
+
+use crate::call_stack::CallStack;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationErrorKind {
+    UndefinedPath(PathId),
+    UndefinedFunction(FunctionId),
+    UndefinedType(TypeId),
+    UndefinedVariable(VariableId),
+    UndefinedObject(ObjectId),
+    ReturnWithoutCall,
+    /// `Unwind` leaves more frames than are open.
+    UnwindPastStack {
+        frames: usize,
+        open: usize,
+    },
+    /// `Unwind` names `found` where the open call is to `expected`.
+    UnwindMismatch {
+        expected: FunctionId,
+        found: FunctionId,
+    },
+    DropLastStepWithoutStep,
+    OpenCallsAtThreadExit {
+        thread_id: ThreadId,
+        open: usize,
+    },
+}
+
+/// A problem found at the event `geid`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub geid: u64,
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationErrorKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            ValidationErrorKind::OpenCallsAtThreadExit { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl ValidationError {
+    pub fn severity(&self) -> Severity {
+        self.kind.severity()
+    }
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationErrorKind::UndefinedPath(id) => write!(f, "path {} is used before its Path event", id.0),
+            ValidationErrorKind::UndefinedFunction(id) => {
+                write!(f, "function {} is used before its Function event", id.0)
+            }
+            ValidationErrorKind::UndefinedType(id) => write!(f, "type {} is used before its Type event", id.0),
+            ValidationErrorKind::UndefinedVariable(id) => {
+                write!(f, "variable {} is used before its VariableName event", id.0)
+            }
+            ValidationErrorKind::UndefinedObject(id) => {
+                write!(f, "back-reference to object {} which is not defined since the last Step", id.0)
+            }
+            ValidationErrorKind::ReturnWithoutCall => write!(f, "Return without an open Call"),
+            ValidationErrorKind::UnwindPastStack { frames, open } => {
+                write!(f, "Unwind of {frames} frames with only {open} calls open")
+            }
+            ValidationErrorKind::UnwindMismatch { expected, found } => {
+                write!(f, "Unwind names function {} but the open call is to function {}", found.0, expected.0)
+            }
+            ValidationErrorKind::DropLastStepWithoutStep => write!(f, "DropLastStep without a Step to drop"),
+            ValidationErrorKind::OpenCallsAtThreadExit { thread_id, open } => {
+                write!(f, "thread {} exits with {open} calls open", thread_id.0)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity() {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}: geid {}: {}", self.geid, self.kind)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Validator {
+    paths: usize,
+    functions: usize,
+    types: usize,
+    variables: usize,
+    // steps not dropped by a `DropLastStep`
+    steps: usize,
+    // objects defined since the last `Step`
+    objects: HashSet<ObjectId>,
+    calls: CallStack,
+    // (geid of the `Type`, referenced id), checked in `finish`
+    type_references: Vec<(u64, TypeId)>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a whole trace; the GEID of each event is its index.
+    pub fn validate(events: &[TraceLowLevelEvent]) -> Vec<ValidationError> {
+        let mut validator = Self::new();
+        for (geid, event) in events.iter().enumerate() {
+            validator.observe(geid as u64, event);
+        }
+        validator.finish()
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        match event {
+            TraceLowLevelEvent::Path(_) => self.paths += 1,
+            TraceLowLevelEvent::VariableName(_) | TraceLowLevelEvent::Variable(_) => self.variables += 1,
+            TraceLowLevelEvent::Type(type_record) => {
+                self.types += 1;
+                let references = type_references(&type_record.specific_info);
+                self.type_references.extend(references.into_iter().map(|type_id| (geid, type_id)));
+            }
+            TraceLowLevelEvent::Function(function) => {
+                self.check_path(geid, function.path_id);
+                self.functions += 1;
+            }
+            TraceLowLevelEvent::Step(step) => {
+                self.check_path(geid, step.path_id);
+                self.steps += 1;
+                self.objects.clear();
+            }
+            TraceLowLevelEvent::DropLastStep => {
+                if self.steps == 0 {
+                    self.report(geid, ValidationErrorKind::DropLastStepWithoutStep);
+                } else {
+                    self.steps -= 1;
+                }
+            }
+            TraceLowLevelEvent::Value(full_value) => {
+                self.check_variable(geid, full_value.variable_id);
+                self.check_value(geid, &full_value.value);
+            }
+            TraceLowLevelEvent::Call(call) => {
+                if call.function_id.0 >= self.functions {
+                    self.report(geid, ValidationErrorKind::UndefinedFunction(call.function_id));
+                }
+                for arg in &call.args {
+                    self.check_variable(geid, arg.variable_id);
+                    self.check_value(geid, &arg.value);
+                }
+            }
+            TraceLowLevelEvent::Return(ret) => {
+                if self.calls.depth() == 0 {
+                    self.report(geid, ValidationErrorKind::ReturnWithoutCall);
+                }
+                self.check_value(geid, &ret.return_value);
+            }
+            TraceLowLevelEvent::Unwind(unwind) => self.check_unwind(geid, &unwind.frames),
+            TraceLowLevelEvent::Throw(throw) => self.check_value(geid, &throw.exception),
+            TraceLowLevelEvent::Catch(catch) => self.check_value(geid, &catch.exception),
+            TraceLowLevelEvent::Event(record_event) => {
+                for attribute in &record_event.attributes {
+                    self.check_value(geid, &attribute.value);
+                }
+            }
+            TraceLowLevelEvent::BindVariable(bind) => self.check_variable(geid, bind.variable_id),
+            TraceLowLevelEvent::Assignment(assignment) => {
+                self.check_variable(geid, assignment.to);
+                match &assignment.from {
+                    RValue::Simple(variable_id) => self.check_variable(geid, *variable_id),
+                    RValue::Compound(variable_ids) => {
+                        for variable_id in variable_ids {
+                            self.check_variable(geid, *variable_id);
+                        }
+                    }
+                }
+            }
+            TraceLowLevelEvent::DropVariables(variable_ids) => {
+                for variable_id in variable_ids {
+                    self.check_variable(geid, *variable_id);
+                }
+            }
+            TraceLowLevelEvent::DropVariable(variable_id) => self.check_variable(geid, *variable_id),
+            TraceLowLevelEvent::VariableCell(record) => self.check_variable(geid, record.variable_id),
+            TraceLowLevelEvent::CompoundValue(record) => self.check_value(geid, &record.value),
+            TraceLowLevelEvent::CellValue(record) => self.check_value(geid, &record.value),
+            TraceLowLevelEvent::AssignCell(record) => self.check_value(geid, &record.new_value),
+            TraceLowLevelEvent::ThreadExit(thread_id) => {
+                let open = self.calls.thread_frames(*thread_id).len();
+                if open > 0 {
+                    self.report(geid, ValidationErrorKind::OpenCallsAtThreadExit { thread_id: *thread_id, open });
+                }
+            }
+            _ => {}
+        }
+        self.calls.observe(event);
+    }
+
+    /// Run the checks that need the whole trace and return every problem
+    /// found, in GEID order.
+    pub fn finish(mut self) -> Vec<ValidationError> {
+        for (geid, type_id) in std::mem::take(&mut self.type_references) {
+            if type_id.0 >= self.types {
+                self.report(geid, ValidationErrorKind::UndefinedType(type_id));
+            }
+        }
+        self.errors.sort_by_key(|error| error.geid);
+        self.errors
+    }
+
+    fn report(&mut self, geid: u64, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError { geid, kind });
+    }
+
+    fn check_path(&mut self, geid: u64, path_id: PathId) {
+        if path_id.0 >= self.paths {
+            self.report(geid, ValidationErrorKind::UndefinedPath(path_id));
+        }
+    }
+
+    fn check_variable(&mut self, geid: u64, variable_id: VariableId) {
+        if variable_id.0 >= self.variables {
+            self.report(geid, ValidationErrorKind::UndefinedVariable(variable_id));
+        }
+    }
+
+    fn check_type(&mut self, geid: u64, type_id: TypeId) {
+        if type_id.0 >= self.types {
+            self.report(geid, ValidationErrorKind::UndefinedType(type_id));
+        }
+    }
+
+    fn check_unwind(&mut self, geid: u64, frames: &[FunctionId]) {
+        let open = self.calls.frames();
+        if frames.len() > open.len() {
+            let kind = ValidationErrorKind::UnwindPastStack {
+                frames: frames.len(),
+                open: open.len(),
+            };
+            self.report(geid, kind);
+            return;
+        }
+        // `frames` is innermost first
+        let mismatch = frames
+            .iter()
+            .zip(open.iter().rev())
+            .find(|(found, expected)| found != expected)
+            .map(|(found, expected)| ValidationErrorKind::UnwindMismatch {
+                expected: *expected,
+                found: *found,
+            });
+        if let Some(kind) = mismatch {
+            self.report(geid, kind);
+        }
+    }
+
+    fn check_value(&mut self, geid: u64, value: &ValueRecord) {
+        match value {
+            ValueRecord::Int { type_id, .. }
+            | ValueRecord::UInt { type_id, .. }
+            | ValueRecord::Int128 { type_id, .. }
+            | ValueRecord::UInt128 { type_id, .. }
+            | ValueRecord::Float { type_id, .. }
+            | ValueRecord::Bool { type_id, .. }
+            | ValueRecord::String { type_id, .. }
+            | ValueRecord::Raw { type_id, .. }
+            | ValueRecord::Error { type_id, .. }
+            | ValueRecord::None { type_id }
+            | ValueRecord::BigInt { type_id, .. }
+            | ValueRecord::Char { type_id, .. }
+            | ValueRecord::Bytes { type_id, .. } => self.check_type(geid, *type_id),
+            ValueRecord::Sequence { elements, type_id, .. } | ValueRecord::Tuple { elements, type_id } => {
+                self.check_type(geid, *type_id);
+                for element in elements {
+                    self.check_value(geid, element);
+                }
+            }
+            ValueRecord::Struct { field_values, type_id } => {
+                self.check_type(geid, *type_id);
+                for field_value in field_values {
+                    self.check_value(geid, field_value);
+                }
+            }
+            ValueRecord::Map { entries, type_id } => {
+                self.check_type(geid, *type_id);
+                for (key, value) in entries {
+                    self.check_value(geid, key);
+                    self.check_value(geid, value);
+                }
+            }
+            ValueRecord::Variant { contents, type_id, .. } => {
+                self.check_type(geid, *type_id);
+                self.check_value(geid, contents);
+            }
+            ValueRecord::Reference { dereferenced, type_id, .. } => {
+                self.check_type(geid, *type_id);
+                self.check_value(geid, dereferenced);
+            }
+            ValueRecord::Cell { .. } => {}
+            ValueRecord::Elided { partial, .. } => self.check_value(geid, partial),
+            ValueRecord::Object { id, value } => {
+                // defined before its body, which may refer back to it
+                self.objects.insert(*id);
+                self.check_value(geid, value);
+            }
+            ValueRecord::BackRef { id, type_id } => {
+                self.check_type(geid, *type_id);
+                if !self.objects.contains(id) {
+                    self.report(geid, ValidationErrorKind::UndefinedObject(*id));
+                }
+            }
+        }
+    }
+}
+
+fn type_references(info: &TypeSpecificInfo) -> Vec<TypeId> {
+    match info {
+        TypeSpecificInfo::None => vec![],
+        TypeSpecificInfo::Struct { fields } => fields.iter().map(|field| field.type_id).collect(),
+        TypeSpecificInfo::Pointer { dereference_type_id } => vec![*dereference_type_id],
+        TypeSpecificInfo::Collection { element_type_id } => vec![*element_type_id],
+        TypeSpecificInfo::Table { key_type_id, value_type_id } => vec![*key_type_id, *value_type_id],
+        TypeSpecificInfo::Variants { variants } => variants.iter().map(|variant| variant.type_id).collect(),
+        TypeSpecificInfo::Function {
+            param_type_ids,
+            return_type_id,
+        } => param_type_ids.iter().chain([return_type_id]).copied().collect(),
+    }
+}
@@ -0,0 +1,296 @@
+//! Reader-side variable histories.
+//!
+//! [`VariableHistory`] is fed the events in trace order together with their
+//! GEID and keeps, for every interned variable name, the timeline of the
+//! values it took: each entry says at which step, in which call and by what
+//! kind of event the variable changed.
+//!
+//! A variable is tracked per call, so `n` in a recursive call is not a new
+//! value of the caller's `n`. Full `Value` snapshots are only recorded when
+//! the value differs from the variable's previous one in the same call.
+//!
+//! Writers register the argument values before the step at the function's
+//! line and the `Call` itself (see `CallTree`); the values matching the
+//! `Call`'s arguments are moved to the callee, where they belong.
+//!
+//! Aliasing follows places: `BindVariable` (or `VariableCell`) and
+//! by-reference `Assignment`s tie variables to a `Place`, and a write to a
+//! place (`CellValue`, `AssignCell` or `CompoundValue`) is a change of every
+//! variable bound to it at that moment, in whichever call it lives.
+
+use std::collections::HashMap;
+
+use codetracer_trace_types::{CallKey, PassBy, Place, RValue, StepId, TraceLowLevelEvent, ValueRecord, VariableId};
+
+use crate::call_tree::CallTree;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    /// A full `Value` snapshot, or an argument of a `Call`.
+    Value,
+    Bind(Place),
+    Assign {
+        by_reference: bool,
+    },
+    /// A write to the place the variable is bound to.
+    Cell(Place),
+    Drop,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableChange {
+    pub geid: u64,
+    /// `None` before the first step of the trace.
+    pub step_id: Option<StepId>,
+    pub call_key: CallKey,
+    pub kind: ChangeKind,
+    /// The value after the change, when the trace tells it.
+    pub value: Option<ValueRecord>,
+}
+
+// a variable of a call: (call key, variable)
+type Local = (i64, VariableId);
+
+// a value change recorded right before a step, which moves to the callee if
+// the step turns out to be a function's entry step
+#[derive(Debug)]
+struct PendingArgument {
+    variable_id: VariableId,
+    index: usize,
+    previous: Option<ValueRecord>,
+}
+
+#[derive(Debug, Default)]
+pub struct VariableHistory {
+    names: Vec<String>,
+    // by `VariableId`
+    timelines: Vec<Vec<VariableChange>>,
+    values: HashMap<Local, ValueRecord>,
+    places: HashMap<Local, Place>,
+    // the variables bound to each place
+    bound: HashMap<Place, Vec<Local>>,
+    cells: HashMap<Place, ValueRecord>,
+    values_before_step: Vec<PendingArgument>,
+    values_before_last_step: Vec<PendingArgument>,
+    call_tree: CallTree,
+}
+
+impl VariableHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the histories for a whole trace; the GEID of each event is its
+    /// index.
+    pub fn from_events(events: &[TraceLowLevelEvent]) -> Self {
+        let mut history = Self::new();
+        for (geid, event) in events.iter().enumerate() {
+            history.observe(geid as u64, event);
+        }
+        history
+    }
+
+    /// Feed the next event of the trace.
+    pub fn observe(&mut self, geid: u64, event: &TraceLowLevelEvent) {
+        if let TraceLowLevelEvent::Call(call) = event {
+            let after_step = self.call_tree.steps().last().is_some_and(|step| step.geid + 1 == geid);
+            self.call_tree.observe(geid, event);
+            let callee = self.call_tree.current_call();
+            let pending = if after_step {
+                std::mem::take(&mut self.values_before_last_step)
+            } else {
+                vec![]
+            };
+            // the caller's own values can be right before the entry step too
+            let arguments: Vec<PendingArgument> = pending
+                .into_iter()
+                .filter(|pending| {
+                    let value = &self.timelines[pending.variable_id.0][pending.index].value;
+                    call.args
+                        .iter()
+                        .any(|arg| arg.variable_id == pending.variable_id && value.as_ref() == Some(&arg.value))
+                })
+                .collect();
+            let step_id = self.current_step();
+            for argument in &arguments {
+                self.move_to_callee(argument, callee, step_id);
+            }
+            for arg in &call.args {
+                if !arguments.iter().any(|argument| argument.variable_id == arg.variable_id) {
+                    self.record(geid, arg.variable_id, callee, ChangeKind::Value, Some(arg.value.clone()));
+                }
+            }
+            self.values_before_step.clear();
+            self.values_before_last_step.clear();
+            return;
+        }
+
+        let call_key = self.call_tree.current_call();
+        match event {
+            TraceLowLevelEvent::VariableName(name) | TraceLowLevelEvent::Variable(name) => {
+                self.names.push(name.clone());
+                self.timelines.push(vec![]);
+            }
+            TraceLowLevelEvent::Value(full_value) => {
+                let local = (call_key.0, full_value.variable_id);
+                let previous = self.values.get(&local).cloned();
+                if previous.as_ref() != Some(&full_value.value) {
+                    let index = self.record(geid, full_value.variable_id, call_key, ChangeKind::Value, Some(full_value.value.clone()));
+                    self.values_before_step.push(PendingArgument {
+                        variable_id: full_value.variable_id,
+                        index,
+                        previous,
+                    });
+                }
+            }
+            TraceLowLevelEvent::BindVariable(bind) => self.bind(geid, call_key, bind.variable_id, bind.place),
+            TraceLowLevelEvent::VariableCell(record) => self.bind(geid, call_key, record.variable_id, record.place),
+            TraceLowLevelEvent::Assignment(assignment) => {
+                let by_reference = matches!(assignment.pass_by, PassBy::Reference);
+                let mut value = None;
+                if let RValue::Simple(from) = assignment.from {
+                    value = self.values.get(&(call_key.0, from)).cloned();
+                    let place = self.places.get(&(call_key.0, from)).copied();
+                    if let (true, Some(place)) = (by_reference, place) {
+                        self.set_place((call_key.0, assignment.to), Some(place));
+                    }
+                }
+                self.record(geid, assignment.to, call_key, ChangeKind::Assign { by_reference }, value);
+            }
+            TraceLowLevelEvent::CellValue(record) => self.write_place(geid, record.place, &record.value),
+            TraceLowLevelEvent::AssignCell(record) => self.write_place(geid, record.place, &record.new_value),
+            TraceLowLevelEvent::CompoundValue(record) => self.write_place(geid, record.place, &record.value),
+            TraceLowLevelEvent::DropVariables(variable_ids) => {
+                for variable_id in variable_ids {
+                    self.drop(geid, call_key, *variable_id);
+                }
+            }
+            TraceLowLevelEvent::DropVariable(variable_id) => self.drop(geid, call_key, *variable_id),
+            _ => {}
+        }
+        self.call_tree.observe(geid, event);
+
+        match event {
+            TraceLowLevelEvent::Value(_) => {}
+            TraceLowLevelEvent::Step(_) => {
+                self.values_before_last_step = std::mem::take(&mut self.values_before_step);
+            }
+            _ => {
+                self.values_before_step.clear();
+                self.values_before_last_step.clear();
+            }
+        }
+    }
+
+    pub fn variable_id(&self, name: &str) -> Option<VariableId> {
+        self.names.iter().position(|known| known == name).map(VariableId)
+    }
+
+    pub fn variable_name(&self, variable_id: VariableId) -> Option<&str> {
+        self.names.get(variable_id.0).map(String::as_str)
+    }
+
+    /// Every change of `variable_id`, in trace order.
+    pub fn timeline(&self, variable_id: VariableId) -> &[VariableChange] {
+        self.timelines.get(variable_id.0).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn timeline_by_name(&self, name: &str) -> &[VariableChange] {
+        self.variable_id(name).map_or(&[], |variable_id| self.timeline(variable_id))
+    }
+
+    /// The value of `variable_id` in the call `call_key` once the event
+    /// `geid` had happened; `None` if it had none or was dropped.
+    pub fn value_at(&self, variable_id: VariableId, call_key: CallKey, geid: u64) -> Option<&ValueRecord> {
+        self.timeline(variable_id)
+            .iter()
+            .rev()
+            .filter(|change| change.geid <= geid && change.call_key == call_key)
+            .find(|change| change.value.is_some() || change.kind == ChangeKind::Drop)
+            .and_then(|change| change.value.as_ref())
+    }
+
+    /// The call tree of the trace, built along the way.
+    pub fn call_tree(&self) -> &CallTree {
+        &self.call_tree
+    }
+
+    fn current_step(&self) -> Option<StepId> {
+        self.call_tree.steps().len().checked_sub(1).map(|index| StepId(index as i64))
+    }
+
+    fn record(&mut self, geid: u64, variable_id: VariableId, call_key: CallKey, kind: ChangeKind, value: Option<ValueRecord>) -> usize {
+        let local = (call_key.0, variable_id);
+        match (&kind, &value) {
+            (ChangeKind::Drop, _) => {
+                self.values.remove(&local);
+            }
+            (_, Some(value)) => {
+                self.values.insert(local, value.clone());
+            }
+            _ => {}
+        }
+        if self.timelines.len() <= variable_id.0 {
+            self.timelines.resize_with(variable_id.0 + 1, Vec::new);
+        }
+        let step_id = self.current_step();
+        let timeline = &mut self.timelines[variable_id.0];
+        timeline.push(VariableChange {
+            geid,
+            step_id,
+            call_key,
+            kind,
+            value,
+        });
+        timeline.len() - 1
+    }
+
+    fn move_to_callee(&mut self, argument: &PendingArgument, callee: CallKey, step_id: Option<StepId>) {
+        let change = &mut self.timelines[argument.variable_id.0][argument.index];
+        let caller_local = (change.call_key.0, argument.variable_id);
+        change.call_key = callee;
+        change.step_id = step_id;
+        if let Some(value) = change.value.clone() {
+            self.values.insert((callee.0, argument.variable_id), value);
+        }
+        match &argument.previous {
+            Some(previous) => self.values.insert(caller_local, previous.clone()),
+            None => self.values.remove(&caller_local),
+        };
+    }
+
+    fn set_place(&mut self, local: Local, place: Option<Place>) {
+        let previous = match place {
+            Some(place) => self.places.insert(local, place),
+            None => self.places.remove(&local),
+        };
+        if let Some(locals) = previous.and_then(|previous| self.bound.get_mut(&previous)) {
+            locals.retain(|bound| *bound != local);
+        }
+        if let Some(place) = place {
+            self.bound.entry(place).or_default().push(local);
+        }
+    }
+
+    fn bind(&mut self, geid: u64, call_key: CallKey, variable_id: VariableId, place: Place) {
+        self.set_place((call_key.0, variable_id), Some(place));
+        let value = self.cells.get(&place).cloned();
+        self.record(geid, variable_id, call_key, ChangeKind::Bind(place), value);
+    }
+
+    fn write_place(&mut self, geid: u64, place: Place, value: &ValueRecord) {
+        self.cells.insert(place, value.clone());
+        // variables of calls that have returned are gone
+        let call_tree = &self.call_tree;
+        let aliases = self.bound.entry(place).or_default();
+        aliases.retain(|(call_key, _)| call_tree.call(CallKey(*call_key)).is_none_or(|call| call.end_geid.is_none()));
+        for (call_key, variable_id) in aliases.clone() {
+            self.record(geid, variable_id, CallKey(call_key), ChangeKind::Cell(place), Some(value.clone()));
+        }
+    }
+
+    fn drop(&mut self, geid: u64, call_key: CallKey, variable_id: VariableId) {
+        self.set_place((call_key.0, variable_id), None);
+        self.record(geid, variable_id, call_key, ChangeKind::Drop, None);
+    }
+}
@@ -0,0 +1,154 @@
+//! Call stack reconstruction across normal returns, exceptions and threads.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::call_stack::CallStack;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::ctfs_roundtrip;
+
+/// main -> outer -> inner; inner throws, both unwind, main catches, then a
+/// normal call to `after`.
+fn write_exception_trace(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/test/exc.py");
+    TraceWriter::start(writer, path, Line(1));
+    let outer = TraceWriter::ensure_function_id(writer, "outer", path, Line(10));
+    let inner = TraceWriter::ensure_function_id(writer, "inner", path, Line(20));
+    let after = TraceWriter::ensure_function_id(writer, "after", path, Line(30));
+    let err_type = TraceWriter::ensure_type_id(writer, TypeKind::Error, "ValueError");
+    let exception = ValueRecord::Error {
+        msg: "boom".to_string(),
+        type_id: err_type,
+    };
+
+    TraceWriter::register_call(writer, outer, vec![]);
+    TraceWriter::register_call(writer, inner, vec![]);
+    TraceWriter::register_step(writer, path, Line(21));
+    TraceWriter::register_throw(writer, exception.clone());
+    TraceWriter::register_unwind(writer, &[inner, outer]);
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::register_catch(writer, exception);
+    TraceWriter::register_call(writer, after, vec![]);
+    TraceWriter::register_return(writer, NONE_VALUE);
+}
+
+fn depths(events: &[TraceLowLevelEvent]) -> (Vec<usize>, CallStack) {
+    let mut stack = CallStack::new();
+    let mut depths = vec![];
+    for event in events {
+        stack.observe(event);
+        if matches!(
+            event,
+            TraceLowLevelEvent::Call(_) | TraceLowLevelEvent::Return(_) | TraceLowLevelEvent::Unwind(_)
+        ) {
+            depths.push(stack.depth());
+        }
+    }
+    (depths, stack)
+}
+
+#[test]
+fn test_unwind_keeps_stack_balanced_after_ctfs_roundtrip() {
+    let events = ctfs_roundtrip(write_exception_trace);
+
+    // toplevel, outer, inner, unwind both, after, return from after
+    let (depths, stack) = depths(&events);
+    assert_eq!(depths, vec![1, 2, 3, 1, 2, 1]);
+    assert!(stack.pending_exception().is_none());
+    assert!(events.iter().any(|e| matches!(e, TraceLowLevelEvent::Unwind(u) if u.frames.len() == 2)));
+}
+
+#[test]
+fn test_pending_exception_and_thread_stacks() {
+    let main = FunctionId(0);
+    let worker_fn = FunctionId(1);
+    let exception = ValueRecord::Error {
+        msg: "panic".to_string(),
+        type_id: TypeId(0),
+    };
+    let events = vec![
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: main,
+            args: vec![],
+        }),
+        TraceLowLevelEvent::ThreadStart(ThreadId(1)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: worker_fn,
+            args: vec![],
+        }),
+        TraceLowLevelEvent::Throw(ThrowRecord {
+            exception: exception.clone(),
+        }),
+    ];
+    let mut stack = CallStack::new();
+    for event in &events {
+        stack.observe(event);
+    }
+    assert_eq!(stack.current_thread(), ThreadId(1));
+    assert_eq!(stack.pending_exception(), Some(&exception));
+    assert_eq!(stack.thread_frames(ThreadId(0)), &[main]);
+
+    // the panic leaves the worker thread entirely
+    stack.observe(&TraceLowLevelEvent::Unwind(UnwindRecord { frames: vec![worker_fn] }));
+    assert_eq!(stack.depth(), 0);
+    stack.observe(&TraceLowLevelEvent::ThreadExit(ThreadId(1)));
+    stack.observe(&TraceLowLevelEvent::ThreadSwitch(ThreadId(0)));
+    assert_eq!(stack.frames(), &[main]);
+    assert!(stack.pending_exception().is_none());
+}
+
+#[test]
+fn test_task_stack_survives_suspension() {
+    use codetracer_trace_writer::abstract_trace_writer::AbstractTraceWriter;
+    use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+
+    let mut writer = NonStreamingTraceWriter::new("tasks", &[]);
+    let path = Path::new("/test/tasks.py");
+    TraceWriter::start(&mut writer, path, Line(1));
+    let fetch = TraceWriter::ensure_function_id(&mut writer, "fetch", path, Line(10));
+    let read = TraceWriter::ensure_function_id(&mut writer, "read", path, Line(20));
+    let tick = TraceWriter::ensure_function_id(&mut writer, "tick", path, Line(30));
+    let (a, b) = (TaskId(1), TaskId(2));
+
+    writer.task_spawn(a);
+    writer.task_spawn(b);
+    // a: fetch -> read, awaits inside read
+    writer.task_resume(a);
+    TraceWriter::register_call(&mut writer, fetch, vec![]);
+    TraceWriter::register_call(&mut writer, read, vec![]);
+    writer.task_suspend(a);
+    // b runs to completion meanwhile
+    writer.task_resume(b);
+    TraceWriter::register_call(&mut writer, tick, vec![]);
+    let during_b = writer.events.len();
+    TraceWriter::register_return(&mut writer, NONE_VALUE);
+    writer.task_complete(b);
+    // a continues inside read
+    writer.task_resume(a);
+    let after_resume = writer.events.len();
+    TraceWriter::register_return(&mut writer, NONE_VALUE);
+    TraceWriter::register_return(&mut writer, NONE_VALUE);
+    writer.task_complete(a);
+
+    let mut stack = CallStack::new();
+    for (i, event) in writer.events.iter().enumerate() {
+        if i == during_b {
+            assert_eq!(stack.current_task(), Some(b));
+            assert_eq!(stack.frames(), &[tick]);
+            assert_eq!(stack.task_frames(a), &[fetch, read]);
+            // the event loop's own stack is untouched by either task
+            assert_eq!(stack.thread_frames(ThreadId(0)), &[TOP_LEVEL_FUNCTION_ID]);
+        }
+        if i == after_resume {
+            assert_eq!(stack.current_task(), Some(a));
+            assert_eq!(stack.frames(), &[fetch, read]);
+        }
+        stack.observe(event);
+    }
+    assert_eq!(stack.current_task(), None);
+    assert_eq!(stack.frames(), &[TOP_LEVEL_FUNCTION_ID]);
+    assert!(stack.task_frames(a).is_empty());
+}
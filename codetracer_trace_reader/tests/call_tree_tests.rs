@@ -0,0 +1,132 @@
+//! Call tree and step index reconstruction.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::call_tree::{CallTree, StepIndexEntry};
+use codetracer_trace_reader::ctfs_reader::{read_step_index, store_step_index};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{ctfs_roundtrip, load_ctfs_trace, write_ctfs_trace};
+
+/// toplevel -> square(3) -> mul, then a call to `fail` that unwinds.
+fn write_call_trace(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/test/calls.py");
+    TraceWriter::start(writer, path, Line(1));
+    let int_type = TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+    let mul = TraceWriter::ensure_function_id(writer, "mul", path, Line(20));
+    let fail = TraceWriter::ensure_function_id(writer, "fail", path, Line(30));
+
+    TraceWriter::register_step(writer, path, Line(2));
+    let arg = TraceWriter::arg(writer, "x", ValueRecord::Int { i: 3, type_id: int_type });
+    TraceWriter::register_call(writer, square, vec![arg]);
+    TraceWriter::register_step(writer, path, Line(11));
+    TraceWriter::register_call(writer, mul, vec![]);
+    TraceWriter::register_step(writer, path, Line(21));
+    TraceWriter::register_return(writer, ValueRecord::Int { i: 9, type_id: int_type });
+    TraceWriter::register_step(writer, path, Line(12));
+    TraceWriter::register_step(writer, path, Line(13));
+    TraceWriter::drop_last_step(writer);
+    TraceWriter::register_return(writer, ValueRecord::Int { i: 9, type_id: int_type });
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::register_call(writer, fail, vec![]);
+    TraceWriter::register_step(writer, path, Line(31));
+    TraceWriter::register_unwind(writer, &[fail]);
+    TraceWriter::register_step(writer, path, Line(4));
+}
+
+#[test]
+fn test_call_tree_after_ctfs_roundtrip() {
+    let events = ctfs_roundtrip(write_call_trace);
+
+    let tree = CallTree::from_events(&events);
+    let calls = tree.calls();
+    assert_eq!(calls.len(), 4);
+    let (toplevel, square, mul, fail) = (&calls[0], &calls[1], &calls[2], &calls[3]);
+
+    assert_eq!(toplevel.parent_key, NO_KEY);
+    assert_eq!(tree.roots().count(), 1);
+    assert_eq!(toplevel.children, vec![CallKey(1), CallKey(3)]);
+    assert_eq!(toplevel.end_geid, None);
+
+    assert_eq!((square.parent_key, square.depth), (CallKey(0), 1));
+    assert_eq!((mul.parent_key, mul.depth), (CallKey(1), 2));
+    assert_eq!(square.args.len(), 1);
+    assert_eq!(square.return_value, Some(ValueRecord::Int { i: 9, type_id: TypeId(1) }));
+    assert!(matches!(events[square.call_geid as usize], TraceLowLevelEvent::Call(_)));
+    assert!(matches!(events[square.end_geid.unwrap() as usize], TraceLowLevelEvent::Return(_)));
+
+    // lines of the steps: 2 | 10 11 | 20 21 | 12 (13 dropped) | 3 | 30 31 | 4,
+    // where the writer's step at the function's line before each call is
+    // the callee's first step
+    assert_eq!(tree.steps().len(), 10);
+    assert_eq!((toplevel.first_step, toplevel.last_step), (Some(StepId(0)), Some(StepId(9))));
+    // the dropped step doesn't count, and mul's steps are not square's
+    assert_eq!((square.first_step, square.last_step), (Some(StepId(1)), Some(StepId(5))));
+    assert_eq!((mul.first_step, mul.last_step), (Some(StepId(3)), Some(StepId(4))));
+    let step_calls: Vec<i64> = tree.steps().iter().map(|step| step.call_key.0).collect();
+    assert_eq!(step_calls, vec![0, 1, 1, 2, 2, 1, 0, 3, 3, 0]);
+    assert_eq!(tree.call_of_step(StepId(7)), CallKey(3));
+    assert_eq!(tree.call_of_step(StepId(10)), NO_KEY);
+
+    assert!(fail.end_geid.is_some());
+    assert_eq!(fail.return_value, None);
+    assert_eq!(tree.current_call(), CallKey(0));
+}
+
+#[test]
+fn test_threads_are_not_each_others_callers() {
+    let step = TraceLowLevelEvent::Step(StepRecord {
+        path_id: PathId(0),
+        line: Line(1),
+        span: None,
+    });
+    let call = |function_id: usize| {
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: FunctionId(function_id),
+            args: vec![],
+        })
+    };
+    let events = vec![
+        call(0),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        call(1),
+        step.clone(),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(0)),
+        call(2),
+        step.clone(),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        TraceLowLevelEvent::Return(ReturnRecord { return_value: NONE_VALUE }),
+        step,
+    ];
+
+    let tree = CallTree::from_events(&events);
+    let calls = tree.calls();
+    assert_eq!(calls[1].parent_key, NO_KEY);
+    assert_eq!(calls[1].thread_id, ThreadId(1));
+    assert_eq!((calls[2].parent_key, calls[2].depth), (CallKey(0), 1));
+    // the return on thread 1 leaves thread 1's call, not the newer one on thread 0
+    assert_eq!(calls[1].end_geid, Some(8));
+    assert_eq!(calls[2].end_geid, None);
+    let step_calls: Vec<i64> = tree.steps().iter().map(|step| step.call_key.0).collect();
+    assert_eq!(step_calls, vec![1, 2, -1]);
+}
+
+#[test]
+fn test_step_index_stored_in_ctfs_container() {
+    let dir = tempfile::tempdir().unwrap();
+    let ct_path = write_ctfs_trace(&dir, write_call_trace);
+
+    assert_eq!(read_step_index(&ct_path).unwrap(), None);
+    let tree = CallTree::from_events(&load_ctfs_trace(&ct_path));
+    store_step_index(&ct_path, tree.steps()).unwrap();
+    // storing again keeps the first index
+    store_step_index(&ct_path, &[]).unwrap();
+
+    let stored: Vec<StepIndexEntry> = read_step_index(&ct_path).unwrap().unwrap();
+    assert_eq!(stored, tree.steps());
+    // the container is still a readable trace
+    assert_eq!(load_ctfs_trace(&ct_path).len(), tree.steps().last().unwrap().geid as usize + 1);
+}
@@ -0,0 +1,142 @@
+//! Chrome Trace Event export.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::chrome_trace::ChromeTraceExporter;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{ctfs_roundtrip, int};
+use serde_json::Value;
+
+// (ph, name, ts, tid) of the non-metadata events
+fn timeline(chrome_trace: &Value) -> Vec<(String, String, u64, u64)> {
+    chrome_trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["ph"] != "M")
+        .map(|event| {
+            (
+                event["ph"].as_str().unwrap().to_string(),
+                event["name"].as_str().unwrap().to_string(),
+                event["ts"].as_u64().unwrap(),
+                event["tid"].as_u64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn owned(events: &[(&str, &str, u64, u64)]) -> Vec<(String, String, u64, u64)> {
+    events
+        .iter()
+        .map(|(ph, name, ts, tid)| (ph.to_string(), name.to_string(), *ts, *tid))
+        .collect()
+}
+
+#[test]
+fn test_calls_threads_and_instant_events() {
+    let (mut square, mut worker) = (FunctionId(0), FunctionId(0));
+    let events = ctfs_roundtrip(|writer| {
+        let path = Path::new("/src/main.py");
+        TraceWriter::start(writer, path, Line(1));
+        TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+        square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+        worker = TraceWriter::ensure_function_id(writer, "worker", path, Line(20));
+        TraceWriter::register_step(writer, path, Line(2));
+        let arg = TraceWriter::arg(writer, "n", int(3));
+        TraceWriter::register_call(writer, square, vec![arg]);
+        TraceWriter::register_special_event(writer, EventLogKind::Write, "stdout", "hello");
+        TraceWriter::register_return(writer, int(9));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadStart(ThreadId(4)));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadSwitch(ThreadId(4)));
+        TraceWriter::register_call(writer, worker, vec![]);
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadExit(ThreadId(4)));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadSwitch(ThreadId(0)));
+        TraceWriter::register_step(writer, path, Line(3));
+    });
+
+    let chrome_trace = ChromeTraceExporter::export(&events);
+    let geid = |wanted: &dyn Fn(&TraceLowLevelEvent) -> bool| events.iter().position(wanted).unwrap() as u64;
+    let square_call = geid(&|event| matches!(event, TraceLowLevelEvent::Call(call) if call.function_id == square));
+    let worker_call = geid(&|event| matches!(event, TraceLowLevelEvent::Call(call) if call.function_id == worker));
+    let write = geid(&|event| matches!(event, TraceLowLevelEvent::Event(_)));
+    let thread_exit = geid(&|event| matches!(event, TraceLowLevelEvent::ThreadExit(_)));
+    let end = events.len() as u64;
+
+    assert_eq!(
+        timeline(&chrome_trace),
+        owned(&[
+            ("B", "<toplevel>", 2, 0),
+            ("B", "square", square_call, 0),
+            ("i", "Write", write, 0),
+            ("E", "square", write + 1, 0),
+            ("B", "worker", worker_call, 4),
+            ("E", "worker", thread_exit, 4),
+            ("E", "<toplevel>", end, 0),
+        ])
+    );
+
+    let trace_events = chrome_trace["traceEvents"].as_array().unwrap();
+    let thread_names: Vec<&str> = trace_events
+        .iter()
+        .filter(|event| event["name"] == "thread_name")
+        .map(|event| event["args"]["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(thread_names, vec!["thread 0", "thread 4"]);
+    let call = trace_events.iter().find(|event| event["name"] == "square" && event["ph"] == "B").unwrap();
+    assert_eq!(call["args"]["path"], "/src/main.py");
+    assert_eq!(call["args"]["line"], 10);
+    assert_eq!(call["args"]["arguments"]["n"]["i"], 3);
+    let ret = trace_events.iter().find(|event| event["name"] == "square" && event["ph"] == "E").unwrap();
+    assert_eq!(ret["args"]["return"]["i"], 9);
+    let instant = trace_events.iter().find(|event| event["ph"] == "i").unwrap();
+    assert_eq!((&instant["args"]["content"], &instant["s"]), (&Value::from("hello"), &Value::from("t")));
+}
+
+#[test]
+fn test_unwinds_and_tasks() {
+    let path = Path::new("/src/tasks.py");
+    let mut writer = NonStreamingTraceWriter::new("chrome", &[]);
+    let writer_ref: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::start(writer_ref, path, Line(1));
+    let fetch = TraceWriter::ensure_function_id(writer_ref, "fetch", path, Line(10));
+    let parse = TraceWriter::ensure_function_id(writer_ref, "parse", path, Line(20));
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::TaskSpawn(TaskId(1)));
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::TaskResume(TaskId(1)));
+    TraceWriter::register_call(writer_ref, fetch, vec![]);
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::TaskSuspend(TaskId(1)));
+    TraceWriter::register_call(writer_ref, parse, vec![]);
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::Throw(ThrowRecord { exception: NONE_VALUE }));
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::Unwind(UnwindRecord { frames: vec![parse] }));
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::TaskResume(TaskId(1)));
+    TraceWriter::register_return(writer_ref, NONE_VALUE);
+    TraceWriter::add_event(writer_ref, TraceLowLevelEvent::TaskComplete(TaskId(1)));
+    let events = TraceWriter::events(writer_ref).to_vec();
+
+    let chrome_trace = ChromeTraceExporter::export(&events);
+    let pids: Vec<u64> = chrome_trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["name"] == "fetch")
+        .map(|event| event["pid"].as_u64().unwrap())
+        .collect();
+    assert_eq!(pids, vec![2, 2]);
+    let kinds: Vec<(String, String)> = timeline(&chrome_trace).into_iter().map(|(ph, name, _, _)| (ph, name)).collect();
+    let expected: Vec<(String, String)> = [
+        ("B", "<toplevel>"),
+        ("B", "fetch"),
+        ("B", "parse"),
+        ("i", "throw"),
+        ("E", "parse"),
+        ("E", "fetch"),
+        ("E", "<toplevel>"),
+    ]
+    .iter()
+    .map(|(ph, name)| (ph.to_string(), name.to_string()))
+    .collect();
+    assert_eq!(kinds, expected);
+}
@@ -0,0 +1,59 @@
+//! Helpers shared by the integration tests: write a CTFS trace with a
+//! closure and read its events back.
+
+// every test crate compiles its own copy and uses only some of it
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use codetracer_trace_types::*;
+use codetracer_trace_writer::ctfs_writer::{CtfsTraceWriter, EventSerializationFormat};
+use codetracer_trace_writer::trace_writer::TraceWriter;
+
+/// An `int` value, for traces whose first type is `int` (type id 1, after
+/// the writer's own `None`).
+pub fn int(i: i64) -> ValueRecord {
+    ValueRecord::Int { i, type_id: TypeId(1) }
+}
+
+/// Helper: create a CtfsTraceWriter with default (SplitBinary) format,
+/// write some events, and return the .ct path.
+pub fn write_ctfs_trace(dir: &tempfile::TempDir, events_fn: impl FnOnce(&mut dyn TraceWriter)) -> PathBuf {
+    write_ctfs_trace_with_format(dir, EventSerializationFormat::SplitBinary, events_fn)
+}
+
+/// Helper: create a CtfsTraceWriter with a specific format.
+pub fn write_ctfs_trace_with_format(
+    dir: &tempfile::TempDir,
+    format: EventSerializationFormat,
+    events_fn: impl FnOnce(&mut dyn TraceWriter),
+) -> PathBuf {
+    let writer = match format {
+        EventSerializationFormat::Cbor => CtfsTraceWriter::new_cbor("test_program", &[]),
+        EventSerializationFormat::SplitBinary => CtfsTraceWriter::new("test_program", &[]),
+    };
+    write_ctfs_trace_with_writer(dir, writer, events_fn)
+}
+
+/// Helper: write the events with an already configured writer, e.g. one
+/// with small chunks.
+pub fn write_ctfs_trace_with_writer(dir: &tempfile::TempDir, mut writer: CtfsTraceWriter, events_fn: impl FnOnce(&mut dyn TraceWriter)) -> PathBuf {
+    let path = dir.path().join("trace");
+    let writer_ref: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::begin_writing_trace_events(writer_ref, &path).unwrap();
+    events_fn(writer_ref);
+    TraceWriter::finish_writing_trace_events(writer_ref).unwrap();
+    path.with_extension("ct")
+}
+
+/// Helper: read back all events of a .ct file.
+pub fn load_ctfs_trace(ct_path: &Path) -> Vec<TraceLowLevelEvent> {
+    let mut reader = codetracer_trace_reader::create_trace_reader(codetracer_trace_reader::TraceEventsFileFormat::Ctfs);
+    reader.load_trace_events(ct_path).unwrap()
+}
+
+/// Helper: write the events to a temporary .ct file and read them back.
+pub fn ctfs_roundtrip(events_fn: impl FnOnce(&mut dyn TraceWriter)) -> Vec<TraceLowLevelEvent> {
+    let dir = tempfile::tempdir().unwrap();
+    load_ctfs_trace(&write_ctfs_trace(&dir, events_fn))
+}
@@ -0,0 +1,117 @@
+//! Line and function coverage and its lcov and Cobertura exports.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::coverage::Coverage;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{ctfs_roundtrip, int};
+
+/// main.py calls square twice and util.py's helper once; one step is
+/// dropped again. The writer adds an entry step at each function's line.
+fn coverage_trace() -> Vec<TraceLowLevelEvent> {
+    ctfs_roundtrip(|writer| {
+        let main = Path::new("/src/main.py");
+        let util = Path::new("/src/lib/util.py");
+        TraceWriter::start(writer, main, Line(1));
+        TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+        let square = TraceWriter::ensure_function_id(writer, "square", main, Line(10));
+        let helper = TraceWriter::ensure_function_id(writer, "helper", util, Line(3));
+        TraceWriter::ensure_function_id(writer, "unused", util, Line(8));
+
+        TraceWriter::register_step(writer, main, Line(2));
+        for n in [2, 3] {
+            TraceWriter::register_step(writer, main, Line(3));
+            let arg = TraceWriter::arg(writer, "n", int(n));
+            TraceWriter::register_call(writer, square, vec![arg]);
+            TraceWriter::register_step(writer, main, Line(11));
+            TraceWriter::register_return(writer, int(n * n));
+        }
+        TraceWriter::register_call(writer, helper, vec![]);
+        TraceWriter::register_step(writer, util, Line(4));
+        TraceWriter::register_step(writer, util, Line(5));
+        TraceWriter::drop_last_step(writer);
+        TraceWriter::register_return(writer, NONE_VALUE);
+        TraceWriter::register_step(writer, main, Line(4));
+    })
+}
+
+#[test]
+fn test_line_and_function_hits() {
+    let coverage = Coverage::from_events(&coverage_trace());
+
+    let files = coverage.files();
+    let hits = |path: &str| -> Vec<(i64, u64)> { files[Path::new(path)].iter().map(|(line, hits)| (*line, *hits)).collect() };
+    assert_eq!(hits("/src/main.py"), vec![(2, 1), (3, 2), (4, 1), (10, 2), (11, 2)]);
+    // the dropped step is not a hit
+    assert_eq!(hits("/src/lib/util.py"), vec![(3, 1), (4, 1)]);
+
+    let function = |name: &str| coverage.functions().iter().find(|function| function.name == name).unwrap();
+    assert_eq!(function("square").calls, 2);
+    // the entry steps on line 10 run in square
+    assert_eq!(
+        function("square").lines.iter().map(|(line, hits)| (*line, *hits)).collect::<Vec<_>>(),
+        vec![(10, 2), (11, 2)]
+    );
+    assert_eq!(
+        function("<toplevel>").lines.iter().map(|(line, hits)| (*line, *hits)).collect::<Vec<_>>(),
+        vec![(2, 1), (3, 2), (4, 1)]
+    );
+    assert_eq!((function("helper").calls, function("helper").lines.len()), (1, 2));
+    assert_eq!(function("unused").calls, 0);
+}
+
+#[test]
+fn test_lcov_export() {
+    let lcov = Coverage::from_events(&coverage_trace()).to_lcov();
+    let expected = "\
+TN:
+SF:/src/lib/util.py
+FN:3,helper
+FN:8,unused
+FNDA:1,helper
+FNDA:0,unused
+FNF:2
+FNH:1
+DA:3,1
+DA:4,1
+LF:2
+LH:2
+end_of_record
+TN:
+SF:/src/main.py
+FN:1,<toplevel>
+FN:10,square
+FNDA:1,<toplevel>
+FNDA:2,square
+FNF:2
+FNH:2
+DA:2,1
+DA:3,2
+DA:4,1
+DA:10,2
+DA:11,2
+LF:5
+LH:5
+end_of_record
+";
+    assert_eq!(lcov, expected);
+}
+
+#[test]
+fn test_cobertura_export() {
+    let xml = Coverage::from_events(&coverage_trace()).to_cobertura();
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" ?>\n"));
+    assert!(xml.contains(r#"lines-covered="7" lines-valid="7""#));
+    assert!(xml.contains(r#"<package name="/src/lib" "#));
+    assert!(xml.contains(r#"<class name="main.py" filename="/src/main.py" "#));
+    assert!(xml.contains(r#"<method name="&lt;toplevel&gt;" signature="" line-rate="1" "#));
+    assert!(xml.contains(r#"<method name="unused" signature="" line-rate="0" "#));
+    assert!(xml.contains(r#"<line number="11" hits="2"/>"#));
+    assert_eq!(xml.matches("<class ").count(), 2);
+    assert_eq!(xml.matches("<package ").count(), 2);
+    assert!(xml.trim_end().ends_with("</coverage>"));
+}
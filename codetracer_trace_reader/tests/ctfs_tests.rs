@@ -2,32 +2,12 @@
 
 use std::path::Path;
 
+mod common;
+
 use codetracer_trace_types::*;
 use codetracer_trace_writer::ctfs_writer::EventSerializationFormat;
 use codetracer_trace_writer::trace_writer::TraceWriter;
-
-/// Helper: create a CtfsTraceWriter with default (SplitBinary) format,
-/// write some events, and return the .ct path.
-fn write_ctfs_trace(dir: &tempfile::TempDir, events_fn: impl FnOnce(&mut dyn TraceWriter)) -> std::path::PathBuf {
-    write_ctfs_trace_with_format(dir, EventSerializationFormat::SplitBinary, events_fn)
-}
-
-/// Helper: create a CtfsTraceWriter with a specific format.
-fn write_ctfs_trace_with_format(
-    dir: &tempfile::TempDir,
-    format: EventSerializationFormat,
-    events_fn: impl FnOnce(&mut dyn TraceWriter),
-) -> std::path::PathBuf {
-    let path = dir.path().join("trace");
-    let mut writer: Box<dyn TraceWriter + Send> = match format {
-        EventSerializationFormat::Cbor => Box::new(codetracer_trace_writer::ctfs_writer::CtfsTraceWriter::new_cbor("test_program", &[])),
-        EventSerializationFormat::SplitBinary => Box::new(codetracer_trace_writer::ctfs_writer::CtfsTraceWriter::new("test_program", &[])),
-    };
-    TraceWriter::begin_writing_trace_events(writer.as_mut(), &path).unwrap();
-    events_fn(writer.as_mut());
-    TraceWriter::finish_writing_trace_events(writer.as_mut()).unwrap();
-    path.with_extension("ct")
-}
+use common::{write_ctfs_trace, write_ctfs_trace_with_format};
 
 #[test]
 fn test_ctfs_writer_creates_ct_file() {
@@ -197,6 +177,46 @@ fn test_ctfs_container_has_expected_files() {
     assert!(!paths.is_empty(), "Expected at least one path registered");
 }
 
+#[test]
+fn test_ctfs_embeds_deduplicated_source_snapshots() {
+    use codetracer_trace_reader::ctfs_reader::read_source_snapshots;
+    use codetracer_trace_writer::source_snapshots::sha256_hex;
+
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src");
+    std::fs::create_dir(&src).unwrap();
+    let (a, b, missing) = (src.join("a.py"), src.join("b.py"), src.join("gone.py"));
+    std::fs::write(&a, "print(1)\n").unwrap();
+    std::fs::write(&b, "print(1)\n").unwrap();
+
+    let ct_path = write_ctfs_trace(&dir, |writer| {
+        TraceWriter::set_embed_sources(writer, true);
+        TraceWriter::start(writer, &a, Line(1));
+        TraceWriter::register_step(writer, &b, Line(1));
+        TraceWriter::register_step(writer, &missing, Line(1));
+    });
+
+    let snapshots = read_source_snapshots(&ct_path).unwrap().expect("sources were embedded");
+    let hash = sha256_hex(b"print(1)\n");
+    assert_eq!(snapshots.index.path_hashes, vec![Some(hash.clone()), Some(hash), None]);
+    // identical files are stored once
+    assert_eq!(snapshots.index.blobs.len(), 1);
+    assert_eq!(snapshots.content(PathId(1)), Some(&b"print(1)\n"[..]));
+    assert_eq!(snapshots.content(PathId(2)), None);
+
+    // the working copy changes after recording
+    std::fs::write(&a, "print(2)\n").unwrap();
+    assert_eq!(snapshots.is_stale(PathId(0), &std::fs::read(&a).unwrap()), Some(true));
+    assert_eq!(snapshots.is_stale(PathId(1), &std::fs::read(&b).unwrap()), Some(false));
+    assert_eq!(snapshots.is_stale(PathId(2), b""), None);
+
+    let plain_dir = tempfile::tempdir().unwrap();
+    let plain = write_ctfs_trace(&plain_dir, |writer| {
+        TraceWriter::start(writer, &a, Line(1));
+    });
+    assert!(read_source_snapshots(&plain).unwrap().is_none());
+}
+
 // ---- Split Binary format tests ----
 
 #[test]
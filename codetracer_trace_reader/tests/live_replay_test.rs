@@ -44,6 +44,7 @@ fn test_live_replay_during_recording() {
                 let event = TraceLowLevelEvent::Step(StepRecord {
                     path_id: PathId(0),
                     line: Line((batch * 20 + i) as i64),
+                    span: None,
                 });
                 AbstractTraceWriter::add_event(&mut writer, event);
                 events_written += 1;
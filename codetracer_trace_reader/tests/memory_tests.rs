@@ -0,0 +1,77 @@
+//! Memory reconstruction from `MemoryWrite`, `Alloc` and `Free` events.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::memory::MemoryModel;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::ctfs_roundtrip;
+
+const BLOCK: u64 = 0x1000_0ff8;
+
+/// A 16-byte heap block straddling a page boundary, filled and then
+/// partially overwritten at a later step, then freed.
+fn write_memory_trace(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/test/mem.c");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_alloc(writer, BLOCK, 16);
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::register_memory_write(writer, BLOCK, &[0xaa; 16]);
+    TraceWriter::register_step(writer, path, Line(4));
+    TraceWriter::register_memory_write(writer, BLOCK + 6, &[1, 2, 3, 4]);
+    TraceWriter::register_step(writer, path, Line(5));
+    TraceWriter::register_free(writer, BLOCK);
+}
+
+fn geid_of(events: &[TraceLowLevelEvent], predicate: impl Fn(&TraceLowLevelEvent) -> bool) -> u64 {
+    events.iter().position(predicate).unwrap() as u64
+}
+
+#[test]
+fn test_bytes_at_geid_after_ctfs_roundtrip() {
+    let events = ctfs_roundtrip(write_memory_trace);
+    let memory = MemoryModel::from_events(&events);
+
+    let alloc = geid_of(&events, |e| matches!(e, TraceLowLevelEvent::Alloc(_)));
+    let fill = geid_of(&events, |e| matches!(e, TraceLowLevelEvent::MemoryWrite(w) if w.bytes.len() == 16));
+    let patch = geid_of(&events, |e| matches!(e, TraceLowLevelEvent::MemoryWrite(w) if w.bytes.len() == 4));
+    let free = geid_of(&events, |e| matches!(e, TraceLowLevelEvent::Free(_)));
+
+    // before the first write nothing is known
+    assert_eq!(memory.bytes_at(BLOCK, 4, alloc), vec![None; 4]);
+    assert_eq!(memory.read(BLOCK + 4, 4, fill), Some(vec![0xaa; 4]));
+    // the patch spans the page boundary at BLOCK + 8
+    assert_eq!(memory.read(BLOCK + 4, 8, patch), Some(vec![0xaa, 0xaa, 1, 2, 3, 4, 0xaa, 0xaa]));
+    assert_eq!(memory.read(BLOCK + 4, 8, patch - 1), Some(vec![0xaa; 8]));
+    // a read running past the block has unknown bytes at the end
+    assert_eq!(memory.bytes_at(BLOCK + 14, 4, free), vec![Some(0xaa), Some(0xaa), None, None]);
+    assert_eq!(memory.read(BLOCK + 14, 4, free), None);
+    assert_eq!(memory.writes_to(BLOCK + 9, 1), vec![fill, patch]);
+    assert_eq!(memory.writes_to(BLOCK + 12, 4), vec![fill]);
+
+    let block = memory.allocation_at(BLOCK + 15, patch).unwrap();
+    assert_eq!(
+        (block.address, block.size, block.allocated_at, block.freed_at),
+        (BLOCK, 16, alloc, Some(free))
+    );
+    assert!(memory.allocation_at(BLOCK + 16, patch).is_none());
+    assert!(memory.allocation_at(BLOCK, free).is_none());
+    assert!(memory.allocation_at(BLOCK, alloc - 1).is_none());
+}
+
+#[test]
+fn test_reused_address_gets_new_allocation() {
+    let events = vec![
+        TraceLowLevelEvent::Alloc(AllocRecord { address: 0x40, size: 8 }),
+        TraceLowLevelEvent::Free(FreeRecord { address: 0x40 }),
+        TraceLowLevelEvent::Alloc(AllocRecord { address: 0x40, size: 32 }),
+    ];
+    let memory = MemoryModel::from_events(&events);
+    assert_eq!(memory.allocations().len(), 2);
+    assert_eq!(memory.allocation_at(0x50, 0), None);
+    assert_eq!(memory.allocation_at(0x50, 2).map(|a| a.size), Some(32));
+    assert_eq!(memory.allocation_at(0x41, 0).map(|a| a.size), Some(8));
+}
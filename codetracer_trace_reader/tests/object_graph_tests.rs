@@ -0,0 +1,100 @@
+//! Tests for shared-object dedup on the writer and resolution on the reader.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use codetracer_trace_reader::object_graph::ObjectGraph;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::object_identity::ObjectKey;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+
+fn values(events: &[TraceLowLevelEvent]) -> Vec<ValueRecord> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            TraceLowLevelEvent::Value(v) => Some(v.value.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_shared_reference_is_recorded_once() {
+    let mut writer = NonStreamingTraceWriter::new("objects", &[]);
+    let path = Path::new("/test/objects.py");
+    writer.start(path, Line(1));
+    writer.set_reference_dedup(true);
+    writer.register_step(path, Line(2));
+
+    let int_type = writer.ensure_type_id(TypeKind::Int, "int");
+    let ref_type = writer.ensure_type_id(TypeKind::Ref, "ref int");
+    let shared = ValueRecord::Reference {
+        dereferenced: Box::new(ValueRecord::Int { i: 42, type_id: int_type }),
+        address: 0x1000,
+        mutable: false,
+        type_id: ref_type,
+    };
+    writer.register_variable_with_full_value("a", shared.clone());
+    writer.register_variable_with_full_value("b", shared.clone());
+
+    let recorded = values(&writer.events);
+    assert!(matches!(recorded[0], ValueRecord::Object { .. }));
+    assert!(matches!(recorded[1], ValueRecord::BackRef { .. }));
+
+    let mut graph = ObjectGraph::new();
+    for event in &writer.events {
+        graph.observe(event);
+    }
+    assert_eq!(graph.len(), 1);
+    assert_eq!(graph.resolve(&recorded[0]), shared);
+    assert_eq!(graph.resolve(&recorded[1]), shared);
+    let ValueRecord::BackRef { id, .. } = recorded[1] else { unreachable!() };
+    let first = graph.get(id).unwrap();
+    assert!(Arc::ptr_eq(&first, &graph.get(id).unwrap()));
+
+    // a new step starts a new identity scope
+    writer.register_step(path, Line(3));
+    writer.register_variable_with_full_value("a", shared.clone());
+    assert!(matches!(values(&writer.events)[2], ValueRecord::Object { .. }));
+    graph.observe(&TraceLowLevelEvent::Step(StepRecord {
+        path_id: PathId(0),
+        line: Line(3),
+        span: None,
+    }));
+    assert!(graph.is_empty());
+}
+
+#[test]
+fn test_cycle_resolves_to_back_ref() {
+    let mut writer = NonStreamingTraceWriter::new("cycle", &[]);
+    let path = Path::new("/test/cycle.py");
+    writer.start(path, Line(1));
+    let node_type = writer.ensure_type_id(TypeKind::Struct, "Node");
+
+    // node.next = node
+    let (id, is_new) = writer.ensure_object_id(ObjectKey::Address(0x2000));
+    assert!(is_new);
+    let (again, is_new_again) = writer.ensure_object_id(ObjectKey::Address(0x2000));
+    assert_eq!((again, is_new_again), (id, false));
+    let node = ValueRecord::Object {
+        id,
+        value: Box::new(ValueRecord::Struct {
+            field_values: vec![ValueRecord::BackRef { id, type_id: node_type }],
+            type_id: node_type,
+        }),
+    };
+    writer.register_variable_with_full_value("node", node.clone());
+
+    let mut graph = ObjectGraph::new();
+    for event in &writer.events {
+        graph.observe(event);
+    }
+    assert_eq!(
+        graph.resolve(&node),
+        ValueRecord::Struct {
+            field_values: vec![ValueRecord::BackRef { id, type_id: node_type }],
+            type_id: node_type,
+        }
+    );
+}
@@ -0,0 +1,121 @@
+//! Step-count profiles, folded and as pprof.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::profile::Profile;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::ctfs_roundtrip;
+
+/// The main thread calls work, which is interrupted by a worker thread.
+fn profile_trace() -> Vec<TraceLowLevelEvent> {
+    ctfs_roundtrip(|writer| {
+        let path = Path::new("/src/main.py");
+        TraceWriter::start(writer, path, Line(1));
+        let work = TraceWriter::ensure_function_id(writer, "work", path, Line(10));
+        let worker = TraceWriter::ensure_function_id(writer, "run worker", path, Line(20));
+
+        TraceWriter::register_step(writer, path, Line(2));
+        TraceWriter::register_call(writer, work, vec![]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadStart(ThreadId(7)));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadSwitch(ThreadId(7)));
+        TraceWriter::register_call(writer, worker, vec![]);
+        TraceWriter::register_step(writer, path, Line(21));
+        TraceWriter::add_event(writer, TraceLowLevelEvent::ThreadSwitch(ThreadId(0)));
+        TraceWriter::register_step(writer, path, Line(12));
+        TraceWriter::register_return(writer, NONE_VALUE);
+        TraceWriter::register_step(writer, path, Line(3));
+        TraceWriter::register_step(writer, path, Line(4));
+        TraceWriter::drop_last_step(writer);
+    })
+}
+
+#[test]
+fn test_folded_stacks_per_thread() {
+    let profile = Profile::from_events(&profile_trace());
+
+    assert_eq!(profile.total_steps(), 8);
+    // the entry steps at lines 10 and 20 run in the callees
+    assert_eq!(
+        profile.to_folded(),
+        "thread-0;<toplevel> 2\nthread-0;<toplevel>;work 4\nthread-7;run_worker 2\n"
+    );
+}
+
+// (field number, varint value or length-delimited bytes)
+fn fields(mut bytes: &[u8]) -> Vec<(u64, Result<u64, Vec<u8>>)> {
+    fn varint(bytes: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = bytes[0];
+            *bytes = &bytes[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte < 0x80 {
+                return value;
+            }
+            shift += 7;
+        }
+    }
+    let mut fields = vec![];
+    while !bytes.is_empty() {
+        let key = varint(&mut bytes);
+        match key & 7 {
+            0 => fields.push((key >> 3, Ok(varint(&mut bytes)))),
+            2 => {
+                let len = varint(&mut bytes) as usize;
+                fields.push((key >> 3, Err(bytes[..len].to_vec())));
+                bytes = &bytes[len..];
+            }
+            wire_type => panic!("unexpected wire type {wire_type}"),
+        }
+    }
+    fields
+}
+
+fn bytes_of(value: &Result<u64, Vec<u8>>) -> &[u8] {
+    value.as_ref().unwrap_err()
+}
+
+#[test]
+fn test_pprof_export() {
+    let pprof = Profile::from_events(&profile_trace()).to_pprof();
+    let profile = fields(&pprof);
+
+    let strings: Vec<String> = profile
+        .iter()
+        .filter(|(field, _)| *field == 6)
+        .map(|(_, value)| String::from_utf8(bytes_of(value).to_vec()).unwrap())
+        .collect();
+    assert_eq!(strings[0], "");
+    for expected in ["steps", "count", "thread", "<toplevel>", "work", "run worker", "/src/main.py"] {
+        assert!(strings.iter().any(|string| string == expected), "{expected} missing");
+    }
+
+    // one sample per stack, thread and innermost line
+    let samples: Vec<_> = profile
+        .iter()
+        .filter(|(field, _)| *field == 2)
+        .map(|(_, value)| fields(bytes_of(value)))
+        .collect();
+    assert_eq!(samples.len(), 7);
+    let total: u64 = samples
+        .iter()
+        .map(|sample| {
+            let values = sample.iter().find(|(field, _)| *field == 2).unwrap();
+            fields(&[&[0x08], bytes_of(&values.1)].concat())[0].1.clone().unwrap()
+        })
+        .sum();
+    assert_eq!(total, 8);
+
+    let functions = profile.iter().filter(|(field, _)| *field == 5).count();
+    assert_eq!(functions, 3);
+    let locations = profile.iter().filter(|(field, _)| *field == 4).count();
+    // <toplevel> at 2, 3 and as a caller at 1, work at 10, 11, 12, worker at 20, 21
+    assert_eq!(locations, 8);
+    assert!(profile.iter().any(|(field, value)| *field == 12 && *value == Ok(1)));
+}
@@ -0,0 +1,133 @@
+//! Query parsing and evaluation over written traces.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::query::{Expr, Field, Literal, Op, Query, QueryEvaluator, QueryMatch};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{ctfs_roundtrip, int};
+
+/// main.py: x = 1, 2, 3 on lines 2..4, then parse(n) returning an error and
+/// an event from within parse.
+fn query_trace() -> Vec<TraceLowLevelEvent> {
+    ctfs_roundtrip(|writer| {
+        let path = Path::new("/src/app/main.py");
+        TraceWriter::start(writer, path, Line(1));
+        TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+        let error_type = TraceWriter::ensure_type_id(writer, TypeKind::Error, "ValueError");
+        let parse = TraceWriter::ensure_function_id(writer, "parse", path, Line(10));
+        for (line, x) in [(2, 1), (3, 2), (4, 3)] {
+            TraceWriter::register_step(writer, path, Line(line));
+            TraceWriter::register_variable_with_full_value(writer, "x", int(x));
+        }
+        let arg = TraceWriter::arg(writer, "n", int(7));
+        TraceWriter::register_call(writer, parse, vec![arg]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_special_event(writer, EventLogKind::Write, "", "parsing 7");
+        TraceWriter::register_return(
+            writer,
+            ValueRecord::Error {
+                msg: "bad input".to_string(),
+                type_id: error_type,
+            },
+        );
+        TraceWriter::register_step(writer, path, Line(5));
+    })
+}
+
+fn run(events: &[TraceLowLevelEvent], query: &str) -> Vec<QueryMatch> {
+    QueryEvaluator::run(Query::parse(query).unwrap(), events)
+}
+
+fn lines(matches: &[QueryMatch]) -> Vec<i64> {
+    matches.iter().map(|found| found.line.unwrap()).collect()
+}
+
+#[test]
+fn test_parse() {
+    let query = Query::parse("Step, return where not (line >= 3 or var.line = \"a\\\"b\")").unwrap();
+    assert_eq!(query.kinds, vec!["step".to_string(), "return".to_string()]);
+    let Some(Expr::Not(inner)) = query.condition else {
+        panic!("expected a negation");
+    };
+    let Expr::Or(left, right) = *inner else {
+        panic!("expected a disjunction");
+    };
+    assert!(matches!(*left, Expr::Compare(ref operand, Op::Ge, Literal::Int(3)) if operand.field == Field::Line));
+    assert!(matches!(*right, Expr::Compare(ref operand, Op::Eq, Literal::Str(ref text))
+        if operand.field == Field::Variable("line".to_string()) && text == "a\"b"));
+
+    assert_eq!(Query::parse("*").unwrap().kinds, Vec::<String>::new());
+    assert_eq!(Query::parse("where x ~ abc").unwrap().kinds, Vec::<String>::new());
+
+    let error = Query::parse("step where line >").unwrap_err();
+    assert_eq!(error.position, 17);
+    assert!(Query::parse("step where (line = 1").is_err());
+    assert!(Query::parse("step where line = \"open").is_err());
+    assert!(Query::parse("step where line ! 1").is_err());
+    assert!(Query::parse("step line = 1").is_err());
+}
+
+#[test]
+fn test_step_queries() {
+    let events = query_trace();
+
+    assert_eq!(lines(&run(&events, "step")), vec![2, 3, 4, 10, 11, 5]);
+    assert_eq!(
+        lines(&run(&events, "step where path = \"main.py\" and line >= 3 and line < 11")),
+        vec![3, 4, 10, 5]
+    );
+    assert!(run(&events, "step where path = \"ain.py\"").is_empty());
+    assert!(run(&events, "step where path != \"main.py\"").is_empty());
+    assert_eq!(lines(&run(&events, "step where path != \"ain.py\"")).len(), 6);
+    assert_eq!(lines(&run(&events, "step where path ~ app")).len(), 6);
+
+    // the values registered right after a step are part of it
+    assert_eq!(lines(&run(&events, "step where x > 1")), vec![3, 4, 5]);
+    assert_eq!(lines(&run(&events, "step where x = 2 or x.kind = Float")), vec![3]);
+    // the entry step belongs to the callee, where x is not defined
+    assert_eq!(lines(&run(&events, "step where function = parse")), vec![10, 11]);
+    assert_eq!(lines(&run(&events, "step where n = 7 and not x > 0")), vec![10, 11]);
+    assert_eq!(lines(&run(&events, "step where x.type = int and thread = 0")), vec![2, 3, 4, 5]);
+}
+
+#[test]
+fn test_event_queries() {
+    let events = query_trace();
+
+    let returns = run(&events, "return where function = parse and value.kind = Error");
+    assert_eq!(returns.len(), 1);
+    assert_eq!(returns[0].line, Some(11));
+    assert!(matches!(returns[0].event, TraceLowLevelEvent::Return(_)));
+    assert_eq!(run(&events, "return where value = \"bad input\" and value.type = ValueError").len(), 1);
+
+    let calls = run(&events, "call where function = parse");
+    assert_eq!(calls.len(), 1);
+    assert_eq!((calls[0].line, calls[0].call_key), (Some(10), CallKey(1)));
+
+    let writes = run(&events, "event where content ~ \"parsing\"");
+    assert_eq!(writes.len(), 1);
+    assert_eq!(writes[0].function.as_deref(), Some("parse"));
+
+    // every kind, still in GEID order
+    let all = run(&events, "* where geid >= 0");
+    assert_eq!(all.len(), events.len());
+    assert!(all.windows(2).all(|pair| pair[0].geid < pair[1].geid));
+    let values = run(&events, "value, step where kind = Value");
+    assert_eq!(values.len(), 4);
+}
+
+#[test]
+fn test_streaming_matches_come_out_in_order() {
+    let events = query_trace();
+    let mut evaluator = QueryEvaluator::new(Query::parse("step, call where line >= 4").unwrap());
+    let mut geids = vec![];
+    for (geid, event) in events.iter().enumerate() {
+        geids.extend(evaluator.observe(geid as u64, event).iter().map(|found| found.geid));
+    }
+    geids.extend(evaluator.finish().iter().map(|found| found.geid));
+    assert_eq!(geids.len(), 5);
+    assert!(geids.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,171 @@
+//! Replay cursor: stepping, breakpoints and watchpoints.
+
+use std::path::{Path, PathBuf};
+
+mod common;
+
+use codetracer_trace_reader::query::{Literal, Op};
+use codetracer_trace_reader::replay::{Breakpoint, EventSource, ReplayCursor, StopReason, Watchpoint};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::ctfs_writer::{CtfsTraceWriter, EventSerializationFormat};
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{int, write_ctfs_trace_with_writer};
+
+/// Steps, by `StepId`:
+///
+/// ```text
+/// 0  main.py:2    x = 1
+/// 1  main.py:3    square(1)
+/// 2  main.py:10     n = 1
+/// 3  main.py:11     r = 1
+/// 4  main.py:4    x = 2
+/// 5  main.py:3    square(2)
+/// 6  main.py:10     n = 2
+/// 7  main.py:11     r = 4
+/// 8  main.py:4    x = 5
+/// 9  main.py:5
+/// ```
+fn record(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/src/main.py");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(1));
+    for (n, x) in [(1, 2), (2, 5)] {
+        TraceWriter::register_step(writer, path, Line(3));
+        let arg = TraceWriter::arg(writer, "n", int(n));
+        TraceWriter::register_call(writer, square, vec![arg]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_variable_with_full_value(writer, "r", int(n * n));
+        TraceWriter::register_return(writer, int(n * n));
+        TraceWriter::register_step(writer, path, Line(4));
+        TraceWriter::register_variable_with_full_value(writer, "x", int(x));
+    }
+    TraceWriter::register_step(writer, path, Line(5));
+}
+
+/// The trace in a split-binary container with a few events per chunk, so
+/// moving around crosses chunks.
+fn open_chunked(dir: &tempfile::TempDir) -> ReplayCursor<codetracer_trace_reader::ctfs_reader::ChunkedEvents> {
+    let writer = CtfsTraceWriter::with_options("replay", &[], EventSerializationFormat::SplitBinary, 1024, 4);
+    ReplayCursor::open(&write_ctfs_trace_with_writer(dir, writer, record)).unwrap()
+}
+
+fn position<S: EventSource>(cursor: &mut ReplayCursor<S>) -> (i64, i64) {
+    (cursor.step_id().unwrap().0, cursor.location().unwrap().line)
+}
+
+#[test]
+fn test_stepping_stack_and_locals() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cursor = open_chunked(&dir);
+
+    assert_eq!(position(&mut cursor), (0, 2));
+    assert_eq!(cursor.locals(), vec![("x".to_string(), int(1))]);
+    assert_eq!(cursor.step_in(), StopReason::Step);
+    assert_eq!(cursor.step_in(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (2, 10));
+
+    let stack = cursor.stack();
+    let frames: Vec<(&str, Option<i64>)> = stack
+        .iter()
+        .map(|frame| (frame.function.as_str(), frame.location.as_ref().map(|location| location.line)))
+        .collect();
+    assert_eq!(frames, vec![("square", Some(10)), ("<toplevel>", Some(3))]);
+    assert_eq!(stack[0].location.as_ref().unwrap().path, PathBuf::from("/src/main.py"));
+    assert_eq!(cursor.locals(), vec![("n".to_string(), int(1))]);
+    assert_eq!(cursor.frame_locals(stack[1].call_key), vec![("x".to_string(), int(1))]);
+
+    cursor.step_in();
+    assert_eq!(cursor.locals(), vec![("n".to_string(), int(1)), ("r".to_string(), int(1))]);
+    assert_eq!(cursor.step_over(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (4, 4));
+    assert_eq!(cursor.locals(), vec![("x".to_string(), int(2))]);
+
+    cursor.step_in();
+    assert_eq!(cursor.step_over(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (8, 4));
+    assert_eq!(cursor.reverse_step(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (5, 3));
+    assert_eq!(cursor.reverse_step(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (4, 4));
+    assert_eq!(cursor.reverse_step_in(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (3, 11));
+    assert_eq!(cursor.reverse_step_out(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (1, 3));
+    cursor.step_in();
+    assert_eq!(cursor.step_out(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (4, 4));
+
+    assert_eq!(cursor.continue_forward(), StopReason::End);
+    assert_eq!(position(&mut cursor), (9, 5));
+    assert_eq!(cursor.step_in(), StopReason::End);
+    assert_eq!(cursor.reverse_continue(), StopReason::Start);
+    assert_eq!(position(&mut cursor), (0, 2));
+}
+
+#[test]
+fn test_breakpoints() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cursor = open_chunked(&dir);
+
+    let line = cursor.add_breakpoint(Breakpoint::Line {
+        path: PathBuf::from("main.py"),
+        line: 11,
+    });
+    assert_eq!(cursor.continue_forward(), StopReason::Breakpoint(line));
+    assert_eq!(position(&mut cursor), (3, 11));
+    assert_eq!(cursor.continue_forward(), StopReason::Breakpoint(line));
+    assert_eq!(position(&mut cursor), (7, 11));
+    assert_eq!(cursor.continue_forward(), StopReason::End);
+    assert_eq!(cursor.reverse_continue(), StopReason::Breakpoint(line));
+    assert_eq!(position(&mut cursor), (7, 11));
+
+    // moves other than single steps stop at breakpoints too
+    let function = cursor.add_breakpoint(Breakpoint::Function("square".to_string()));
+    assert_eq!(cursor.reverse_step(), StopReason::Breakpoint(function));
+    assert_eq!(position(&mut cursor), (6, 10));
+    assert_eq!(cursor.reverse_step(), StopReason::Step);
+    assert_eq!(position(&mut cursor), (5, 3));
+    assert_eq!(cursor.step_over(), StopReason::Breakpoint(function));
+    assert_eq!(position(&mut cursor), (6, 10));
+
+    assert!(cursor.remove_breakpoint(line));
+    assert!(!cursor.remove_breakpoint(line));
+    assert_eq!(cursor.reverse_continue(), StopReason::Breakpoint(function));
+    assert_eq!(position(&mut cursor), (2, 10));
+    cursor.clear_breakpoints();
+    assert_eq!(cursor.continue_forward(), StopReason::End);
+}
+
+#[test]
+fn test_watchpoints() {
+    let mut writer = NonStreamingTraceWriter::new("replay", &[]);
+    record(&mut writer);
+    let mut cursor = ReplayCursor::new(TraceWriter::events(&writer).to_vec());
+
+    let x = cursor.add_watchpoint(Watchpoint {
+        variable: "x".to_string(),
+        condition: None,
+    });
+    assert_eq!(cursor.continue_forward(), StopReason::Watchpoint(x));
+    assert_eq!(position(&mut cursor), (4, 4));
+    assert_eq!(cursor.continue_forward(), StopReason::Watchpoint(x));
+    assert_eq!(position(&mut cursor), (8, 4));
+    assert_eq!(cursor.reverse_continue(), StopReason::Watchpoint(x));
+    assert_eq!(position(&mut cursor), (4, 4));
+    cursor.remove_breakpoint(x);
+
+    // the arguments of a call change `n` at the callee's entry step
+    let big_n = cursor.add_watchpoint(Watchpoint {
+        variable: "n".to_string(),
+        condition: Some((Op::Ge, Literal::Int(2))),
+    });
+    assert_eq!(cursor.continue_forward(), StopReason::Watchpoint(big_n));
+    assert_eq!(position(&mut cursor), (6, 10));
+    assert_eq!(cursor.continue_forward(), StopReason::End);
+    assert_eq!(cursor.reverse_continue(), StopReason::Watchpoint(big_n));
+    assert_eq!(cursor.reverse_continue(), StopReason::Start);
+}
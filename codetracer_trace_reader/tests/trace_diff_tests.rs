@@ -0,0 +1,137 @@
+//! Diffing independently recorded traces.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::trace_diff::{CallCountDiff, DiffEntry, TraceDiff};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::ctfs_roundtrip;
+
+struct Run {
+    /// Intern some unrelated names first, shifting every id.
+    extra_definitions: bool,
+    /// The value of `x` on the second step.
+    second_x: i64,
+    /// How often `square` is called.
+    calls: usize,
+}
+
+const RUN: Run = Run {
+    extra_definitions: false,
+    second_x: 2,
+    calls: 2,
+};
+
+fn record(writer: &mut dyn TraceWriter, run: &Run) {
+    let path = Path::new("/test/diff.py");
+    if run.extra_definitions {
+        TraceWriter::ensure_path_id(writer, Path::new("/test/other.py"));
+    }
+    TraceWriter::start(writer, path, Line(1));
+    if run.extra_definitions {
+        TraceWriter::ensure_type_id(writer, TypeKind::String, "str");
+        TraceWriter::ensure_function_id(writer, "unused", path, Line(20));
+        TraceWriter::ensure_variable_id(writer, "unused");
+    }
+    let int_type = TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let int = |i: i64| ValueRecord::Int { i, type_id: int_type };
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(1));
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(run.second_x));
+    for n in 0..run.calls as i64 {
+        let arg = TraceWriter::arg(writer, "n", int(n));
+        TraceWriter::register_call(writer, square, vec![arg]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_return(writer, int(n * n));
+    }
+    TraceWriter::register_step(writer, path, Line(4));
+    TraceWriter::register_special_event(writer, EventLogKind::Write, "", "done");
+}
+
+fn events(run: &Run) -> Vec<TraceLowLevelEvent> {
+    let mut writer = NonStreamingTraceWriter::new("diff", &[]);
+    record(&mut writer, run);
+    TraceWriter::events(&writer).to_vec()
+}
+
+#[test]
+fn test_interning_order_does_not_matter() {
+    let shifted = ctfs_roundtrip(|writer| {
+        record(
+            writer,
+            &Run {
+                extra_definitions: true,
+                ..RUN
+            },
+        )
+    });
+
+    let left = events(&RUN);
+    assert_ne!(left.len(), shifted.len());
+    let diff = TraceDiff::compute(&left, &shifted);
+    assert!(diff.is_equivalent(), "{:?}", diff.divergence);
+    assert_eq!(diff.matching_steps, 7);
+    assert!(diff.call_counts.is_empty());
+}
+
+#[test]
+fn test_first_divergent_value() {
+    let left = events(&RUN);
+    let right = events(&Run {
+        extra_definitions: true,
+        second_x: 3,
+        ..RUN
+    });
+
+    let diff = TraceDiff::compute(&left, &right);
+    let divergence = diff.divergence.unwrap();
+    assert_eq!(
+        divergence.left,
+        Some(DiffEntry::Value {
+            name: "x".to_string(),
+            value: "2: int".to_string()
+        })
+    );
+    assert_eq!(divergence.right.as_ref().unwrap().to_string(), "value x = 3: int");
+    assert!(matches!(left[divergence.left_geid.unwrap() as usize], TraceLowLevelEvent::Value(_)));
+    assert!(matches!(right[divergence.right_geid.unwrap() as usize], TraceLowLevelEvent::Value(_)));
+    assert_ne!(divergence.left_geid, divergence.right_geid);
+    assert_eq!(divergence.last_step, Some(("/test/diff.py".to_string(), 3)));
+    assert_eq!(divergence.stack, vec!["<toplevel>".to_string()]);
+    assert_eq!(diff.matching_steps, 2);
+    assert!(diff.call_counts.is_empty());
+}
+
+#[test]
+fn test_extra_call_and_call_counts() {
+    let left = events(&RUN);
+    let right = events(&Run { calls: 3, ..RUN });
+
+    let diff = TraceDiff::compute(&left, &right);
+    let divergence = diff.divergence.unwrap();
+    // the third call's argument comes first, then its entry step
+    assert_eq!(divergence.left.unwrap().to_string(), "step /test/diff.py:4 (thread 0)");
+    assert_eq!(divergence.right.unwrap().to_string(), "value n = 2: int");
+    assert_eq!(divergence.last_step, Some(("/test/diff.py".to_string(), 11)));
+    assert_eq!(
+        diff.call_counts,
+        vec![CallCountDiff {
+            function: "square".to_string(),
+            left: 2,
+            right: 3
+        }]
+    );
+
+    // a trace that is a prefix of the other one
+    let diff = TraceDiff::compute(&left, &left[..left.len() - 1]);
+    let divergence = diff.divergence.unwrap();
+    assert!(matches!(divergence.left, Some(DiffEntry::Event { .. })));
+    assert_eq!((divergence.right, divergence.right_geid), (None, None));
+    assert_eq!(diff.matching_steps, 7);
+}
@@ -0,0 +1,301 @@
+//! Slicing a GEID range out of a trace into a trace of its own.
+
+use std::path::{Path, PathBuf};
+
+mod common;
+
+use codetracer_trace_reader::ctfs_reader::ChunkedEvents;
+use codetracer_trace_reader::trace_slice::{TraceSlicer, slice_ctfs};
+use codetracer_trace_reader::validator::Validator;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::ctfs_writer::{CtfsTraceWriter, EventSerializationFormat};
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::write_ctfs_trace_with_writer;
+
+/// `square` is called twice, after some definitions no slice of the calls
+/// uses, so slicing has to renumber the ones it keeps.
+fn record(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/src/main.py");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::ensure_type_id(writer, TypeKind::String, "str");
+    let int_type = TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let int = |i: i64| ValueRecord::Int { i, type_id: int_type };
+    TraceWriter::ensure_function_id(writer, "unused", Path::new("/src/lib.py"), Line(1));
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+    TraceWriter::ensure_variable_id(writer, "unused");
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(1));
+    for n in [1, 2] {
+        TraceWriter::register_step(writer, path, Line(3));
+        let arg = TraceWriter::arg(writer, "n", int(n));
+        TraceWriter::register_call(writer, square, vec![arg]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_variable_with_full_value(writer, "r", int(n * n));
+        TraceWriter::register_return(writer, int(n * n));
+        TraceWriter::register_step(writer, path, Line(4));
+    }
+}
+
+fn recorded() -> Vec<TraceLowLevelEvent> {
+    let mut writer = NonStreamingTraceWriter::new("slice", &[]);
+    record(&mut writer);
+    TraceWriter::events(&writer).to_vec()
+}
+
+// the GEID of the `nth` step at `line`
+fn step_geid(events: &[TraceLowLevelEvent], line: i64, nth: usize) -> u64 {
+    let geid = events
+        .iter()
+        .enumerate()
+        .filter(|(_, event)| matches!(event, TraceLowLevelEvent::Step(step) if step.line == Line(line)))
+        .nth(nth)
+        .unwrap()
+        .0;
+    geid as u64
+}
+
+fn debug(events: &[TraceLowLevelEvent]) -> Vec<String> {
+    events.iter().map(|event| format!("{event:?}")).collect()
+}
+
+#[test]
+fn test_slice_reemits_definitions_and_calls() {
+    let events = recorded();
+    // the body of the second square call, up to its return
+    let from = step_geid(&events, 11, 1);
+    let slice = TraceSlicer::slice(&events, from, from + 3);
+
+    assert_eq!(Validator::validate(&slice), vec![]);
+    let paths: Vec<&PathBuf> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Path(path) => Some(path),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(paths, vec![&PathBuf::from("/src/main.py")]);
+    let functions: Vec<&str> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Function(function) => Some(function.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(functions, vec!["<toplevel>", "square"]);
+    let types: Vec<&str> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Type(type_record) => Some(type_record.lang_type.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(types, vec!["None", "int"]);
+    let variables: Vec<&str> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::VariableName(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(variables, vec!["n", "r"]);
+
+    let int = |i: i64| ValueRecord::Int { i, type_id: TypeId(1) };
+    let rest = &slice[paths.len() + functions.len() + types.len() + variables.len()..];
+    let expected = vec![
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: FunctionId(0),
+            args: vec![],
+        }),
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: FunctionId(1),
+            args: vec![FullValueRecord {
+                variable_id: VariableId(0),
+                value: int(2),
+            }],
+        }),
+        TraceLowLevelEvent::Step(StepRecord {
+            path_id: PathId(0),
+            line: Line(11),
+            span: None,
+        }),
+        TraceLowLevelEvent::Value(FullValueRecord {
+            variable_id: VariableId(1),
+            value: int(4),
+        }),
+        TraceLowLevelEvent::Return(ReturnRecord { return_value: int(4) }),
+    ];
+    assert_eq!(debug(rest), debug(&expected));
+}
+
+#[test]
+fn test_slice_split_binary_ctfs() {
+    let dir = tempfile::tempdir().unwrap();
+    let writer = CtfsTraceWriter::with_options("slice", &[], EventSerializationFormat::SplitBinary, 1024, 4);
+    let trace_path = write_ctfs_trace_with_writer(&dir, writer, record);
+
+    let events = recorded();
+    // from the value of `r` in the first call: the step it belongs to is
+    // repeated
+    let from = step_geid(&events, 11, 0) + 1;
+    let to = step_geid(&events, 4, 1) + 1;
+    let slice = slice_ctfs(&trace_path, from, to).unwrap();
+    assert_eq!(debug(&slice), debug(&TraceSlicer::slice(&events, from, to)));
+    assert_eq!(Validator::validate(&slice), vec![]);
+    let lines: Vec<i64> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Step(step) => Some(step.line.0),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lines, vec![11, 4, 3, 10, 11, 4]);
+
+    // a range past the end stops at the end
+    let tail = slice_ctfs(&trace_path, from, u64::MAX).unwrap();
+    assert_eq!(debug(&tail), debug(&TraceSlicer::slice(&events, from, events.len() as u64)));
+}
+
+#[test]
+fn test_slice_unchunked_cbor_ctfs() {
+    let dir = tempfile::tempdir().unwrap();
+    let trace_path = write_ctfs_trace_with_writer(&dir, CtfsTraceWriter::new_cbor("slice", &[]), record);
+
+    let events = recorded();
+    let from = step_geid(&events, 11, 1);
+    let slice = slice_ctfs(&trace_path, from, from + 3).unwrap();
+    assert_eq!(debug(&slice), debug(&TraceSlicer::slice(&events, from, from + 3)));
+
+    // going back restarts the stream
+    let mut chunked = ChunkedEvents::open(&trace_path).unwrap();
+    assert_eq!(chunked.event_count(), events.len() as u64);
+    let last = events.len() as u64 - 1;
+    assert_eq!(format!("{:?}", chunked.event(last).unwrap()), format!("{:?}", events[last as usize]));
+    assert_eq!(format!("{:?}", chunked.event(1).unwrap()), format!("{:?}", events[1]));
+}
+
+#[test]
+fn test_slice_defines_objects_from_before_it() {
+    let mut writer = NonStreamingTraceWriter::new("objects", &[]);
+    let path = Path::new("/src/objects.py");
+    TraceWriter::start(&mut writer, path, Line(1));
+    TraceWriter::set_reference_dedup(&mut writer, true);
+    let int_type = TraceWriter::ensure_type_id(&mut writer, TypeKind::Int, "int");
+    let ref_type = TraceWriter::ensure_type_id(&mut writer, TypeKind::Ref, "ref int");
+    let shared = ValueRecord::Reference {
+        dereferenced: Box::new(ValueRecord::Int { i: 42, type_id: int_type }),
+        address: 0x1000,
+        mutable: false,
+        type_id: ref_type,
+    };
+    TraceWriter::register_step(&mut writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(&mut writer, "a", shared.clone());
+    TraceWriter::register_variable_with_full_value(&mut writer, "n", ValueRecord::Int { i: 1, type_id: int_type });
+    TraceWriter::register_variable_with_full_value(&mut writer, "b", shared.clone());
+    TraceWriter::register_variable_with_full_value(&mut writer, "c", shared.clone());
+    let events = TraceWriter::events(&writer).to_vec();
+    assert_eq!(Validator::validate(&events), vec![]);
+
+    // from `n`: `a`, which defined the object, is left out
+    let from = events
+        .iter()
+        .enumerate()
+        .filter(|(_, event)| matches!(event, TraceLowLevelEvent::Value(_)))
+        .nth(1)
+        .unwrap()
+        .0 as u64;
+    let slice = TraceSlicer::slice(&events, from, events.len() as u64);
+    assert_eq!(Validator::validate(&slice), vec![]);
+    let values: Vec<&ValueRecord> = slice
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Value(full_value) => Some(&full_value.value),
+            _ => None,
+        })
+        .collect();
+    assert!(matches!(values[1], ValueRecord::Object { .. }));
+    assert!(matches!(values[2], ValueRecord::BackRef { .. }));
+}
+
+#[test]
+fn test_slice_threads_and_tasks() {
+    let step = |line: i64| {
+        TraceLowLevelEvent::Step(StepRecord {
+            path_id: PathId(0),
+            line: Line(line),
+            span: None,
+        })
+    };
+    let call = |function_id: usize| {
+        TraceLowLevelEvent::Call(CallRecord {
+            function_id: FunctionId(function_id),
+            args: vec![],
+        })
+    };
+    let function = |name: &str, line: i64| {
+        TraceLowLevelEvent::Function(FunctionRecord {
+            path_id: PathId(0),
+            line: Line(line),
+            name: name.to_string(),
+            span: None,
+        })
+    };
+    let none = ValueRecord::None { type_id: TypeId(0) };
+    let events = vec![
+        TraceLowLevelEvent::Path(PathBuf::from("/src/a.py")),
+        function("<toplevel>", 1),
+        call(0),
+        TraceLowLevelEvent::Type(TypeRecord {
+            kind: TypeKind::None,
+            lang_type: "None".to_string(),
+            specific_info: TypeSpecificInfo::None,
+        }),
+        function("worker", 10),
+        function("job", 20),
+        step(2),
+        TraceLowLevelEvent::ThreadStart(ThreadId(1)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        call(1),
+        step(11),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(0)),
+        TraceLowLevelEvent::TaskSpawn(TaskId(7)),
+        TraceLowLevelEvent::TaskResume(TaskId(7)),
+        call(2),
+        step(21),
+        TraceLowLevelEvent::TaskSuspend(TaskId(7)),
+        // the slice starts here
+        step(3),
+        TraceLowLevelEvent::TaskResume(TaskId(7)),
+        step(22),
+        TraceLowLevelEvent::Return(ReturnRecord { return_value: none.clone() }),
+        TraceLowLevelEvent::TaskComplete(TaskId(7)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        step(12),
+        TraceLowLevelEvent::Return(ReturnRecord { return_value: none }),
+        TraceLowLevelEvent::ThreadExit(ThreadId(1)),
+    ];
+    assert_eq!(Validator::validate(&events), vec![]);
+
+    let slice = TraceSlicer::slice(&events, 17, events.len() as u64);
+    assert_eq!(Validator::validate(&slice), vec![]);
+    // the definitions, then the worker thread, then the main thread with
+    // the suspended task
+    let expected = vec![
+        events[0].clone(),
+        events[1].clone(),
+        events[4].clone(),
+        events[5].clone(),
+        events[3].clone(),
+        TraceLowLevelEvent::ThreadStart(ThreadId(1)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        call(1),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(0)),
+        call(0),
+        TraceLowLevelEvent::TaskSpawn(TaskId(7)),
+        TraceLowLevelEvent::TaskResume(TaskId(7)),
+        call(2),
+        TraceLowLevelEvent::TaskSuspend(TaskId(7)),
+    ];
+    assert_eq!(debug(&slice[..expected.len()]), debug(&expected));
+    assert_eq!(debug(&slice[expected.len()..]), debug(&events[17..]));
+}
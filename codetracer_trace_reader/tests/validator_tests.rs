@@ -0,0 +1,234 @@
+//! Semantic validation of event streams.
+
+use std::path::{Path, PathBuf};
+
+mod common;
+
+use codetracer_trace_reader::validator::{Severity, ValidationError, ValidationErrorKind, Validator};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::ctfs_roundtrip;
+
+fn write_well_formed_trace(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/test/ok.py");
+    TraceWriter::start(writer, path, Line(1));
+    let int_type = TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let err_type = TraceWriter::ensure_type_id(writer, TypeKind::Error, "ValueError");
+    let outer = TraceWriter::ensure_function_id(writer, "outer", path, Line(10));
+    let inner = TraceWriter::ensure_function_id(writer, "inner", path, Line(20));
+    let exception = ValueRecord::Error {
+        msg: "boom".to_string(),
+        type_id: err_type,
+    };
+
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "x", ValueRecord::Int { i: 1, type_id: int_type });
+    let arg = TraceWriter::arg(writer, "a", ValueRecord::Int { i: 2, type_id: int_type });
+    TraceWriter::register_call(writer, outer, vec![arg]);
+    TraceWriter::register_step(writer, path, Line(11));
+    TraceWriter::register_step(writer, path, Line(12));
+    TraceWriter::drop_last_step(writer);
+    TraceWriter::register_call(writer, inner, vec![]);
+    TraceWriter::register_throw(writer, exception.clone());
+    TraceWriter::register_unwind(writer, &[inner]);
+    TraceWriter::register_catch(writer, exception);
+    TraceWriter::register_return(writer, ValueRecord::Int { i: 3, type_id: int_type });
+    TraceWriter::bind_variable(writer, "x", Place(7));
+    TraceWriter::drop_variables(writer, &["x".to_string()]);
+}
+
+#[test]
+fn test_writer_output_is_valid_after_ctfs_roundtrip() {
+    let events = ctfs_roundtrip(write_well_formed_trace);
+
+    assert_eq!(Validator::validate(&events), vec![]);
+}
+
+fn step(path_id: usize) -> TraceLowLevelEvent {
+    TraceLowLevelEvent::Step(StepRecord {
+        path_id: PathId(path_id),
+        line: Line(1),
+        span: None,
+    })
+}
+
+fn call(function_id: usize) -> TraceLowLevelEvent {
+    TraceLowLevelEvent::Call(CallRecord {
+        function_id: FunctionId(function_id),
+        args: vec![],
+    })
+}
+
+fn function(path_id: usize) -> TraceLowLevelEvent {
+    TraceLowLevelEvent::Function(FunctionRecord {
+        path_id: PathId(path_id),
+        line: Line(1),
+        name: "f".to_string(),
+        span: None,
+    })
+}
+
+fn error(geid: u64, kind: ValidationErrorKind) -> ValidationError {
+    ValidationError { geid, kind }
+}
+
+#[test]
+fn test_reports_use_before_definition_with_geids() {
+    let events = vec![
+        step(0),
+        TraceLowLevelEvent::Path(PathBuf::from("/test/bad.py")),
+        function(0),
+        call(1),
+        TraceLowLevelEvent::Value(FullValueRecord {
+            variable_id: VariableId(0),
+            value: ValueRecord::Int { i: 1, type_id: TypeId(0) },
+        }),
+        TraceLowLevelEvent::VariableName("x".to_string()),
+        TraceLowLevelEvent::Type(TypeRecord {
+            kind: TypeKind::Int,
+            lang_type: "int".to_string(),
+            specific_info: TypeSpecificInfo::None,
+        }),
+        TraceLowLevelEvent::Value(FullValueRecord {
+            variable_id: VariableId(0),
+            value: ValueRecord::Int { i: 1, type_id: TypeId(0) },
+        }),
+    ];
+
+    assert_eq!(
+        Validator::validate(&events),
+        vec![
+            error(0, ValidationErrorKind::UndefinedPath(PathId(0))),
+            error(3, ValidationErrorKind::UndefinedFunction(FunctionId(1))),
+            error(4, ValidationErrorKind::UndefinedVariable(VariableId(0))),
+            error(4, ValidationErrorKind::UndefinedType(TypeId(0))),
+        ]
+    );
+}
+
+#[test]
+fn test_reports_unbalanced_calls_per_thread() {
+    let events = vec![
+        TraceLowLevelEvent::Path(PathBuf::from("/test/threads.py")),
+        TraceLowLevelEvent::Type(TypeRecord {
+            kind: TypeKind::None,
+            lang_type: "None".to_string(),
+            specific_info: TypeSpecificInfo::None,
+        }),
+        function(0),
+        function(0),
+        call(0),
+        TraceLowLevelEvent::ThreadStart(ThreadId(1)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(1)),
+        // the main thread's call is not open on thread 1
+        TraceLowLevelEvent::Return(ReturnRecord { return_value: NONE_VALUE }),
+        call(1),
+        TraceLowLevelEvent::Unwind(UnwindRecord { frames: vec![FunctionId(0)] }),
+        call(1),
+        TraceLowLevelEvent::ThreadExit(ThreadId(1)),
+        TraceLowLevelEvent::ThreadSwitch(ThreadId(0)),
+        TraceLowLevelEvent::Unwind(UnwindRecord {
+            frames: vec![FunctionId(0), FunctionId(0)],
+        }),
+    ];
+
+    let problems = Validator::validate(&events);
+    assert_eq!(
+        problems.iter().map(|problem| (problem.geid, problem.severity())).collect::<Vec<_>>(),
+        vec![(7, Severity::Error), (9, Severity::Error), (11, Severity::Warning), (13, Severity::Error)]
+    );
+    assert_eq!(
+        problems[1].kind,
+        ValidationErrorKind::UnwindMismatch {
+            expected: FunctionId(1),
+            found: FunctionId(0),
+        }
+    );
+    assert_eq!(
+        problems[2].kind,
+        ValidationErrorKind::OpenCallsAtThreadExit {
+            thread_id: ThreadId(1),
+            open: 1,
+        }
+    );
+    assert_eq!(problems[3].kind, ValidationErrorKind::UnwindPastStack { frames: 2, open: 1 });
+    assert_eq!(problems[0].to_string(), "error: geid 7: Return without an open Call");
+}
+
+#[test]
+fn test_drop_last_step_needs_a_step() {
+    let events = vec![
+        TraceLowLevelEvent::Path(PathBuf::from("/test/drop.py")),
+        TraceLowLevelEvent::DropLastStep,
+        step(0),
+        TraceLowLevelEvent::DropLastStep,
+        TraceLowLevelEvent::DropLastStep,
+    ];
+
+    assert_eq!(
+        Validator::validate(&events),
+        vec![
+            error(1, ValidationErrorKind::DropLastStepWithoutStep),
+            error(4, ValidationErrorKind::DropLastStepWithoutStep),
+        ]
+    );
+}
+
+#[test]
+fn test_type_records_may_refer_forward_but_not_past_the_end() {
+    let list = |next: usize| {
+        TraceLowLevelEvent::Type(TypeRecord {
+            kind: TypeKind::Struct,
+            lang_type: "Node".to_string(),
+            specific_info: TypeSpecificInfo::Struct {
+                fields: vec![FieldTypeRecord {
+                    name: "next".to_string(),
+                    type_id: TypeId(next),
+                }],
+            },
+        })
+    };
+    let events = vec![list(1), list(0), list(5)];
+
+    assert_eq!(
+        Validator::validate(&events),
+        vec![error(2, ValidationErrorKind::UndefinedType(TypeId(5)))]
+    );
+}
+
+#[test]
+fn test_back_references_do_not_survive_a_step() {
+    let object = ValueRecord::Object {
+        id: ObjectId(1),
+        value: Box::new(NONE_VALUE),
+    };
+    let back_ref = ValueRecord::BackRef {
+        id: ObjectId(1),
+        type_id: NONE_TYPE_ID,
+    };
+    let value = |value: ValueRecord| {
+        TraceLowLevelEvent::Value(FullValueRecord {
+            variable_id: VariableId(0),
+            value,
+        })
+    };
+    let events = vec![
+        TraceLowLevelEvent::Path(PathBuf::from("/test/objects.py")),
+        TraceLowLevelEvent::Type(TypeRecord {
+            kind: TypeKind::None,
+            lang_type: "None".to_string(),
+            specific_info: TypeSpecificInfo::None,
+        }),
+        TraceLowLevelEvent::VariableName("x".to_string()),
+        step(0),
+        value(object),
+        value(back_ref.clone()),
+        step(0),
+        value(back_ref),
+    ];
+
+    assert_eq!(
+        Validator::validate(&events),
+        vec![error(7, ValidationErrorKind::UndefinedObject(ObjectId(1)))]
+    );
+}
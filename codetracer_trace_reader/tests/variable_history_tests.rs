@@ -0,0 +1,132 @@
+//! Variable timelines from value, binding and place events.
+
+use std::path::Path;
+
+mod common;
+
+use codetracer_trace_reader::variable_history::{ChangeKind, VariableHistory};
+use codetracer_trace_types::*;
+use codetracer_trace_writer::non_streaming_trace_writer::NonStreamingTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use common::{ctfs_roundtrip, int};
+
+/// toplevel: x = 1; x = 2; square(2) -> square(1), recursively.
+fn write_recursive_trace(writer: &mut dyn TraceWriter) {
+    let path = Path::new("/test/history.py");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(1));
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(1));
+    TraceWriter::register_variable_with_full_value(writer, "x", int(2));
+    // a local of the caller, right before the call's entry step
+    TraceWriter::register_variable_with_full_value(writer, "n", int(5));
+    let arg = TraceWriter::arg(writer, "n", int(2));
+    TraceWriter::register_call(writer, square, vec![arg]);
+    TraceWriter::register_step(writer, path, Line(11));
+    TraceWriter::register_variable_with_full_value(writer, "n", int(2));
+    let arg = TraceWriter::arg(writer, "n", int(1));
+    TraceWriter::register_call(writer, square, vec![arg]);
+    TraceWriter::register_step(writer, path, Line(11));
+    TraceWriter::register_return(writer, int(1));
+    TraceWriter::register_step(writer, path, Line(12));
+    TraceWriter::register_return(writer, int(4));
+}
+
+#[test]
+fn test_values_per_call_after_ctfs_roundtrip() {
+    let events = ctfs_roundtrip(write_recursive_trace);
+
+    let history = VariableHistory::from_events(&events);
+
+    // the repeated x = 1 is not a change
+    let x: Vec<(Option<i64>, Option<ValueRecord>)> = history
+        .timeline_by_name("x")
+        .iter()
+        .map(|change| (change.step_id.map(|step_id| step_id.0), change.value.clone()))
+        .collect();
+    assert_eq!(x, vec![(Some(0), Some(int(1))), (Some(1), Some(int(2)))]);
+
+    // the arguments belong to the callees and their entry steps, the
+    // caller's own n = 5 stays in the toplevel call
+    let n: Vec<(i64, Option<i64>, Option<ValueRecord>)> = history
+        .timeline_by_name("n")
+        .iter()
+        .map(|change| (change.call_key.0, change.step_id.map(|step_id| step_id.0), change.value.clone()))
+        .collect();
+    assert_eq!(
+        n,
+        vec![(0, Some(1), Some(int(5))), (1, Some(2), Some(int(2))), (2, Some(4), Some(int(1)))]
+    );
+    assert!(history.timeline_by_name("n").iter().all(|change| change.kind == ChangeKind::Value));
+
+    let n_id = history.variable_id("n").unwrap();
+    let end = events.len() as u64;
+    assert_eq!(history.value_at(n_id, CallKey(0), end), Some(&int(5)));
+    assert_eq!(history.value_at(n_id, CallKey(1), end), Some(&int(2)));
+    assert_eq!(history.value_at(n_id, CallKey(2), end), Some(&int(1)));
+    assert_eq!(history.value_at(n_id, CallKey(1), history.timeline(n_id)[1].geid - 1), None);
+    assert!(history.timeline_by_name("missing").is_empty());
+}
+
+#[test]
+fn test_place_aliasing_across_calls() {
+    let path = Path::new("/test/alias.py");
+    // in memory: `RValue` events don't serialize yet
+    let mut writer = NonStreamingTraceWriter::new("alias", &[]);
+    let writer: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let update = TraceWriter::ensure_function_id(writer, "update", path, Line(10));
+    let place = Place(100);
+
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_cell_value(writer, place, int(1));
+    TraceWriter::bind_variable(writer, "a", place);
+    TraceWriter::register_call(writer, update, vec![]);
+    TraceWriter::bind_variable(writer, "b", place);
+    TraceWriter::assign_cell(writer, place, int(2));
+    TraceWriter::register_return(writer, NONE_VALUE);
+    TraceWriter::register_step(writer, path, Line(3));
+    // b's call has returned
+    TraceWriter::assign_cell(writer, place, int(3));
+    let rvalue = TraceWriter::simple_rvalue(writer, "a");
+    TraceWriter::assign(writer, "c", rvalue, PassBy::Reference);
+    TraceWriter::drop_variables(writer, &["a".to_string()]);
+    TraceWriter::assign_cell(writer, place, int(4));
+
+    let history = VariableHistory::from_events(TraceWriter::events(writer));
+    let summary = |name: &str| -> Vec<(i64, ChangeKind, Option<ValueRecord>)> {
+        history
+            .timeline_by_name(name)
+            .iter()
+            .map(|change| (change.call_key.0, change.kind.clone(), change.value.clone()))
+            .collect()
+    };
+
+    assert_eq!(
+        summary("a"),
+        vec![
+            (0, ChangeKind::Bind(place), Some(int(1))),
+            (0, ChangeKind::Cell(place), Some(int(2))),
+            (0, ChangeKind::Cell(place), Some(int(3))),
+            (0, ChangeKind::Drop, None),
+        ]
+    );
+    assert_eq!(
+        summary("b"),
+        vec![(1, ChangeKind::Bind(place), Some(int(1))), (1, ChangeKind::Cell(place), Some(int(2))),]
+    );
+    assert_eq!(
+        summary("c"),
+        vec![
+            (0, ChangeKind::Assign { by_reference: true }, Some(int(3))),
+            (0, ChangeKind::Cell(place), Some(int(4))),
+        ]
+    );
+    let a = history.variable_id("a").unwrap();
+    assert_eq!(history.value_at(a, CallKey(0), u64::MAX), None);
+}
@@ -0,0 +1,54 @@
+//! Serde helpers for raw byte buffers.
+//!
+//! Human-readable formats (JSON) get a base64 string, like `base64.rs`;
+//! binary formats (CBOR, including the split-binary payloads) get a native
+//! byte string, so the blob is stored as-is behind its length prefix.
+//! Deserialization accepts either shape, because internally tagged enums are
+//! buffered by serde and lose the `is_human_readable` hint on the way back.
+
+use base64::Engine;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+use std::fmt;
+
+pub fn serialize<S: Serializer>(v: &[u8], s: S) -> Result<S::Ok, S::Error> {
+    if s.is_human_readable() {
+        s.serialize_str(&base64::engine::general_purpose::STANDARD.encode(v))
+    } else {
+        s.serialize_bytes(v)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+    d.deserialize_any(ByteBufVisitor)
+}
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a byte string or a base64 string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        base64::engine::general_purpose::STANDARD.decode(v.as_bytes()).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element::<u8>()? {
+            out.push(b);
+        }
+        Ok(out)
+    }
+}
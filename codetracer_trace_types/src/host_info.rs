@@ -0,0 +1,63 @@
+//! Best-effort probes of the recording host used to fill `TraceMetadata`.
+//!
+//! Every probe returns `None` (or an empty map) instead of failing: missing
+//! host details never prevent a trace from being written.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::GitInfo;
+
+/// Environment variables copied into the metadata by
+/// `TraceMetadata::probe_host`.
+/// The list is deliberately short: the environment routinely carries
+/// secrets, so only variables that change program behaviour in ways a
+/// trace reader needs to know about are recorded.
+pub const RECORDED_ENV_VARS: &[&str] = &["LANG", "LC_ALL", "TZ"];
+
+pub fn unix_time_ms() -> Option<u64> {
+    SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_millis() as u64)
+}
+
+pub fn hostname() -> Option<String> {
+    let from_kernel = std::fs::read_to_string("/proc/sys/kernel/hostname").ok();
+    let name = from_kernel
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())?;
+    let name = name.trim();
+    if name.is_empty() { None } else { Some(name.to_string()) }
+}
+
+pub fn recorded_env() -> BTreeMap<String, String> {
+    RECORDED_ENV_VARS
+        .iter()
+        .filter_map(|name| std::env::var(name).ok().map(|value| (name.to_string(), value)))
+        .collect()
+}
+
+fn git(workdir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(workdir)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// The checked-out commit of the repository containing `workdir`, if any.
+/// Untracked files do not make the tree dirty.
+pub fn git_info(workdir: &Path) -> Option<GitInfo> {
+    let commit = git(workdir, &["rev-parse", "HEAD"])?.trim().to_string();
+    let status = git(workdir, &["status", "--porcelain", "--untracked-files=no"])?;
+    Some(GitInfo {
+        commit,
+        dirty: !status.trim().is_empty(),
+    })
+}
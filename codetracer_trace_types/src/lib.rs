@@ -1,5 +1,8 @@
 mod base64;
+mod byte_buf;
+pub mod host_info;
 mod types;
+mod wide_int;
 pub use types::*;
 
 #[cfg(test)]
@@ -19,6 +22,21 @@ mod tests {
         assert_ne!(a, different);
     }
 
+    #[test]
+    fn test_uint_is_a_decimal_string_in_json() {
+        let value = ValueRecord::UInt {
+            u: u64::MAX,
+            type_id: TypeId(0),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["u"], "18446744073709551615");
+        assert_eq!(serde_json::from_value::<ValueRecord>(json).unwrap(), value);
+
+        // plain numbers written by older tracers still read back
+        let number = serde_json::json!({"kind": "UInt", "u": 18446744073709551615u64, "type_id": 0});
+        assert_eq!(serde_json::from_value::<ValueRecord>(number).unwrap(), value);
+    }
+
     /// M-REC-1 acceptance: `TraceMetadata::new` mints a canonical
     /// UUIDv7 `recording_id`, the version nibble is 7, and the
     /// variant nibble is one of {8,9,a,b}.
@@ -97,6 +115,20 @@ mod tests {
             workdir: PathBuf::from("/tmp"),
             program: "p".into(),
             args: vec![],
+            metadata_version: TRACE_METADATA_VERSION,
+            parent_recording_id: None,
+            exit_code: None,
+            language: Some("python".into()),
+            recorder_name: None,
+            recorder_version: None,
+            start_time: Some(1_700_000_000_000),
+            end_time: None,
+            env: Default::default(),
+            hostname: None,
+            git: Some(GitInfo {
+                commit: "0123456789abcdef0123456789abcdef01234567".into(),
+                dirty: true,
+            }),
         };
         let serialized = serde_json::to_string(&original).unwrap();
         assert!(
@@ -106,6 +138,26 @@ mod tests {
         );
         let decoded: TraceMetadata = serde_json::from_str(&serialized).unwrap();
         assert_eq!(decoded.recording_id, original.recording_id);
+        assert_eq!(decoded.language, original.language);
+        assert_eq!(decoded.start_time, original.start_time);
+        assert_eq!(decoded.git, original.git);
+        assert!(!serialized.contains("hostname"), "absent optional fields are omitted: {}", serialized);
+    }
+
+    /// Metadata from before versioning still parses, as version 1 with
+    /// none of the optional fields.
+    #[test]
+    fn unversioned_metadata_reads_as_version_1() {
+        let legacy = r#"{"recording_id":"01949fcc-7d92-7e9c-aaaa-bbbbbbbbbbbb","program":"p","args":[],"workdir":"/tmp"}"#;
+        let m: TraceMetadata = serde_json::from_str(legacy).unwrap();
+        assert_eq!(m.metadata_version, 1);
+        assert!(m.start_time.is_none() && m.hostname.is_none() && m.git.is_none());
+        assert!(m.env.is_empty());
+
+        let fresh = TraceMetadata::new("p", vec![], PathBuf::from("/"));
+        assert_eq!(fresh.metadata_version, TRACE_METADATA_VERSION);
+        assert!(fresh.start_time.is_some() && fresh.end_time.is_none());
+        assert!(fresh.hostname.is_none() && fresh.git.is_none() && fresh.env.is_empty());
     }
 
     #[test]
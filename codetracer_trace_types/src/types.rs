@@ -3,10 +3,14 @@
 //! All structures derive [`serde::Serialize`] and [`serde::Deserialize`].
 
 use std::cmp::Ord;
+use std::collections::BTreeMap;
 use std::ops;
 use std::path::PathBuf;
 
 use crate::base64;
+use crate::byte_buf;
+use crate::host_info;
+use crate::wide_int;
 use num_derive::FromPrimitive;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -16,7 +20,7 @@ use serde_with::{DisplayFromStr, serde_as};
 // currently, we do assume that we record the whole program
 // so, we try to include minimal amount of data,
 // as we can reconstruct some things like depth, id-s etc
-// afterwards in postprocessing
+// afterwards in postprocessing (`codetracer_trace_reader::call_tree`)
 // this assumption can change in the future
 
 pub const NONE_TYPE_ID: TypeId = TypeId(0);
@@ -59,11 +63,86 @@ pub enum TraceLowLevelEvent {
     ThreadExit(ThreadId),
     ThreadSwitch(ThreadId),
 
+    // async tasks/coroutines, multiplexed on the current thread: a task's
+    // calls happen between its `TaskResume` and the next `TaskSuspend` (an
+    // await) or `TaskComplete`, and its frames survive suspensions
+    TaskSpawn(TaskId),
+    TaskSuspend(TaskId),
+    TaskResume(TaskId),
+    TaskComplete(TaskId),
+
+    // abnormal control flow: an exception is raised at the current step,
+    // the frames it leaves are popped by `Unwind` (instead of `Return`s)
+    // and `Catch` marks it handled in the frame that is then on top
+    Throw(ThrowRecord),
+    Catch(CatchRecord),
+    Unwind(UnwindRecord),
+
+    // process lifecycle: `ProcessStart` opens the recording of one process
+    // (a forked child names its parent's recording), `Exec` replaces the
+    // program image in place and `Exit` is the last event of the process
+    ProcessStart(ProcessStartRecord),
+    Exec(ExecRecord),
+    Signal(SignalRecord),
+    Exit(ExitRecord),
+
+    // raw memory of native programs: `MemoryWrite` records the bytes stored
+    // at an address by the current step, `Alloc`/`Free` the heap blocks
+    MemoryWrite(MemoryWriteRecord),
+    Alloc(AllocRecord),
+    Free(FreeRecord),
+
     // normal event, workaround for cases when we need to drop
     // a step event, but the trace needs to be append-only
     DropLastStep,
 }
 
+impl TraceLowLevelEvent {
+    /// The name of the variant, as used for the event's tag in JSON.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            TraceLowLevelEvent::Step(_) => "Step",
+            TraceLowLevelEvent::Path(_) => "Path",
+            TraceLowLevelEvent::VariableName(_) => "VariableName",
+            TraceLowLevelEvent::Variable(_) => "Variable",
+            TraceLowLevelEvent::Type(_) => "Type",
+            TraceLowLevelEvent::Value(_) => "Value",
+            TraceLowLevelEvent::Function(_) => "Function",
+            TraceLowLevelEvent::Call(_) => "Call",
+            TraceLowLevelEvent::Return(_) => "Return",
+            TraceLowLevelEvent::Event(_) => "Event",
+            TraceLowLevelEvent::Asm(_) => "Asm",
+            TraceLowLevelEvent::BindVariable(_) => "BindVariable",
+            TraceLowLevelEvent::Assignment(_) => "Assignment",
+            TraceLowLevelEvent::DropVariables(_) => "DropVariables",
+            TraceLowLevelEvent::CompoundValue(_) => "CompoundValue",
+            TraceLowLevelEvent::CellValue(_) => "CellValue",
+            TraceLowLevelEvent::AssignCompoundItem(_) => "AssignCompoundItem",
+            TraceLowLevelEvent::AssignCell(_) => "AssignCell",
+            TraceLowLevelEvent::VariableCell(_) => "VariableCell",
+            TraceLowLevelEvent::DropVariable(_) => "DropVariable",
+            TraceLowLevelEvent::ThreadStart(_) => "ThreadStart",
+            TraceLowLevelEvent::ThreadExit(_) => "ThreadExit",
+            TraceLowLevelEvent::ThreadSwitch(_) => "ThreadSwitch",
+            TraceLowLevelEvent::TaskSpawn(_) => "TaskSpawn",
+            TraceLowLevelEvent::TaskSuspend(_) => "TaskSuspend",
+            TraceLowLevelEvent::TaskResume(_) => "TaskResume",
+            TraceLowLevelEvent::TaskComplete(_) => "TaskComplete",
+            TraceLowLevelEvent::Throw(_) => "Throw",
+            TraceLowLevelEvent::Catch(_) => "Catch",
+            TraceLowLevelEvent::Unwind(_) => "Unwind",
+            TraceLowLevelEvent::ProcessStart(_) => "ProcessStart",
+            TraceLowLevelEvent::Exec(_) => "Exec",
+            TraceLowLevelEvent::Signal(_) => "Signal",
+            TraceLowLevelEvent::Exit(_) => "Exit",
+            TraceLowLevelEvent::MemoryWrite(_) => "MemoryWrite",
+            TraceLowLevelEvent::Alloc(_) => "Alloc",
+            TraceLowLevelEvent::Free(_) => "Free",
+            TraceLowLevelEvent::DropLastStep => "DropLastStep",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BindVariableRecord {
     pub variable_id: VariableId,
@@ -165,6 +244,61 @@ pub struct TraceMetadata {
     pub workdir: PathBuf,
     pub program: String,
     pub args: Vec<String>,
+
+    /// Layout version of the metadata, see `TRACE_METADATA_VERSION`.
+    /// Metadata written before the field existed reads as version 1.
+    #[serde(default = "legacy_metadata_version")]
+    pub metadata_version: u32,
+
+    // version 2: everything below is optional, absent when the recorder
+    // could not determine it
+    /// `recording_id` of the trace of the process that forked this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_recording_id: Option<String>,
+    /// Exit code of the recorded process, absent when the recording ended
+    /// before the process did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Source language of the recorded program, e.g. `python` or `ruby`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recorder_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recorder_version: Option<String>,
+    /// Wall-clock start of the recording, in milliseconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    /// Wall-clock end of the recording, in milliseconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    /// The subset of the environment listed in `host_info::RECORDED_ENV_VARS`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// Version-control state of `workdir`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<GitInfo>,
+}
+
+/// Current `TraceMetadata::metadata_version`.
+pub const TRACE_METADATA_VERSION: u32 = 2;
+
+fn legacy_metadata_version() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitInfo {
+    pub commit: String,
+    /// Tracked files differ from `commit`.
+    pub dirty: bool,
+}
+
+/// A fresh canonical recording id (UUIDv7, lowercase hyphenated).
+pub fn new_recording_id() -> String {
+    uuid::Uuid::now_v7().hyphenated().to_string()
 }
 
 impl TraceMetadata {
@@ -175,14 +309,39 @@ impl TraceMetadata {
     /// M-REC-1: the recording_id is generated via `Uuid::now_v7`,
     /// which combines the current wall-clock ms with 74 random bits
     /// from a CSPRNG, per RFC 9562 §5.7.
+    ///
+    /// `start_time` is now. The host details are deliberately not filled
+    /// in here, unlike the other fields: they identify the machine and
+    /// probing them runs `git`, so recorders opt in with
+    /// [`probe_host`](Self::probe_host).
     pub fn new(program: impl Into<String>, args: Vec<String>, workdir: PathBuf) -> Self {
         TraceMetadata {
-            recording_id: uuid::Uuid::now_v7().hyphenated().to_string(),
+            recording_id: new_recording_id(),
             workdir,
             program: program.into(),
             args,
+            metadata_version: TRACE_METADATA_VERSION,
+            parent_recording_id: None,
+            exit_code: None,
+            language: None,
+            recorder_name: None,
+            recorder_version: None,
+            start_time: host_info::unix_time_ms(),
+            end_time: None,
+            env: Default::default(),
+            hostname: None,
+            git: None,
         }
     }
+
+    /// Fill in the hostname, the recorded environment variables and the
+    /// git state of `workdir` from the current host. This runs `git`, so
+    /// recorders call it once, when they want these details in the trace.
+    pub fn probe_host(&mut self) {
+        self.env = host_info::recorded_env();
+        self.hostname = host_info::hostname();
+        self.git = host_info::git_info(&self.workdir);
+    }
 }
 
 // call keys:
@@ -294,6 +453,21 @@ impl Into<u64> for ThreadId {
     }
 }
 
+/// Identity of an async task, coroutine or future, chosen by the recorder
+/// and unique within the trace.
+#[derive(Hash, Debug, Default, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TaskId(pub u64);
+
+/// Identity of a shared heap object inside recorded values.
+///
+/// A `ValueRecord::Object` defines the id the first time the object is
+/// recorded; later occurrences are `ValueRecord::BackRef`s. Ids are unique
+/// for the whole trace, but a back-reference may only point at a definition
+/// made since the most recent `Step`.
+#[derive(Hash, Debug, Default, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct ObjectId(pub u64);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CallRecord {
     // pub key: CallKey,
@@ -308,11 +482,87 @@ pub struct ReturnRecord {
     pub return_value: ValueRecord,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThrowRecord {
+    pub exception: ValueRecord,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatchRecord {
+    pub exception: ValueRecord,
+}
+
+/// Frames exited by an exception or panic, innermost first. Each entry pops
+/// one call from the stack, exactly like a `Return` would.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnwindRecord {
+    pub frames: Vec<FunctionId>,
+}
+
+/// The recorded process starts (or, for a forked child, starts being
+/// recorded). `parent_recording_id` is the `recording_id` of the parent's
+/// trace when the parent was recorded too, so a multi-process recording can
+/// be stitched back together from its per-process traces.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessStartRecord {
+    pub pid: u32,
+    pub parent_pid: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_recording_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecRecord {
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// A signal delivered to the process; `name` is the symbolic name
+/// (e.g. `SIGTERM`) when the recorder knows it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalRecord {
+    pub signal: i32,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// The process exits. A process killed by a signal is recorded as the
+/// `Signal` followed by an `Exit` with the shell convention `128 + signal`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExitRecord {
+    pub code: i32,
+}
+
+/// `bytes` were stored at `address`; the length of the write is the length
+/// of `bytes`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryWriteRecord {
+    pub address: u64,
+    #[serde(with = "byte_buf")]
+    pub bytes: Vec<u8>,
+}
+
+/// A heap block of `size` bytes now lives at `address`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AllocRecord {
+    pub address: u64,
+    pub size: u64,
+}
+
+/// The heap block allocated at `address` was released.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FreeRecord {
+    pub address: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionRecord {
     pub path_id: PathId,
     pub line: Line,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
 }
 
 // #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -325,6 +575,21 @@ pub struct FunctionRecord {
 pub struct StepRecord {
     pub path_id: PathId,
     pub line: Line,
+    // finer position for expression-level languages; `line` stays the
+    // primary position, so readers that only need lines can ignore it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+}
+
+/// A source range inside a file. Columns are 1-based byte offsets within
+/// their line and `end_column` is exclusive, so a span can also describe a
+/// byte range of a single-line (e.g. minified) file.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SourceSpan {
+    pub start_line: Line,
+    pub start_column: u32,
+    pub end_line: Line,
+    pub end_column: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -337,9 +602,9 @@ pub struct VariableRecord {
 pub struct TypeRecord {
     pub kind: TypeKind,
     pub lang_type: String,
-    // for now only for Struct and Pointer: TODO eventually
-    // replace with an enum for TypeRecord, or with more cases
-    // in TypeSpecificInfo for collections, etc
+    // extra structure for composite kinds: fields, element/key/value
+    // types, variant lists and function signatures.
+    // `TypeSpecificInfo::None` for scalars
     pub specific_info: TypeSpecificInfo,
 }
 
@@ -349,12 +614,30 @@ pub struct FieldTypeRecord {
     pub type_id: TypeId,
 }
 
+/// One alternative of an `Enum` or `Variant` type.
+///
+/// `type_id` describes the payload of the alternative; plain enum
+/// members without a payload use `NONE_TYPE_ID`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VariantTypeRecord {
+    pub name: String,
+    pub type_id: TypeId,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "kind")]
 pub enum TypeSpecificInfo {
     None,
     Struct { fields: Vec<FieldTypeRecord> },
     Pointer { dereference_type_id: TypeId },
+    // for Seq, Array, Slice, Set and the other homogeneous collections
+    Collection { element_type_id: TypeId },
+    // for TableKind
+    Table { key_type_id: TypeId, value_type_id: TypeId },
+    // for Enum, Enum16, Enum32 and Variant
+    Variants { variants: Vec<VariantTypeRecord> },
+    // for FunctionKind
+    Function { param_type_ids: Vec<TypeId>, return_type_id: TypeId },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -362,6 +645,16 @@ pub struct RecordEvent {
     pub kind: EventLogKind,
     pub metadata: String,
     pub content: String,
+    /// Typed fields of the event (log level, file descriptor, EVM topics,
+    /// ...), so consumers don't have to parse them out of `metadata`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<EventAttribute>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventAttribute {
+    pub key: String,
+    pub value: ValueRecord,
 }
 
 #[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq)]
@@ -387,6 +680,23 @@ pub enum ValueRecord {
         i: i64,
         type_id: TypeId,
     },
+    // unsigned and 128-bit integers: decimal strings in JSON; in CBOR a
+    // plain integer for UInt and 16 little-endian bytes for the 128-bit ones
+    UInt {
+        #[serde(with = "wide_int::unsigned64")]
+        u: u64,
+        type_id: TypeId,
+    },
+    Int128 {
+        #[serde(with = "wide_int::signed")]
+        i: i128,
+        type_id: TypeId,
+    },
+    UInt128 {
+        #[serde(with = "wide_int::unsigned")]
+        u: u128,
+        type_id: TypeId,
+    },
     Float {
         #[serde_as(as = "DisplayFromStr")]
         f: f64,
@@ -413,6 +723,11 @@ pub enum ValueRecord {
         field_values: Vec<ValueRecord>,
         type_id: TypeId, // must point to Type with STRUCT kind and TypeSpecificInfo::Struct
     },
+    // dicts, hash maps, tables: entries are kept in recording order
+    Map {
+        entries: Vec<(ValueRecord, ValueRecord)>,
+        type_id: TypeId, // usually a Type with TABLE_KIND kind and TypeSpecificInfo::Table
+    },
     Variant {
         discriminator: String,      // TODO: eventually a more specific kind of value/type
         contents: Box<ValueRecord>, // usually a Struct or a Tuple
@@ -450,6 +765,92 @@ pub enum ValueRecord {
         c: char,
         type_id: TypeId,
     },
+    // byte arrays and buffers: base64 in JSON, a native byte string in CBOR
+    Bytes {
+        #[serde(with = "byte_buf")]
+        data: Vec<u8>,
+        type_id: TypeId,
+    },
+    // a value cut down by the writer's capture limits:
+    // `partial` keeps what was recorded (leading elements, a string prefix,
+    // or just `None { type_id }` when the whole value was dropped),
+    // `original_len` is the element/character count before truncation and
+    // `depth_cutoff` is set when the value sat below `max_depth`
+    Elided {
+        partial: Box<ValueRecord>,
+        original_len: u64,
+        depth_cutoff: bool,
+    },
+    // first occurrence of a shared object: `value` is recorded once and
+    // later occurrences (including cyclic ones inside `value`) are `BackRef`s
+    Object {
+        id: ObjectId,
+        value: Box<ValueRecord>,
+    },
+    BackRef {
+        id: ObjectId,
+        type_id: TypeId,
+    },
+}
+
+impl ValueRecord {
+    /// The name of the variant, as used for the value's `kind` tag in JSON.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            ValueRecord::Int { .. } => "Int",
+            ValueRecord::UInt { .. } => "UInt",
+            ValueRecord::Int128 { .. } => "Int128",
+            ValueRecord::UInt128 { .. } => "UInt128",
+            ValueRecord::Float { .. } => "Float",
+            ValueRecord::Bool { .. } => "Bool",
+            ValueRecord::String { .. } => "String",
+            ValueRecord::Sequence { .. } => "Sequence",
+            ValueRecord::Tuple { .. } => "Tuple",
+            ValueRecord::Struct { .. } => "Struct",
+            ValueRecord::Map { .. } => "Map",
+            ValueRecord::Variant { .. } => "Variant",
+            ValueRecord::Reference { .. } => "Reference",
+            ValueRecord::Raw { .. } => "Raw",
+            ValueRecord::Error { .. } => "Error",
+            ValueRecord::None { .. } => "None",
+            ValueRecord::Cell { .. } => "Cell",
+            ValueRecord::BigInt { .. } => "BigInt",
+            ValueRecord::Char { .. } => "Char",
+            ValueRecord::Bytes { .. } => "Bytes",
+            ValueRecord::Elided { .. } => "Elided",
+            ValueRecord::Object { .. } => "Object",
+            ValueRecord::BackRef { .. } => "BackRef",
+        }
+    }
+
+    /// The type of the value; `Elided` and `Object` have the type of the
+    /// value they wrap and a `Cell` has none.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self {
+            ValueRecord::Int { type_id, .. }
+            | ValueRecord::UInt { type_id, .. }
+            | ValueRecord::Int128 { type_id, .. }
+            | ValueRecord::UInt128 { type_id, .. }
+            | ValueRecord::Float { type_id, .. }
+            | ValueRecord::Bool { type_id, .. }
+            | ValueRecord::String { type_id, .. }
+            | ValueRecord::Sequence { type_id, .. }
+            | ValueRecord::Tuple { type_id, .. }
+            | ValueRecord::Struct { type_id, .. }
+            | ValueRecord::Map { type_id, .. }
+            | ValueRecord::Variant { type_id, .. }
+            | ValueRecord::Reference { type_id, .. }
+            | ValueRecord::Raw { type_id, .. }
+            | ValueRecord::Error { type_id, .. }
+            | ValueRecord::None { type_id }
+            | ValueRecord::BigInt { type_id, .. }
+            | ValueRecord::Char { type_id, .. }
+            | ValueRecord::Bytes { type_id, .. }
+            | ValueRecord::BackRef { type_id, .. } => Some(*type_id),
+            ValueRecord::Elided { partial: value, .. } | ValueRecord::Object { value, .. } => value.type_id(),
+            ValueRecord::Cell { .. } => None,
+        }
+    }
 }
 
 /// Categories of types recorded in the trace.
@@ -0,0 +1,122 @@
+//! Serde helpers for integers wider than a JSON number can safely hold:
+//! `u64` and the 128-bit ones.
+//!
+//! Human-readable formats (JSON) get a decimal string, which every JSON
+//! parser can carry without rounding; binary formats (CBOR, including the
+//! split-binary payloads) get a plain integer for `u64` and the 16
+//! little-endian bytes as a byte string for the 128-bit ones. Like
+//! `byte_buf.rs`, deserialization accepts any of these shapes, plus plain
+//! integers for values written by hand.
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub mod unsigned64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &u64, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() { s.collect_str(v) } else { s.serialize_u64(*v) }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<u64, D::Error> {
+        d.deserialize_any(WideIntVisitor::<u64>(PhantomData))
+    }
+}
+
+pub mod signed {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &i128, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.collect_str(v)
+        } else {
+            s.serialize_bytes(&v.to_le_bytes())
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<i128, D::Error> {
+        d.deserialize_any(WideIntVisitor::<i128>(PhantomData))
+    }
+}
+
+pub mod unsigned {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &u128, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.collect_str(v)
+        } else {
+            s.serialize_bytes(&v.to_le_bytes())
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<u128, D::Error> {
+        d.deserialize_any(WideIntVisitor::<u128>(PhantomData))
+    }
+}
+
+trait WideInt: FromStr + TryFrom<i64> + TryFrom<u64> + TryFrom<i128> + TryFrom<u128> {
+    /// `None` unless `bytes` has the width of the integer.
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl WideInt for u64 {
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+}
+
+impl WideInt for i128 {
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(i128::from_le_bytes(bytes.try_into().ok()?))
+    }
+}
+
+impl WideInt for u128 {
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(u128::from_le_bytes(bytes.try_into().ok()?))
+    }
+}
+
+struct WideIntVisitor<T>(PhantomData<T>);
+
+impl<T: WideInt> WideIntVisitor<T> {
+    fn convert<E: de::Error, V: TryInto<T> + fmt::Display + Copy>(v: V) -> Result<T, E> {
+        v.try_into().map_err(|_| E::custom(format!("integer {v} out of range")))
+    }
+}
+
+impl<'de, T: WideInt> Visitor<'de> for WideIntVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal string, little-endian bytes or an integer")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(|_| E::custom(format!("invalid integer: {v:?}")))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        T::from_le_bytes(v).ok_or_else(|| E::invalid_length(v.len(), &"the width of the integer"))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        Self::convert(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Self::convert(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        Self::convert(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        Self::convert(v)
+    }
+}
@@ -0,0 +1,23 @@
+use clap::Args;
+use codetracer_trace_reader::chrome_trace::ChromeTraceExporter;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ChromeTraceCommand {
+    /// Trace file to read (.json, .bin or .ct)
+    input_file: String,
+
+    /// JSON file to write, for chrome://tracing or ui.perfetto.dev
+    output_file: String,
+}
+
+pub(crate) fn run(cmd: ChromeTraceCommand) {
+    let events = load_trace_or_exit(&cmd.input_file);
+
+    let chrome_trace = ChromeTraceExporter::export(&events);
+    if let Err(e) = std::fs::write(&cmd.output_file, chrome_trace.to_string()) {
+        eprintln!("Error: cannot write '{}': {}", cmd.output_file, e);
+        std::process::exit(2);
+    }
+}
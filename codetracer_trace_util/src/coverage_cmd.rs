@@ -0,0 +1,31 @@
+use clap::Args;
+use codetracer_trace_reader::coverage::Coverage;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct CoverageCommand {
+    /// Trace file to read (.json, .bin or .ct)
+    input_file: String,
+
+    /// Report to write: lcov for .info or .lcov, Cobertura for .xml
+    output_file: String,
+}
+
+pub(crate) fn run(cmd: CoverageCommand) {
+    let report: fn(&Coverage) -> String = if cmd.output_file.ends_with(".info") || cmd.output_file.ends_with(".lcov") {
+        Coverage::to_lcov
+    } else if cmd.output_file.ends_with(".xml") {
+        Coverage::to_cobertura
+    } else {
+        eprintln!("Error: cannot tell the report format of '{}', use .info, .lcov or .xml", cmd.output_file);
+        std::process::exit(2);
+    };
+    let events = load_trace_or_exit(&cmd.input_file);
+
+    let coverage = Coverage::from_events(&events);
+    if let Err(e) = std::fs::write(&cmd.output_file, report(&coverage)) {
+        eprintln!("Error: cannot write '{}': {}", cmd.output_file, e);
+        std::process::exit(2);
+    }
+}
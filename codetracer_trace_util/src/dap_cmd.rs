@@ -0,0 +1,521 @@
+//! A Debug Adapter Protocol server over a recorded trace.
+//!
+//! The client launches with `{"program": "<trace file>"}` and then steps
+//! through the recording with a [`ReplayCursor`], in both directions. The
+//! trace never runs, so the session is always stopped: every move answers
+//! its request and reports where it stopped with a `stopped` event.
+//!
+//! Frame ids are positions on the current stack, innermost first, and
+//! variable references are handed out per stop: the ones of an earlier stop
+//! mean nothing after the next move. A variable holding a back-reference
+//! to a shared object shows and expands the object. Data breakpoints watch a local by
+//! name; their condition is a comparison with the new value, either with
+//! the variable spelled out (`x > 10`) or without (`> 10`).
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use codetracer_trace_reader::query::{Expr, Field, Literal, Op, Query, ValueProperty};
+use codetracer_trace_reader::replay::{Breakpoint, BreakpointId, EventSource, ReplayCursor, StopReason, Watchpoint};
+use codetracer_trace_reader::{create_trace_reader, ctfs_reader::ChunkedEvents};
+use codetracer_trace_types::{CallKey, TypeId, TypeSpecificInfo, ValueRecord};
+use serde_json::{Value, json};
+
+use crate::determine_input_file_format_from_name;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DapCommand {}
+
+pub(crate) fn run(_cmd: DapCommand) {
+    let mut session = DapSession::new(io::stdout().lock());
+    if let Err(e) = session.serve(&mut io::stdin().lock()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+}
+
+/// Read one message; `None` once the client has closed the stream.
+fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let Some(content_length) = content_length else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "message without a Content-Length header"));
+    };
+    let mut content = vec![0; content_length];
+    input.read_exact(&mut content)?;
+    serde_json::from_slice(&content).map(Some).map_err(io::Error::from)
+}
+
+fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = message.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+    output.flush()
+}
+
+// what a variables reference stands for
+enum Handle {
+    Locals(CallKey),
+    Value(ValueRecord),
+}
+
+struct DapSession<W: Write> {
+    output: W,
+    seq: i64,
+    cursor: Option<ReplayCursor<Box<dyn EventSource>>>,
+    stop_on_entry: bool,
+    source_breakpoints: HashMap<PathBuf, Vec<BreakpointId>>,
+    function_breakpoints: Vec<BreakpointId>,
+    data_breakpoints: Vec<BreakpointId>,
+    // by variables reference - 1, since the last stop
+    handles: Vec<Handle>,
+}
+
+impl<W: Write> DapSession<W> {
+    fn new(output: W) -> Self {
+        DapSession {
+            output,
+            seq: 0,
+            cursor: None,
+            stop_on_entry: true,
+            source_breakpoints: HashMap::new(),
+            function_breakpoints: vec![],
+            data_breakpoints: vec![],
+            handles: vec![],
+        }
+    }
+
+    /// Answer requests until the client disconnects or closes the stream.
+    fn serve(&mut self, input: &mut impl BufRead) -> io::Result<()> {
+        while let Some(message) = read_message(input)? {
+            if message["type"] != "request" {
+                continue;
+            }
+            let command = message["command"].as_str().unwrap_or_default().to_string();
+            let arguments = &message["arguments"];
+            match self.handle(&command, arguments) {
+                Ok(body) => self.send(json!({
+                    "type": "response",
+                    "request_seq": message["seq"],
+                    "success": true,
+                    "command": command,
+                    "body": body,
+                }))?,
+                Err(error) => self.send(json!({
+                    "type": "response",
+                    "request_seq": message["seq"],
+                    "success": false,
+                    "command": command,
+                    "message": error,
+                }))?,
+            }
+            match command.as_str() {
+                "launch" if self.cursor.is_some() => self.send_event("initialized", json!({}))?,
+                "configurationDone" if self.cursor.is_some() => {
+                    let reason = if self.stop_on_entry {
+                        None
+                    } else {
+                        self.cursor.as_mut().map(ReplayCursor::continue_forward)
+                    };
+                    self.send_stopped(reason)?;
+                }
+                "continue" | "next" | "stepIn" | "stepOut" | "stepBack" | "reverseContinue" if self.cursor.is_some() => {
+                    let cursor = self.cursor.as_mut().unwrap();
+                    let reason = match command.as_str() {
+                        "continue" => cursor.continue_forward(),
+                        "next" => cursor.step_over(),
+                        "stepIn" => cursor.step_in(),
+                        "stepOut" => cursor.step_out(),
+                        "stepBack" => cursor.reverse_step(),
+                        _ => cursor.reverse_continue(),
+                    };
+                    self.send_stopped(Some(reason))?;
+                }
+                "disconnect" | "terminate" => return Ok(()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn send(&mut self, mut message: Value) -> io::Result<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        write_message(&mut self.output, &message)
+    }
+
+    fn send_event(&mut self, event: &str, body: Value) -> io::Result<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+
+    // `None` for the stop at the first step
+    fn send_stopped(&mut self, reason: Option<StopReason>) -> io::Result<()> {
+        self.handles.clear();
+        let thread_id = self
+            .cursor
+            .as_ref()
+            .and_then(|cursor| cursor.thread_id())
+            .map_or(0, |thread_id| thread_id.0);
+        let mut body = json!({ "threadId": thread_id, "allThreadsStopped": true });
+        let (reason, description) = match reason {
+            None => ("entry", None),
+            Some(StopReason::Step) => ("step", None),
+            Some(StopReason::Breakpoint(id)) => {
+                body["hitBreakpointIds"] = json!([id.0]);
+                if self.function_breakpoints.contains(&id) {
+                    ("function breakpoint", None)
+                } else {
+                    ("breakpoint", None)
+                }
+            }
+            Some(StopReason::Watchpoint(id)) => {
+                body["hitBreakpointIds"] = json!([id.0]);
+                ("data breakpoint", None)
+            }
+            Some(StopReason::End) => ("step", Some("end of the trace")),
+            Some(StopReason::Start) => ("step", Some("start of the trace")),
+        };
+        body["reason"] = json!(reason);
+        if let Some(description) = description {
+            body["description"] = json!(description);
+        }
+        self.send_event("stopped", body)
+    }
+
+    fn handle(&mut self, command: &str, arguments: &Value) -> Result<Value, String> {
+        match command {
+            "initialize" => {
+                return Ok(json!({
+                    "supportsConfigurationDoneRequest": true,
+                    "supportsFunctionBreakpoints": true,
+                    "supportsDataBreakpoints": true,
+                    "supportsStepBack": true,
+                    "supportsTerminateRequest": true,
+                }));
+            }
+            "launch" => {
+                let program = arguments["program"].as_str().ok_or("launch needs the trace file as 'program'")?;
+                self.stop_on_entry = arguments["stopOnEntry"].as_bool().unwrap_or(true);
+                self.cursor = Some(open_cursor(program)?);
+                return Ok(json!({}));
+            }
+            "disconnect" | "terminate" | "pause" => return Ok(json!({})),
+            _ => {}
+        }
+
+        let Some(cursor) = self.cursor.as_mut() else {
+            return Err(format!("'{command}' needs a launched trace"));
+        };
+        match command {
+            "setBreakpoints" => {
+                let path = PathBuf::from(arguments["source"]["path"].as_str().ok_or("setBreakpoints needs a source path")?);
+                for id in self.source_breakpoints.remove(&path).unwrap_or_default() {
+                    cursor.remove_breakpoint(id);
+                }
+                let mut ids = vec![];
+                let mut breakpoints = vec![];
+                for line in array(&arguments["breakpoints"])
+                    .iter()
+                    .filter_map(|breakpoint| breakpoint["line"].as_i64())
+                {
+                    let id = cursor.add_breakpoint(Breakpoint::Line { path: path.clone(), line });
+                    ids.push(id);
+                    breakpoints.push(json!({ "id": id.0, "verified": true, "line": line }));
+                }
+                self.source_breakpoints.insert(path, ids);
+                Ok(json!({ "breakpoints": breakpoints }))
+            }
+            "setFunctionBreakpoints" => {
+                for id in self.function_breakpoints.drain(..) {
+                    cursor.remove_breakpoint(id);
+                }
+                let mut breakpoints = vec![];
+                for name in array(&arguments["breakpoints"])
+                    .iter()
+                    .filter_map(|breakpoint| breakpoint["name"].as_str())
+                {
+                    let id = cursor.add_breakpoint(Breakpoint::Function(name.to_string()));
+                    self.function_breakpoints.push(id);
+                    breakpoints.push(json!({ "id": id.0, "verified": true }));
+                }
+                Ok(json!({ "breakpoints": breakpoints }))
+            }
+            "dataBreakpointInfo" => {
+                let name = arguments["name"].as_str().unwrap_or_default();
+                let reference = arguments["variablesReference"].as_u64();
+                let local = reference.is_none_or(|reference| matches!(self.handle_of(reference), Some(Handle::Locals(_))));
+                if !local || name.is_empty() {
+                    return Ok(json!({ "dataId": null, "description": "only local variables can be watched" }));
+                }
+                Ok(json!({ "dataId": name, "description": name, "accessTypes": ["write"], "canPersist": true }))
+            }
+            "setDataBreakpoints" => {
+                for id in self.data_breakpoints.drain(..) {
+                    cursor.remove_breakpoint(id);
+                }
+                let mut breakpoints = vec![];
+                for breakpoint in array(&arguments["breakpoints"]) {
+                    let variable = breakpoint["dataId"].as_str().unwrap_or_default().to_string();
+                    let condition = match breakpoint["condition"].as_str().filter(|condition| !condition.trim().is_empty()) {
+                        Some(condition) => match parse_condition(&variable, condition) {
+                            Some(condition) => Some(condition),
+                            None => {
+                                breakpoints.push(json!({ "verified": false, "message": format!("cannot use '{condition}' as a condition") }));
+                                continue;
+                            }
+                        },
+                        None => None,
+                    };
+                    let id = cursor.add_watchpoint(Watchpoint { variable, condition });
+                    self.data_breakpoints.push(id);
+                    breakpoints.push(json!({ "id": id.0, "verified": true }));
+                }
+                Ok(json!({ "breakpoints": breakpoints }))
+            }
+            "configurationDone" => Ok(json!({})),
+            "threads" => {
+                let mut thread_ids: Vec<u64> = cursor.history().call_tree().roots().map(|call| call.thread_id.0).collect();
+                thread_ids.sort_unstable();
+                thread_ids.dedup();
+                if thread_ids.is_empty() {
+                    thread_ids.push(0);
+                }
+                let threads: Vec<Value> = thread_ids
+                    .iter()
+                    .map(|thread_id| json!({ "id": thread_id, "name": format!("thread {thread_id}") }))
+                    .collect();
+                Ok(json!({ "threads": threads }))
+            }
+            "stackTrace" => {
+                let thread_id = arguments["threadId"].as_u64();
+                let current = cursor.thread_id().map(|thread_id| thread_id.0);
+                let stack = if thread_id.is_none() || thread_id == current {
+                    cursor.stack()
+                } else {
+                    vec![]
+                };
+                let frames: Vec<Value> = stack
+                    .iter()
+                    .enumerate()
+                    .map(|(id, frame)| {
+                        let mut stack_frame = json!({ "id": id, "name": frame.function, "line": 0, "column": 0 });
+                        if let Some(location) = &frame.location {
+                            stack_frame["line"] = json!(location.line);
+                            stack_frame["column"] = json!(1);
+                            stack_frame["source"] = source(&location.path);
+                        }
+                        stack_frame
+                    })
+                    .collect();
+                Ok(json!({ "stackFrames": frames, "totalFrames": stack.len() }))
+            }
+            "scopes" => {
+                let call_key = self.frame_call(arguments)?;
+                self.handles.push(Handle::Locals(call_key));
+                let reference = self.handles.len();
+                Ok(json!({ "scopes": [
+                    { "name": "Locals", "presentationHint": "locals", "variablesReference": reference, "expensive": false },
+                ] }))
+            }
+            "variables" => {
+                let reference = arguments["variablesReference"].as_u64().unwrap_or_default();
+                let children = match self.handle_of(reference) {
+                    Some(Handle::Locals(call_key)) => self.cursor.as_ref().unwrap().frame_locals(*call_key),
+                    Some(Handle::Value(value)) => self.children(value),
+                    None => return Err(format!("unknown variables reference {reference}")),
+                };
+                let variables: Vec<Value> = children.into_iter().map(|(name, value)| self.variable(name, value)).collect();
+                Ok(json!({ "variables": variables }))
+            }
+            "evaluate" => {
+                let expression = arguments["expression"].as_str().unwrap_or_default().trim();
+                let call_key = self.frame_call(arguments)?;
+                let cursor = self.cursor.as_ref().unwrap();
+                let Some((name, value)) = cursor.frame_locals(call_key).into_iter().find(|(name, _)| name == expression) else {
+                    return Err(format!("no variable named '{expression}' here"));
+                };
+                let variable = self.variable(name, value);
+                Ok(json!({
+                    "result": variable["value"],
+                    "type": variable["type"],
+                    "variablesReference": variable["variablesReference"],
+                }))
+            }
+            "continue" => Ok(json!({ "allThreadsContinued": true })),
+            "next" | "stepIn" | "stepOut" | "stepBack" | "reverseContinue" => Ok(json!({})),
+            _ => Err(format!("unsupported request '{command}'")),
+        }
+    }
+
+    fn handle_of(&self, reference: u64) -> Option<&Handle> {
+        usize::try_from(reference).ok()?.checked_sub(1).and_then(|index| self.handles.get(index))
+    }
+
+    // the call of the frame in `arguments["frameId"]`, the innermost one
+    // when there is none
+    fn frame_call(&mut self, arguments: &Value) -> Result<CallKey, String> {
+        let cursor = self.cursor.as_mut().ok_or("no launched trace")?;
+        let frame_id = arguments["frameId"].as_u64().unwrap_or_default() as usize;
+        cursor
+            .stack()
+            .get(frame_id)
+            .map(|frame| frame.call_key)
+            .ok_or_else(|| format!("unknown frame {frame_id}"))
+    }
+
+    fn variable(&mut self, name: String, value: ValueRecord) -> Value {
+        let value = self.shared_object(value);
+        let text = self.value_text(&value);
+        let type_name = value.type_id().map(|type_id| self.type_name(type_id));
+        let reference = if self.children(&value).is_empty() {
+            0
+        } else {
+            self.handles.push(Handle::Value(value));
+            self.handles.len()
+        };
+        json!({ "name": name, "value": text, "type": type_name, "variablesReference": reference })
+    }
+
+    // the object a back-reference points to, so that it reads and expands
+    // like the variable that defined it
+    fn shared_object(&self, value: ValueRecord) -> ValueRecord {
+        let object = match &value {
+            ValueRecord::BackRef { id, .. } => self.cursor.as_ref().and_then(|cursor| cursor.object(*id)),
+            _ => None,
+        };
+        object.map_or(value, |object| (*object).clone())
+    }
+
+    fn type_name(&self, type_id: TypeId) -> String {
+        self.cursor
+            .as_ref()
+            .and_then(|cursor| cursor.type_record(type_id))
+            .map_or_else(|| format!("<type {}>", type_id.0), |type_record| type_record.lang_type.clone())
+    }
+
+    // the one-line summary shown next to the name
+    fn value_text(&self, value: &ValueRecord) -> String {
+        let hex = |bytes: &[u8]| bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        match value {
+            ValueRecord::Int { i, .. } => i.to_string(),
+            ValueRecord::UInt { u, .. } => u.to_string(),
+            ValueRecord::Int128 { i, .. } => i.to_string(),
+            ValueRecord::UInt128 { u, .. } => u.to_string(),
+            ValueRecord::Float { f, .. } => format!("{f:?}"),
+            ValueRecord::Bool { b, .. } => b.to_string(),
+            ValueRecord::String { text, .. } => format!("{text:?}"),
+            ValueRecord::Char { c, .. } => format!("{c:?}"),
+            ValueRecord::Raw { r, .. } => r.clone(),
+            ValueRecord::Error { msg, .. } => format!("<error: {msg}>"),
+            ValueRecord::None { type_id } => self.type_name(*type_id),
+            ValueRecord::BigInt { b, negative, .. } => format!("{}0x{}", if *negative { "-" } else { "" }, hex(b)),
+            ValueRecord::Bytes { data, .. } => format!("bytes {}", hex(data)),
+            ValueRecord::Sequence { elements, type_id, .. } | ValueRecord::Tuple { elements, type_id } => {
+                format!("{} ({} items)", self.type_name(*type_id), elements.len())
+            }
+            ValueRecord::Map { entries, type_id } => format!("{} ({} items)", self.type_name(*type_id), entries.len()),
+            ValueRecord::Struct { type_id, .. } => self.type_name(*type_id),
+            ValueRecord::Variant { discriminator, type_id, .. } => format!("{}::{discriminator}", self.type_name(*type_id)),
+            ValueRecord::Reference { dereferenced, mutable, .. } => {
+                format!("{}{}", if *mutable { "&mut " } else { "&" }, self.value_text(dereferenced))
+            }
+            ValueRecord::Cell { place } => format!("<cell @{}>", place.0),
+            ValueRecord::Elided { partial, original_len, .. } => {
+                format!("{} (elided from {original_len})", self.value_text(partial))
+            }
+            ValueRecord::Object { value, .. } => self.value_text(value),
+            ValueRecord::BackRef { type_id, .. } => format!("<shared {}>", self.type_name(*type_id)),
+        }
+    }
+
+    // the named parts of a compound value, shown when it is expanded
+    fn children(&self, value: &ValueRecord) -> Vec<(String, ValueRecord)> {
+        match value {
+            ValueRecord::Sequence { elements, .. } => elements
+                .iter()
+                .enumerate()
+                .map(|(index, element)| (format!("[{index}]"), element.clone()))
+                .collect(),
+            ValueRecord::Tuple { elements, .. } => elements
+                .iter()
+                .enumerate()
+                .map(|(index, element)| (index.to_string(), element.clone()))
+                .collect(),
+            ValueRecord::Struct { field_values, type_id } => {
+                let fields = match self.cursor.as_ref().and_then(|cursor| cursor.type_record(*type_id)) {
+                    Some(type_record) => match &type_record.specific_info {
+                        TypeSpecificInfo::Struct { fields } => fields.iter().map(|field| field.name.clone()).collect(),
+                        _ => vec![],
+                    },
+                    None => vec![],
+                };
+                field_values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field_value)| (fields.get(index).cloned().unwrap_or_else(|| index.to_string()), field_value.clone()))
+                    .collect()
+            }
+            ValueRecord::Map { entries, .. } => entries.iter().map(|(key, value)| (self.value_text(key), value.clone())).collect(),
+            ValueRecord::Variant { discriminator, contents, .. } => vec![(discriminator.clone(), (**contents).clone())],
+            ValueRecord::Reference { dereferenced, .. } => vec![("*".to_string(), (**dereferenced).clone())],
+            ValueRecord::Elided { partial, .. } => self.children(partial),
+            ValueRecord::Object { value, .. } => self.children(value),
+            _ => vec![],
+        }
+    }
+}
+
+fn open_cursor(program: &str) -> Result<ReplayCursor<Box<dyn EventSource>>, String> {
+    let error = |e: Box<dyn std::error::Error>| format!("cannot read trace '{program}': {e}");
+    let source: Box<dyn EventSource> = if program.ends_with(".ct") {
+        Box::new(ChunkedEvents::open(Path::new(program)).map_err(error)?)
+    } else {
+        let format = determine_input_file_format_from_name(program).ok_or_else(|| format!("cannot tell the trace format of '{program}'"))?;
+        Box::new(create_trace_reader(format).load_trace_events(Path::new(program)).map_err(error)?)
+    };
+    Ok(ReplayCursor::new(source))
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or(&[])
+}
+
+fn source(path: &Path) -> Value {
+    let name = path
+        .file_name()
+        .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+    json!({ "name": name, "path": path.display().to_string() })
+}
+
+// `x > 10` or `> 10`: the comparison of a data breakpoint's condition on
+// `variable`; a condition on anything else is refused
+fn parse_condition(variable: &str, condition: &str) -> Option<(Op, Literal)> {
+    [format!("* where {condition}"), format!("* where value {condition}")]
+        .iter()
+        .find_map(|query| match Query::parse(query).ok()?.condition? {
+            Expr::Compare(operand, op, literal) => {
+                let on_variable = match &operand.field {
+                    Field::Value => true,
+                    Field::Variable(name) => name == variable,
+                    _ => false,
+                };
+                (on_variable && operand.property == ValueProperty::Itself).then_some((op, literal))
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,82 @@
+use clap::Args;
+use codetracer_trace_reader::trace_diff::TraceDiff;
+use serde_json::json;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DiffCommand {
+    /// The first trace (.json, .bin or .ct)
+    left_file: String,
+
+    /// The trace to compare it with
+    right_file: String,
+
+    /// Print the result as JSON
+    #[arg(long, default_value_t = false)]
+    json: bool,
+}
+
+pub(crate) fn run(cmd: DiffCommand) {
+    let left = load_trace_or_exit(&cmd.left_file);
+    let right = load_trace_or_exit(&cmd.right_file);
+
+    let diff = TraceDiff::compute(&left, &right);
+    if cmd.json {
+        let divergence = diff.divergence.as_ref().map(|divergence| {
+            json!({
+                "left_geid": divergence.left_geid,
+                "right_geid": divergence.right_geid,
+                "left": divergence.left.as_ref().map(ToString::to_string),
+                "right": divergence.right.as_ref().map(ToString::to_string),
+                "last_step": divergence.last_step.as_ref().map(|(path, line)| json!({ "path": path, "line": line })),
+                "stack": divergence.stack,
+            })
+        });
+        let call_counts: Vec<serde_json::Value> = diff
+            .call_counts
+            .iter()
+            .map(|counts| json!({ "function": counts.function, "left": counts.left, "right": counts.right }))
+            .collect();
+        let result = json!({
+            "equivalent": diff.is_equivalent(),
+            "matching_entries": diff.matching_entries,
+            "matching_steps": diff.matching_steps,
+            "divergence": divergence,
+            "call_counts": call_counts,
+        });
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    } else {
+        match &diff.divergence {
+            None => println!("the traces are equivalent: {} steps", diff.matching_steps),
+            Some(divergence) => {
+                println!(
+                    "the traces diverge after {} matching entries ({} steps)",
+                    diff.matching_entries, diff.matching_steps
+                );
+                if let Some((path, line)) = &divergence.last_step {
+                    println!("  last common step: {path}:{line}");
+                }
+                if !divergence.stack.is_empty() {
+                    println!("  stack: {}", divergence.stack.join(" > "));
+                }
+                let side = |geid: Option<u64>, entry: &Option<_>| match (geid, entry) {
+                    (Some(geid), Some(entry)) => format!("geid {geid}: {entry}"),
+                    _ => "end of trace".to_string(),
+                };
+                println!("  {}: {}", cmd.left_file, side(divergence.left_geid, &divergence.left));
+                println!("  {}: {}", cmd.right_file, side(divergence.right_geid, &divergence.right));
+            }
+        }
+        if !diff.call_counts.is_empty() {
+            println!("call counts:");
+            for counts in &diff.call_counts {
+                println!("  {:30} {:>8} {:>8}", counts.function, counts.left, counts.right);
+            }
+        }
+    }
+
+    if !diff.is_equivalent() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,87 @@
+use clap::Args;
+use codetracer_trace_reader::variable_history::{ChangeKind, VariableChange, VariableHistory};
+use codetracer_trace_types::TraceLowLevelEvent;
+use serde_json::json;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct HistoryCommand {
+    /// Trace file to read (.json, .bin or .ct)
+    input_file: String,
+
+    /// Name of the variable
+    variable: String,
+
+    /// Print the timeline as JSON
+    #[arg(long, default_value_t = false)]
+    json: bool,
+}
+
+fn kind_name(kind: &ChangeKind) -> String {
+    match kind {
+        ChangeKind::Value => "value".to_string(),
+        ChangeKind::Bind(place) => format!("bind @{}", place.0),
+        ChangeKind::Assign { by_reference: false } => "assign".to_string(),
+        ChangeKind::Assign { by_reference: true } => "assign by reference".to_string(),
+        ChangeKind::Cell(place) => format!("cell @{}", place.0),
+        ChangeKind::Drop => "drop".to_string(),
+    }
+}
+
+pub(crate) fn run(cmd: HistoryCommand) {
+    let events = load_trace_or_exit(&cmd.input_file);
+    let function_names: Vec<&str> = events
+        .iter()
+        .filter_map(|event| match event {
+            TraceLowLevelEvent::Function(function) => Some(function.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let history = VariableHistory::from_events(&events);
+    if history.variable_id(&cmd.variable).is_none() {
+        eprintln!("Error: no variable named '{}' in '{}'", cmd.variable, cmd.input_file);
+        std::process::exit(1);
+    }
+    let function_name = |change: &VariableChange| {
+        history
+            .call_tree()
+            .call(change.call_key)
+            .and_then(|call| function_names.get(call.function_id.0).copied())
+            .unwrap_or("")
+    };
+
+    let timeline = history.timeline_by_name(&cmd.variable);
+    if cmd.json {
+        let changes: Vec<serde_json::Value> = timeline
+            .iter()
+            .map(|change| {
+                json!({
+                    "geid": change.geid,
+                    "step_id": change.step_id.map(|step_id| step_id.0),
+                    "call_key": change.call_key.0,
+                    "function": function_name(change),
+                    "kind": kind_name(&change.kind),
+                    "value": change.value,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&changes).unwrap());
+        return;
+    }
+
+    for change in timeline {
+        let step = change.step_id.map_or("-".to_string(), |step_id| step_id.0.to_string());
+        let value = change.value.as_ref().map_or(String::new(), |value| serde_json::to_string(value).unwrap());
+        println!(
+            "geid {:>8}  step {:>6}  call {:>6} {:20} {:20} {}",
+            change.geid,
+            step,
+            change.call_key.0,
+            function_name(change),
+            kind_name(&change.kind),
+            value
+        );
+    }
+}
@@ -1,12 +1,31 @@
 use std::path::Path;
 
+use crate::chrome_trace_cmd::ChromeTraceCommand;
+use crate::coverage_cmd::CoverageCommand;
+use crate::dap_cmd::DapCommand;
+use crate::diff_cmd::DiffCommand;
 use crate::fmt_trace_cmd::FmtTraceCommand;
+use crate::history_cmd::HistoryCommand;
 use crate::inspect_ctfs_cmd::InspectCtfsCommand;
+use crate::profile_cmd::ProfileCommand;
+use crate::query_cmd::QueryCommand;
+use crate::slice_cmd::SliceCommand;
+use crate::validate_cmd::ValidateCommand;
 use clap::{Args, Parser, Subcommand};
 use codetracer_trace_reader::create_trace_reader;
+use codetracer_trace_types::TraceLowLevelEvent;
 use codetracer_trace_writer::{create_trace_writer, trace_writer::TraceWriter};
+mod chrome_trace_cmd;
+mod coverage_cmd;
+mod dap_cmd;
+mod diff_cmd;
 mod fmt_trace_cmd;
+mod history_cmd;
 mod inspect_ctfs_cmd;
+mod profile_cmd;
+mod query_cmd;
+mod slice_cmd;
+mod validate_cmd;
 
 #[derive(Debug, Clone, Args)]
 struct ConvertCommand {
@@ -23,6 +42,24 @@ enum RuntimeTracingCliCommand {
     FormatTrace(FmtTraceCommand),
     /// Inspect a .ct CTFS container file
     InspectCtfs(InspectCtfsCommand),
+    /// Check that a trace is semantically well-formed
+    Validate(ValidateCommand),
+    /// Show every change of a variable with the step and call it happened in
+    History(HistoryCommand),
+    /// Print the events matching a query
+    Query(QueryCommand),
+    /// Find the first point where two recordings of a program diverge
+    Diff(DiffCommand),
+    /// Export the line and function coverage of a trace as lcov or Cobertura
+    Coverage(CoverageCommand),
+    /// Export a step-count profile as folded stacks or pprof
+    Profile(ProfileCommand),
+    /// Export the call timeline of every thread as a Chrome trace for Perfetto
+    ChromeTrace(ChromeTraceCommand),
+    /// Serve the Debug Adapter Protocol over stdio, replaying a trace
+    Dap(DapCommand),
+    /// Cut a GEID range out of a trace into a trace that loads on its own
+    Slice(SliceCommand),
 }
 
 #[derive(Parser, Debug)]
@@ -44,6 +81,20 @@ fn determine_input_file_format_from_name(s: &str) -> Option<codetracer_trace_rea
     }
 }
 
+/// Load all events of `input_file`, exiting with status 2 when the trace
+/// can't be read.
+fn load_trace_or_exit(input_file: &str) -> Vec<TraceLowLevelEvent> {
+    let Some(format) = determine_input_file_format_from_name(input_file) else {
+        eprintln!("Error: cannot tell the trace format of '{}'", input_file);
+        std::process::exit(2);
+    };
+    let mut reader = create_trace_reader(format);
+    reader.load_trace_events(Path::new(input_file)).unwrap_or_else(|e| {
+        eprintln!("Error: cannot read trace '{}': {}", input_file, e);
+        std::process::exit(2);
+    })
+}
+
 fn determine_output_file_format_from_name(s: &str) -> Option<codetracer_trace_writer::TraceEventsFileFormat> {
     if s.ends_with(".json") {
         Some(codetracer_trace_writer::TraceEventsFileFormat::Json)
@@ -76,5 +127,32 @@ fn main() {
         RuntimeTracingCliCommand::InspectCtfs(inspect_ctfs_cmd) => {
             inspect_ctfs_cmd::run(inspect_ctfs_cmd);
         }
+        RuntimeTracingCliCommand::Validate(validate_cmd) => {
+            validate_cmd::run(validate_cmd);
+        }
+        RuntimeTracingCliCommand::History(history_cmd) => {
+            history_cmd::run(history_cmd);
+        }
+        RuntimeTracingCliCommand::Query(query_cmd) => {
+            query_cmd::run(query_cmd);
+        }
+        RuntimeTracingCliCommand::Diff(diff_cmd) => {
+            diff_cmd::run(diff_cmd);
+        }
+        RuntimeTracingCliCommand::Coverage(coverage_cmd) => {
+            coverage_cmd::run(coverage_cmd);
+        }
+        RuntimeTracingCliCommand::Profile(profile_cmd) => {
+            profile_cmd::run(profile_cmd);
+        }
+        RuntimeTracingCliCommand::ChromeTrace(chrome_trace_cmd) => {
+            chrome_trace_cmd::run(chrome_trace_cmd);
+        }
+        RuntimeTracingCliCommand::Dap(dap_cmd) => {
+            dap_cmd::run(dap_cmd);
+        }
+        RuntimeTracingCliCommand::Slice(slice_cmd) => {
+            slice_cmd::run(slice_cmd);
+        }
     }
 }
@@ -0,0 +1,35 @@
+use clap::Args;
+use codetracer_trace_reader::profile::Profile;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ProfileCommand {
+    /// Trace file to read (.json, .bin or .ct)
+    input_file: String,
+
+    /// Profile to write: folded stacks for .folded, pprof for .pb or .pprof
+    output_file: String,
+}
+
+pub(crate) fn run(cmd: ProfileCommand) {
+    let export: fn(&Profile) -> Vec<u8> = if cmd.output_file.ends_with(".folded") {
+        |profile| profile.to_folded().into_bytes()
+    } else if cmd.output_file.ends_with(".pb") || cmd.output_file.ends_with(".pprof") {
+        Profile::to_pprof
+    } else {
+        eprintln!(
+            "Error: cannot tell the profile format of '{}', use .folded, .pb or .pprof",
+            cmd.output_file
+        );
+        std::process::exit(2);
+    };
+    let events = load_trace_or_exit(&cmd.input_file);
+
+    let profile = Profile::from_events(&events);
+    if let Err(e) = std::fs::write(&cmd.output_file, export(&profile)) {
+        eprintln!("Error: cannot write '{}': {}", cmd.output_file, e);
+        std::process::exit(2);
+    }
+    println!("{}: {} steps", cmd.output_file, profile.total_steps());
+}
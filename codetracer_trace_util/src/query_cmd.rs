@@ -0,0 +1,77 @@
+use clap::Args;
+use codetracer_trace_reader::query::{Query, QueryEvaluator, QueryMatch};
+use serde_json::json;
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QueryCommand {
+    /// Trace file to search (.json, .bin or .ct)
+    input_file: String,
+
+    /// The query, e.g. `step where path = "main.py" and x > 1`
+    query: String,
+
+    /// Print the matches as JSON Lines
+    #[arg(long, default_value_t = false)]
+    json: bool,
+
+    /// Stop after this many matches
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+fn print_match(found: &QueryMatch, as_json: bool) {
+    if as_json {
+        let line = json!({
+            "geid": found.geid,
+            "kind": found.event.kind_name(),
+            "path": found.path,
+            "line": found.line,
+            "function": found.function,
+            "thread_id": found.thread_id.0,
+            "call_key": found.call_key.0,
+            "step_id": found.step_id.map(|step_id| step_id.0),
+            "event": serde_json::to_value(&found.event).ok(),
+        });
+        println!("{line}");
+        return;
+    }
+    let location = match (&found.path, found.line) {
+        (Some(path), Some(line)) => format!("{}:{}", path.display(), line),
+        _ => "-".to_string(),
+    };
+    println!(
+        "geid {:>8}  {:16} thread {:>4}  call {:>6} {:20} {}",
+        found.geid,
+        found.event.kind_name(),
+        found.thread_id.0,
+        found.call_key.0,
+        found.function.as_deref().unwrap_or(""),
+        location
+    );
+}
+
+pub(crate) fn run(cmd: QueryCommand) {
+    let query = Query::parse(&cmd.query).unwrap_or_else(|e| {
+        eprintln!("Error: invalid query: {e}");
+        std::process::exit(2);
+    });
+    let events = load_trace_or_exit(&cmd.input_file);
+
+    let limit = cmd.limit.unwrap_or(usize::MAX);
+    let mut evaluator = QueryEvaluator::new(query);
+    let mut count = 0;
+    for (geid, event) in events.iter().enumerate() {
+        for found in evaluator.observe(geid as u64, event) {
+            if count == limit {
+                return;
+            }
+            print_match(&found, cmd.json);
+            count += 1;
+        }
+    }
+    for found in evaluator.finish().iter().take(limit - count) {
+        print_match(found, cmd.json);
+    }
+}
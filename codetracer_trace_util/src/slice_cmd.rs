@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use codetracer_trace_reader::ctfs_reader::read_trace_metadata;
+use codetracer_trace_reader::trace_slice::{TraceSlicer, slice_ctfs};
+use codetracer_trace_types::TraceMetadata;
+use codetracer_trace_writer::{TraceEventsFileFormat, create_trace_writer, trace_writer::TraceWriter};
+
+use crate::{determine_output_file_format_from_name, load_trace_or_exit};
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SliceCommand {
+    /// Trace file to read (.json, .bin or .ct)
+    input_file: String,
+
+    /// GEID of the first event to keep
+    from: u64,
+
+    /// GEID of the first event after the slice
+    to: u64,
+
+    /// Trace file to write (.json, .bin or .ct)
+    output_file: String,
+}
+
+pub(crate) fn run(cmd: SliceCommand) {
+    if cmd.from >= cmd.to {
+        eprintln!("Error: the range {}..{} is empty", cmd.from, cmd.to);
+        std::process::exit(2);
+    }
+    let Some(output_format) = determine_output_file_format_from_name(&cmd.output_file) else {
+        eprintln!("Error: cannot tell the trace format of '{}'", cmd.output_file);
+        std::process::exit(2);
+    };
+
+    // a CTFS container is read a chunk at a time, up to `to`
+    let mut slice = if cmd.input_file.ends_with(".ct") {
+        slice_ctfs(Path::new(&cmd.input_file), cmd.from, cmd.to).unwrap_or_else(|e| {
+            eprintln!("Error: cannot read trace '{}': {}", cmd.input_file, e);
+            std::process::exit(2);
+        })
+    } else {
+        TraceSlicer::slice(&load_trace_or_exit(&cmd.input_file), cmd.from, cmd.to)
+    };
+
+    // the slice is a recording of its own, derived from the input's
+    let source_metadata = read_metadata(&cmd.input_file).unwrap_or_else(|e| {
+        eprintln!("Error: cannot read the metadata of '{}': {}", cmd.input_file, e);
+        std::process::exit(2);
+    });
+    let mut writer = create_trace_writer("", &[], output_format);
+    match &source_metadata {
+        Some(metadata) => TraceWriter::derive_from(writer.as_mut(), metadata),
+        None => eprintln!("Warning: '{}' has no metadata, the slice gets none either", cmd.input_file),
+    }
+    // a CTFS container holds its metadata; a JSON or binary trace has it in
+    // the file next to it, unless that is the input's
+    let metadata_file = Some(metadata_file_of(&cmd.output_file))
+        .filter(|_| !matches!(output_format, TraceEventsFileFormat::Ctfs))
+        .filter(|metadata_file| {
+            let shared = *metadata_file == metadata_file_of(&cmd.input_file);
+            if shared {
+                eprintln!("Warning: not writing {}, it belongs to '{}'", metadata_file.display(), cmd.input_file);
+            }
+            !shared
+        });
+
+    let written = (|| -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(metadata_file) = &metadata_file {
+            TraceWriter::begin_writing_trace_metadata(writer.as_mut(), metadata_file)?;
+        }
+        writer.begin_writing_trace_events(Path::new(&cmd.output_file))?;
+        TraceWriter::append_events(writer.as_mut(), &mut slice);
+        writer.finish_writing_trace_events()?;
+        if metadata_file.is_some() {
+            TraceWriter::finish_writing_trace_metadata(writer.as_mut())?;
+        }
+        Ok(())
+    })();
+    if let Err(e) = written {
+        eprintln!("Error: cannot write '{}': {}", cmd.output_file, e);
+        std::process::exit(2);
+    }
+}
+
+// `trace_metadata.json` in the directory of a JSON or binary trace
+fn metadata_file_of(trace_file: &str) -> PathBuf {
+    Path::new(trace_file).with_file_name("trace_metadata.json")
+}
+
+fn read_metadata(input_file: &str) -> Result<Option<TraceMetadata>, Box<dyn std::error::Error>> {
+    if input_file.ends_with(".ct") {
+        return read_trace_metadata(Path::new(input_file));
+    }
+    let metadata_file = metadata_file_of(input_file);
+    if !metadata_file.exists() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&std::fs::read(metadata_file)?)?))
+}
@@ -0,0 +1,30 @@
+use clap::Args;
+use codetracer_trace_reader::validator::{Severity, Validator};
+
+use crate::load_trace_or_exit;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ValidateCommand {
+    /// Trace file to check (.json, .bin or .ct)
+    input_file: String,
+
+    /// Fail on warnings too
+    #[arg(long, default_value_t = false)]
+    strict: bool,
+}
+
+pub(crate) fn run(cmd: ValidateCommand) {
+    let events = load_trace_or_exit(&cmd.input_file);
+
+    let problems = Validator::validate(&events);
+    for problem in &problems {
+        println!("{problem}");
+    }
+    let errors = problems.iter().filter(|problem| problem.severity() == Severity::Error).count();
+    let warnings = problems.len() - errors;
+    println!("{}: {} events, {} errors, {} warnings", cmd.input_file, events.len(), errors, warnings);
+
+    if errors > 0 || (cmd.strict && warnings > 0) {
+        std::process::exit(1);
+    }
+}
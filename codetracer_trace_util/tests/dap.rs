@@ -0,0 +1,315 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use codetracer_trace_types::*;
+use codetracer_trace_writer::ctfs_writer::CtfsTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+use serde_json::{Value, json};
+
+fn int(i: i64) -> ValueRecord {
+    ValueRecord::Int { i, type_id: TypeId(1) }
+}
+
+// main.py: lines 2-5 at the top level call square (lines 10-11) twice
+fn write_trace(dir: &Path) -> String {
+    let trace_path = dir.join("trace");
+    let mut writer = CtfsTraceWriter::new("dap", &[]);
+    let writer: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::begin_writing_trace_events(writer, &trace_path).unwrap();
+    let path = Path::new("/src/main.py");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let point = TraceWriter::ensure_raw_type_id(
+        writer,
+        TypeRecord {
+            kind: TypeKind::Struct,
+            lang_type: "Point".to_string(),
+            specific_info: TypeSpecificInfo::Struct {
+                fields: vec![
+                    FieldTypeRecord {
+                        name: "x".to_string(),
+                        type_id: TypeId(1),
+                    },
+                    FieldTypeRecord {
+                        name: "y".to_string(),
+                        type_id: TypeId(1),
+                    },
+                ],
+            },
+        },
+    );
+    let square = TraceWriter::ensure_function_id(writer, "square", path, Line(10));
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(
+        writer,
+        "p",
+        ValueRecord::Struct {
+            field_values: vec![int(1), int(2)],
+            type_id: point,
+        },
+    );
+    for n in [1, 2] {
+        TraceWriter::register_step(writer, path, Line(3));
+        let arg = TraceWriter::arg(writer, "n", int(n));
+        TraceWriter::register_call(writer, square, vec![arg]);
+        TraceWriter::register_step(writer, path, Line(11));
+        TraceWriter::register_variable_with_full_value(writer, "r", int(n * n));
+        TraceWriter::register_return(writer, int(n * n));
+        TraceWriter::register_step(writer, path, Line(4));
+    }
+    TraceWriter::register_step(writer, path, Line(5));
+    TraceWriter::finish_writing_trace_events(writer).unwrap();
+    trace_path.with_extension("ct").display().to_string()
+}
+
+// shared.py: a and b at line 2 hold the same reference, so b is recorded
+// as a back-reference to a
+fn write_shared_trace(dir: &Path) -> String {
+    let trace_path = dir.join("trace");
+    let mut writer = CtfsTraceWriter::new("dap", &[]);
+    let writer: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::begin_writing_trace_events(writer, &trace_path).unwrap();
+    let path = Path::new("/src/shared.py");
+    TraceWriter::start(writer, path, Line(1));
+    TraceWriter::set_reference_dedup(writer, true);
+    TraceWriter::ensure_type_id(writer, TypeKind::Int, "int");
+    let ref_type = TraceWriter::ensure_type_id(writer, TypeKind::Ref, "ref int");
+    let shared = ValueRecord::Reference {
+        dereferenced: Box::new(int(42)),
+        address: 0x1000,
+        mutable: false,
+        type_id: ref_type,
+    };
+    TraceWriter::register_step(writer, path, Line(2));
+    TraceWriter::register_variable_with_full_value(writer, "a", shared.clone());
+    TraceWriter::register_variable_with_full_value(writer, "b", shared);
+    TraceWriter::register_step(writer, path, Line(3));
+    TraceWriter::finish_writing_trace_events(writer).unwrap();
+    trace_path.with_extension("ct").display().to_string()
+}
+
+/// Send the requests to `codetracer_trace_util dap` and return every
+/// message it answered with.
+fn session(requests: &[(&str, Value)]) -> Vec<Value> {
+    let mut input = vec![];
+    for (seq, (command, arguments)) in requests.iter().enumerate() {
+        let request = json!({ "seq": seq + 1, "type": "request", "command": command, "arguments": arguments }).to_string();
+        write!(input, "Content-Length: {}\r\n\r\n{}", request.len(), request).unwrap();
+    }
+    let mut child = Command::new(env!("CARGO_BIN_EXE_codetracer_trace_util"))
+        .arg("dap")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(&input).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let mut messages = vec![];
+    let mut rest = output.stdout.as_slice();
+    while !rest.is_empty() {
+        let header_end = rest.windows(4).position(|window| window == b"\r\n\r\n").unwrap();
+        let header = std::str::from_utf8(&rest[..header_end]).unwrap();
+        let length: usize = header.strip_prefix("Content-Length: ").unwrap().parse().unwrap();
+        let content = &rest[header_end + 4..header_end + 4 + length];
+        messages.push(serde_json::from_slice(content).unwrap());
+        rest = &rest[header_end + 4 + length..];
+    }
+    messages
+}
+
+fn response(messages: &[Value], request_seq: u64) -> &Value {
+    messages
+        .iter()
+        .find(|message| message["type"] == "response" && message["request_seq"] == request_seq)
+        .unwrap()
+}
+
+// the `stopped` events, as (reason, line of the top frame of the next
+// stackTrace)
+fn stops(messages: &[Value]) -> Vec<(String, i64)> {
+    let mut stops = vec![];
+    for (index, message) in messages.iter().enumerate() {
+        if message["event"] == "stopped" {
+            let stack_trace = messages[index..].iter().find(|message| message["command"] == "stackTrace").unwrap();
+            let line = stack_trace["body"]["stackFrames"][0]["line"].as_i64().unwrap();
+            stops.push((message["body"]["reason"].as_str().unwrap().to_string(), line));
+        }
+    }
+    stops
+}
+
+#[test]
+fn test_dap_session() {
+    let dir = tempfile::tempdir().unwrap();
+    let trace = write_trace(dir.path());
+    let stack_trace = ("stackTrace", json!({ "threadId": 0 }));
+    let messages = session(&[
+        ("initialize", json!({ "adapterID": "codetracer" })),
+        ("launch", json!({ "program": trace })),
+        (
+            "setBreakpoints",
+            json!({ "source": { "path": "/src/main.py" }, "breakpoints": [{ "line": 11 }] }),
+        ),
+        ("configurationDone", json!({})),
+        stack_trace.clone(),
+        ("scopes", json!({ "frameId": 0 })),
+        ("variables", json!({ "variablesReference": 1 })),
+        ("variables", json!({ "variablesReference": 2 })),
+        ("continue", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("scopes", json!({ "frameId": 0 })),
+        ("variables", json!({ "variablesReference": 1 })),
+        ("stepBack", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("next", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("stepOut", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("continue", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("continue", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("reverseContinue", json!({ "threadId": 0 })),
+        stack_trace.clone(),
+        ("disconnect", json!({})),
+    ]);
+
+    assert!(messages.iter().all(|message| message["type"] == "event" || message["success"] == true));
+    assert_eq!(response(&messages, 1)["body"]["supportsStepBack"], true);
+    let initialized = messages.iter().position(|message| message["event"] == "initialized").unwrap();
+    assert!(initialized > messages.iter().position(|message| message["command"] == "launch").unwrap());
+    assert_eq!(response(&messages, 3)["body"]["breakpoints"][0]["verified"], true);
+
+    // p at the first step, and its fields
+    let locals = &response(&messages, 7)["body"]["variables"];
+    assert_eq!(locals[0]["name"], "p");
+    assert_eq!(locals[0]["value"], "Point");
+    assert_eq!(locals[0]["variablesReference"], 2);
+    let fields: Vec<(&str, &str)> = response(&messages, 8)["body"]["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| (field["name"].as_str().unwrap(), field["value"].as_str().unwrap()))
+        .collect();
+    assert_eq!(fields, vec![("x", "1"), ("y", "2")]);
+
+    // in square, called from line 3
+    let frames = &response(&messages, 10)["body"]["stackFrames"];
+    assert_eq!((&frames[0]["name"], &frames[0]["line"]), (&json!("square"), &json!(11)));
+    assert_eq!((&frames[1]["name"], &frames[1]["line"]), (&json!("<toplevel>"), &json!(3)));
+    assert_eq!(frames[0]["source"]["path"], "/src/main.py");
+    let variables: Vec<(&str, &str, &str)> = response(&messages, 12)["body"]["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|variable| {
+            (
+                variable["name"].as_str().unwrap(),
+                variable["value"].as_str().unwrap(),
+                variable["type"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(variables, vec![("n", "1", "int"), ("r", "1", "int")]);
+
+    assert_eq!(
+        stops(&messages),
+        vec![
+            ("entry".to_string(), 2),
+            ("breakpoint".to_string(), 11),
+            ("step".to_string(), 10),
+            ("breakpoint".to_string(), 11),
+            ("step".to_string(), 4),
+            ("breakpoint".to_string(), 11),
+            ("step".to_string(), 5),
+            ("breakpoint".to_string(), 11),
+        ]
+    );
+    let end = messages.iter().filter(|message| message["event"] == "stopped").nth(6).unwrap();
+    assert_eq!(end["body"]["description"], "end of the trace");
+}
+
+#[test]
+fn test_dap_data_breakpoints_and_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let trace = write_trace(dir.path());
+    let messages = session(&[
+        ("threads", json!({})),
+        ("launch", json!({ "program": dir.path().join("missing.ct") })),
+        ("launch", json!({ "program": trace, "stopOnEntry": false })),
+        ("dataBreakpointInfo", json!({ "name": "r" })),
+        (
+            "setDataBreakpoints",
+            json!({ "breakpoints": [
+                { "dataId": "r", "condition": "r > 1" },
+                { "dataId": "r", "condition": "r >" },
+                { "dataId": "r", "condition": "n > 1" },
+            ] }),
+        ),
+        ("configurationDone", json!({})),
+        ("stackTrace", json!({ "threadId": 0 })),
+        ("evaluate", json!({ "expression": "r", "frameId": 0 })),
+        ("threads", json!({})),
+        ("readMemory", json!({})),
+    ]);
+
+    assert_eq!(response(&messages, 1)["success"], false);
+    assert_eq!(response(&messages, 2)["success"], false);
+    assert_eq!(response(&messages, 4)["body"]["dataId"], "r");
+    let breakpoints = &response(&messages, 5)["body"]["breakpoints"];
+    let verified: Vec<&Value> = breakpoints.as_array().unwrap().iter().map(|breakpoint| &breakpoint["verified"]).collect();
+    // a condition on another variable is refused
+    assert_eq!(verified, vec![&json!(true), &json!(false), &json!(false)]);
+
+    // not stopping on entry runs to the second square, where r becomes 4
+    let stopped = messages.iter().find(|message| message["event"] == "stopped").unwrap();
+    assert_eq!(stopped["body"]["reason"], "data breakpoint");
+    assert_eq!(stopped["body"]["hitBreakpointIds"], json!([breakpoints[0]["id"]]));
+    assert_eq!(response(&messages, 7)["body"]["stackFrames"][0]["line"], 11);
+    assert_eq!(response(&messages, 8)["body"]["result"], "4");
+    assert_eq!(response(&messages, 9)["body"]["threads"], json!([{ "id": 0, "name": "thread 0" }]));
+    assert_eq!(response(&messages, 10)["message"], "unsupported request 'readMemory'");
+}
+
+#[test]
+fn test_dap_expands_shared_references() {
+    let dir = tempfile::tempdir().unwrap();
+    let trace = write_shared_trace(dir.path());
+    let messages = session(&[
+        ("launch", json!({ "program": trace })),
+        ("configurationDone", json!({})),
+        ("next", json!({ "threadId": 0 })),
+        ("scopes", json!({ "frameId": 0 })),
+        ("variables", json!({ "variablesReference": 1 })),
+        ("variables", json!({ "variablesReference": 3 })),
+        ("evaluate", json!({ "expression": "b", "frameId": 0 })),
+        ("variables", json!({ "variablesReference": 4 })),
+    ]);
+
+    assert!(messages.iter().all(|message| message["type"] == "event" || message["success"] == true));
+    // b, defined by a at the step before, reads and expands like a
+    let variables: Vec<(&str, &str, &str)> = response(&messages, 5)["body"]["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|variable| {
+            (
+                variable["name"].as_str().unwrap(),
+                variable["value"].as_str().unwrap(),
+                variable["type"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(variables, vec![("a", "&42", "ref int"), ("b", "&42", "ref int")]);
+    assert_eq!(response(&messages, 5)["body"]["variables"][1]["variablesReference"], 3);
+    assert_eq!(
+        response(&messages, 6)["body"]["variables"],
+        json!([{ "name": "*", "value": "42", "type": "int", "variablesReference": 0 }])
+    );
+    assert_eq!(response(&messages, 7)["body"]["result"], "&42");
+    assert_eq!(response(&messages, 7)["body"]["variablesReference"], 4);
+    assert_eq!(response(&messages, 8)["body"]["variables"][0]["value"], "42");
+}
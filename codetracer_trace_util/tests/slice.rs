@@ -0,0 +1,56 @@
+use std::path::Path;
+use std::process::Command;
+
+use codetracer_trace_reader::ctfs_reader::read_trace_metadata;
+use codetracer_trace_types::*;
+use codetracer_trace_writer::ctfs_writer::CtfsTraceWriter;
+use codetracer_trace_writer::trace_writer::TraceWriter;
+
+fn write_trace(dir: &Path) -> String {
+    let trace_path = dir.join("trace");
+    let mut writer = CtfsTraceWriter::new("app.py", &["--fast".to_string()]);
+    let writer: &mut dyn TraceWriter = &mut writer;
+    TraceWriter::set_language(writer, "python");
+    TraceWriter::begin_writing_trace_events(writer, &trace_path).unwrap();
+    let path = Path::new("/src/app.py");
+    TraceWriter::start(writer, path, Line(1));
+    for line in 2..6 {
+        TraceWriter::register_step(writer, path, Line(line));
+    }
+    TraceWriter::finish_writing_trace_events(writer).unwrap();
+    trace_path.with_extension("ct").display().to_string()
+}
+
+fn slice(input: &str, output: &Path) {
+    let status = Command::new(env!("CARGO_BIN_EXE_codetracer_trace_util"))
+        .args(["slice", input, "2", "4"])
+        .arg(output)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn test_slice_derives_metadata_from_the_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let trace = write_trace(dir.path());
+    let source = read_trace_metadata(Path::new(&trace)).unwrap().unwrap();
+
+    let ct_slice = dir.path().join("slice.ct");
+    slice(&trace, &ct_slice);
+    let json_dir = tempfile::tempdir().unwrap();
+    slice(&trace, &json_dir.path().join("trace.json"));
+    let json_metadata = std::fs::read(json_dir.path().join("trace_metadata.json")).unwrap();
+
+    for derived in [
+        read_trace_metadata(&ct_slice).unwrap().unwrap(),
+        serde_json::from_slice(&json_metadata).unwrap(),
+    ] {
+        assert_ne!(derived.recording_id, source.recording_id);
+        assert_eq!(derived.parent_recording_id.as_ref(), Some(&source.recording_id));
+        assert_eq!((&derived.program, &derived.args), (&source.program, &source.args));
+        assert_eq!(derived.workdir, source.workdir);
+        assert_eq!(derived.language.as_deref(), Some("python"));
+        assert_eq!((derived.start_time, derived.end_time), (source.start_time, source.end_time));
+    }
+}
@@ -6,11 +6,14 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use crate::capture_limits;
+use crate::object_identity::{self, ObjectKey};
 use codetracer_trace_types::{
-    AssignCellRecord, AssignCompoundItemRecord, AssignmentRecord, BindVariableRecord, CallRecord, CellValueRecord, CompoundValueRecord, EventLogKind,
-    FullValueRecord, FunctionId, FunctionRecord, Line, NONE_TYPE_ID, PassBy, PathId, Place, RValue, RecordEvent, ReturnRecord, StepRecord,
-    TOP_LEVEL_FUNCTION_ID, ThreadId, TraceLowLevelEvent, TraceMetadata, TypeId, TypeKind, TypeRecord, TypeSpecificInfo, ValueRecord,
-    VariableCellRecord, VariableId,
+    AllocRecord, AssignCellRecord, AssignCompoundItemRecord, AssignmentRecord, BindVariableRecord, CallRecord, CatchRecord, CellValueRecord,
+    CompoundValueRecord, EventAttribute, EventLogKind, ExecRecord, ExitRecord, FreeRecord, FullValueRecord, FunctionId, FunctionRecord, Line,
+    MemoryWriteRecord, NONE_TYPE_ID, ObjectId, PassBy, PathId, Place, ProcessStartRecord, RValue, RecordEvent, ReturnRecord, SignalRecord,
+    SourceSpan, StepRecord, TOP_LEVEL_FUNCTION_ID, TRACE_METADATA_VERSION, TaskId, ThreadId, ThrowRecord, TraceLowLevelEvent, TraceMetadata, TypeId,
+    TypeKind, TypeRecord, TypeSpecificInfo, UnwindRecord, ValueRecord, VariableCellRecord, VariableId, host_info,
 };
 
 pub struct AbstractTraceWriterData {
@@ -18,9 +21,20 @@ pub struct AbstractTraceWriterData {
     pub workdir: PathBuf,
     pub program: String,
     pub args: Vec<String>,
+    // the rest of the metadata, created with the writer, so it has the start
+    // time and recording id; `program`, `args` and `workdir` above replace
+    // its own when it is written, see `AbstractTraceWriter::trace_metadata`
+    pub metadata: TraceMetadata,
+    // fill in the host details of the metadata, see `AbstractTraceWriter::probe_host`
+    pub probe_host: bool,
+    // the metadata was taken from the recording this trace was cut from,
+    // see `AbstractTraceWriter::derive_from`
+    pub derived: bool,
     // internal tracer state:
     pub path_list: Vec<PathBuf>,
     pub function_list: Vec<(String, PathId, Line)>,
+    // parallel to `function_list`: the span of each function, if it has one
+    pub function_spans: Vec<Option<SourceSpan>>,
 
     pub paths: HashMap<PathBuf, PathId>,
     pub functions: HashMap<String, FunctionId>,
@@ -29,17 +43,35 @@ pub struct AbstractTraceWriterData {
 
     pub trace_metadata_path: Option<PathBuf>,
     pub trace_paths_path: Option<PathBuf>,
+
+    // capture limits, `None` for unlimited; see `capture_limits::limit_value`
+    pub max_elements: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub max_string_len: Option<usize>,
+
+    // object identity, see `object_identity`; `object_ids` is cleared at every step
+    pub object_ids: HashMap<ObjectKey, ObjectId>,
+    pub next_object_id: u64,
+    pub dedup_references: bool,
+
+    // snapshot the registered source files into the trace, see `source_snapshots`
+    pub embed_sources: bool,
 }
 
 impl AbstractTraceWriterData {
     pub fn new(program: &str, args: &[String]) -> Self {
+        let workdir = env::current_dir().expect("can access the current dir");
         AbstractTraceWriterData {
-            workdir: env::current_dir().expect("can access the current dir"),
+            metadata: TraceMetadata::new(program, args.to_vec(), workdir.clone()),
+            workdir,
             program: program.to_string(),
             args: args.to_vec(),
+            probe_host: false,
+            derived: false,
 
             path_list: vec![],
             function_list: vec![],
+            function_spans: vec![],
             paths: HashMap::new(),
             functions: HashMap::new(),
             variables: HashMap::new(),
@@ -47,6 +79,16 @@ impl AbstractTraceWriterData {
 
             trace_metadata_path: None,
             trace_paths_path: None,
+
+            max_elements: None,
+            max_depth: None,
+            max_string_len: None,
+
+            object_ids: HashMap::new(),
+            next_object_id: 0,
+            dedup_references: false,
+
+            embed_sources: false,
         }
     }
 }
@@ -67,6 +109,43 @@ pub trait AbstractTraceWriter {
         self.get_mut_data().workdir = workdir.to_path_buf();
     }
 
+    fn set_language(&mut self, language: &str) {
+        self.get_mut_data().metadata.language = Some(language.to_string());
+    }
+
+    /// Name and version of the recorder producing the trace, stored in
+    /// the metadata next to the language.
+    fn set_recorder(&mut self, name: &str, version: &str) {
+        let metadata = &mut self.get_mut_data().metadata;
+        metadata.recorder_name = Some(name.to_string());
+        metadata.recorder_version = Some(version.to_string());
+    }
+
+    /// Record the hostname, a few environment variables and the git state
+    /// of the workdir in the metadata. They are probed when the metadata
+    /// is written.
+    fn probe_host(&mut self) {
+        self.get_mut_data().probe_host = true;
+    }
+
+    /// Describe the trace as derived from the recording `source`, e.g. a
+    /// slice of it: the metadata keeps the recorded program, its host and
+    /// its times, gets a fresh `recording_id` and names `source` as the
+    /// parent recording.
+    fn derive_from(&mut self, source: &TraceMetadata) {
+        let data = self.get_mut_data();
+        data.program = source.program.clone();
+        data.args = source.args.clone();
+        data.workdir = source.workdir.clone();
+        data.metadata = TraceMetadata {
+            recording_id: data.metadata.recording_id.clone(),
+            parent_recording_id: Some(source.recording_id.clone()),
+            metadata_version: TRACE_METADATA_VERSION,
+            ..source.clone()
+        };
+        data.derived = true;
+    }
+
     fn begin_writing_trace_metadata(&mut self, path: &Path) -> Result<(), Box<dyn Error>> {
         self.get_mut_data().trace_metadata_path = Some(path.to_path_buf());
         Ok(())
@@ -110,6 +189,17 @@ pub trait AbstractTraceWriter {
         *self.get_data().functions.get(function_name).unwrap()
     }
 
+    /// Like `ensure_function_id`, for a function whose definition covers
+    /// `span`; its line is `span.start_line`.
+    fn ensure_function_id_with_span(&mut self, function_name: &str, path: &std::path::Path, span: SourceSpan) -> FunctionId {
+        if !self.get_data().functions.contains_key(function_name) {
+            let mut_data = self.get_mut_data();
+            mut_data.functions.insert(function_name.to_string(), FunctionId(mut_data.functions.len()));
+            self.register_function_with_span(function_name, path, span);
+        }
+        *self.get_data().functions.get(function_name).unwrap()
+    }
+
     fn ensure_type_id(&mut self, kind: TypeKind, lang_type: &str) -> TypeId {
         let typ = self.to_raw_type(kind, lang_type);
         self.ensure_raw_type_id(typ)
@@ -140,17 +230,48 @@ pub trait AbstractTraceWriter {
 
     fn register_function(&mut self, name: &str, path: &std::path::Path, line: Line) {
         let path_id = self.ensure_path_id(path);
-        self.get_mut_data().function_list.push((name.to_string(), path_id, line));
+        let data = self.get_mut_data();
+        data.function_list.push((name.to_string(), path_id, line));
+        data.function_spans.push(None);
         self.add_event(TraceLowLevelEvent::Function(FunctionRecord {
             name: name.to_string(),
             path_id,
             line,
+            span: None,
+        }));
+    }
+
+    /// Register a function whose definition covers `span`; its line is
+    /// `span.start_line`.
+    fn register_function_with_span(&mut self, name: &str, path: &std::path::Path, span: SourceSpan) {
+        let path_id = self.ensure_path_id(path);
+        let data = self.get_mut_data();
+        data.function_list.push((name.to_string(), path_id, span.start_line));
+        data.function_spans.push(Some(span));
+        self.add_event(TraceLowLevelEvent::Function(FunctionRecord {
+            name: name.to_string(),
+            path_id,
+            line: span.start_line,
+            span: Some(span),
         }));
     }
 
     fn register_step(&mut self, path: &std::path::Path, line: Line) {
         let path_id = self.ensure_path_id(path);
-        self.add_event(TraceLowLevelEvent::Step(StepRecord { path_id, line }));
+        self.get_mut_data().object_ids.clear();
+        self.add_event(TraceLowLevelEvent::Step(StepRecord { path_id, line, span: None }));
+    }
+
+    /// Register a step at the sub-expression `span`; its line is
+    /// `span.start_line`.
+    fn register_step_with_span(&mut self, path: &std::path::Path, span: SourceSpan) {
+        let path_id = self.ensure_path_id(path);
+        self.get_mut_data().object_ids.clear();
+        self.add_event(TraceLowLevelEvent::Step(StepRecord {
+            path_id,
+            line: span.start_line,
+            span: Some(span),
+        }));
     }
 
     fn register_call(&mut self, function_id: FunctionId, args: Vec<FullValueRecord>) {
@@ -161,11 +282,15 @@ pub trait AbstractTraceWriter {
             for arg in &args {
                 self.register_full_value(arg.variable_id, arg.value.clone());
             }
-            let function = &self.get_data().function_list[function_id.0];
-            self.add_event(TraceLowLevelEvent::Step(StepRecord {
+            let data = self.get_data();
+            let function = &data.function_list[function_id.0];
+            let step = StepRecord {
                 path_id: function.1,
                 line: function.2,
-            }));
+                span: data.function_spans.get(function_id.0).copied().flatten(),
+            };
+            self.get_mut_data().object_ids.clear();
+            self.add_event(TraceLowLevelEvent::Step(step));
         }
         // the actual call event:
         self.add_event(TraceLowLevelEvent::Call(CallRecord { function_id, args }));
@@ -173,18 +298,126 @@ pub trait AbstractTraceWriter {
 
     fn arg(&mut self, name: &str, value: ValueRecord) -> FullValueRecord {
         let variable_id = self.ensure_variable_id(name);
+        let value = self.prepare_value(value);
         FullValueRecord { variable_id, value }
     }
 
     fn register_return(&mut self, return_value: ValueRecord) {
+        let return_value = self.prepare_value(return_value);
         self.add_event(TraceLowLevelEvent::Return(ReturnRecord { return_value }));
     }
 
+    /// Record that `exception` was raised at the current step.
+    fn register_throw(&mut self, exception: ValueRecord) {
+        let exception = self.prepare_value(exception);
+        self.add_event(TraceLowLevelEvent::Throw(ThrowRecord { exception }));
+    }
+
+    /// Record that `exception` was handled in the frame now on top of the
+    /// call stack, after any `register_unwind` for the frames it left.
+    fn register_catch(&mut self, exception: ValueRecord) {
+        let exception = self.prepare_value(exception);
+        self.add_event(TraceLowLevelEvent::Catch(CatchRecord { exception }));
+    }
+
+    /// Record that `frames` (innermost first) were exited by an exception or
+    /// panic; each one takes the place of the `Return` it never reached.
+    fn register_unwind(&mut self, frames: &[FunctionId]) {
+        self.add_event(TraceLowLevelEvent::Unwind(UnwindRecord { frames: frames.to_vec() }));
+    }
+
+    /// Record the bytes the current step stored at `address`. Unlike values,
+    /// memory writes are not subject to the capture limits.
+    fn register_memory_write(&mut self, address: u64, bytes: &[u8]) {
+        self.add_event(TraceLowLevelEvent::MemoryWrite(MemoryWriteRecord {
+            address,
+            bytes: bytes.to_vec(),
+        }));
+    }
+
+    fn register_alloc(&mut self, address: u64, size: u64) {
+        self.add_event(TraceLowLevelEvent::Alloc(AllocRecord { address, size }));
+    }
+
+    fn register_free(&mut self, address: u64) {
+        self.add_event(TraceLowLevelEvent::Free(FreeRecord { address }));
+    }
+
+    /// Set the capture limits applied to variable, argument and return
+    /// values. `None` leaves that dimension unlimited.
+    fn set_capture_limits(&mut self, max_elements: Option<usize>, max_depth: Option<usize>, max_string_len: Option<usize>) {
+        let data = self.get_mut_data();
+        data.max_elements = max_elements;
+        data.max_depth = max_depth;
+        data.max_string_len = max_string_len;
+    }
+
+    /// Cut `value` down to the configured capture limits, marking what was
+    /// dropped with `ValueRecord::Elided`.
+    fn limit_value(&self, value: ValueRecord) -> ValueRecord {
+        let data = self.get_data();
+        capture_limits::limit_value(value, data.max_elements, data.max_depth, data.max_string_len)
+    }
+
+    /// Look up the id of a shared object, assigning a fresh one if it has not
+    /// been recorded since the last step.
+    ///
+    /// Returns `(id, true)` for a new object, which the caller should record
+    /// in full as `ValueRecord::Object { id, .. }`, and `(id, false)` for one
+    /// already recorded, which should become `ValueRecord::BackRef { id, .. }`.
+    fn ensure_object_id(&mut self, key: ObjectKey) -> (ObjectId, bool) {
+        let data = self.get_mut_data();
+        if let Some(id) = data.object_ids.get(&key) {
+            return (*id, false);
+        }
+        let id = ObjectId(data.next_object_id);
+        data.next_object_id += 1;
+        data.object_ids.insert(key, id);
+        (id, true)
+    }
+
+    /// Share `ValueRecord::Reference`s with the same address between all
+    /// values registered on a step, instead of recording each one in full.
+    fn set_reference_dedup(&mut self, enabled: bool) {
+        self.get_mut_data().dedup_references = enabled;
+    }
+
+    /// Store a copy of every registered source file in the trace when it is
+    /// finished, so it can be shown even after the files change. Only
+    /// container formats (CTFS) have room for the copies; other writers
+    /// ignore the setting.
+    fn set_embed_sources(&mut self, enabled: bool) {
+        self.get_mut_data().embed_sources = enabled;
+    }
+
+    /// The processing every registered variable, argument and return value
+    /// goes through: capture limits first, then reference dedup if enabled.
+    fn prepare_value(&mut self, value: ValueRecord) -> ValueRecord {
+        let value = self.limit_value(value);
+        let data = self.get_mut_data();
+        if !data.dedup_references {
+            return value;
+        }
+        object_identity::dedup_references(value, &mut data.object_ids, &mut data.next_object_id)
+    }
+
     fn register_special_event(&mut self, kind: EventLogKind, metadata: &str, content: &str) {
+        self.register_special_event_with_attributes(kind, metadata, content, vec![]);
+    }
+
+    fn register_special_event_with_attributes(&mut self, kind: EventLogKind, metadata: &str, content: &str, attributes: Vec<EventAttribute>) {
+        let attributes = attributes
+            .into_iter()
+            .map(|attribute| EventAttribute {
+                key: attribute.key,
+                value: self.prepare_value(attribute.value),
+            })
+            .collect();
         self.add_event(TraceLowLevelEvent::Event(RecordEvent {
             kind,
             metadata: metadata.to_string(),
             content: content.to_string(),
+            attributes,
         }));
     }
 
@@ -211,6 +444,7 @@ pub trait AbstractTraceWriter {
 
     fn register_variable_with_full_value(&mut self, name: &str, value: ValueRecord) {
         let variable_id = self.ensure_variable_id(name);
+        let value = self.prepare_value(value);
         self.register_full_value(variable_id, value);
     }
 
@@ -302,22 +536,93 @@ pub trait AbstractTraceWriter {
         self.add_event(TraceLowLevelEvent::ThreadSwitch(thread_id));
     }
 
+    /// A new async task exists; it does not run until `task_resume`.
+    fn task_spawn(&mut self, task_id: TaskId) {
+        self.add_event(TraceLowLevelEvent::TaskSpawn(task_id));
+    }
+
+    /// The running task yields (e.g. at an `await`); its open calls stay on
+    /// its own stack until it is resumed.
+    fn task_suspend(&mut self, task_id: TaskId) {
+        self.add_event(TraceLowLevelEvent::TaskSuspend(task_id));
+    }
+
+    /// The task runs on the current thread again; calls, returns and steps
+    /// from here on belong to it.
+    fn task_resume(&mut self, task_id: TaskId) {
+        self.add_event(TraceLowLevelEvent::TaskResume(task_id));
+    }
+
+    fn task_complete(&mut self, task_id: TaskId) {
+        self.add_event(TraceLowLevelEvent::TaskComplete(task_id));
+    }
+
+    /// The `recording_id` this trace's metadata will carry. It is minted with
+    /// the writer, so a recorder can hand it to a child process (which passes
+    /// it to `process_start`) before the parent's trace is finished.
+    fn recording_id(&mut self) -> String {
+        self.get_data().metadata.recording_id.clone()
+    }
+
+    fn process_start(&mut self, pid: u32, parent_pid: u32, parent_recording_id: Option<String>) {
+        self.get_mut_data().metadata.parent_recording_id = parent_recording_id.clone();
+        self.add_event(TraceLowLevelEvent::ProcessStart(ProcessStartRecord {
+            pid,
+            parent_pid,
+            parent_recording_id,
+        }));
+    }
+
+    fn process_exec(&mut self, program: &str, args: &[String]) {
+        self.add_event(TraceLowLevelEvent::Exec(ExecRecord {
+            program: program.to_string(),
+            args: args.to_vec(),
+        }));
+    }
+
+    fn process_signal(&mut self, signal: i32, name: &str) {
+        self.add_event(TraceLowLevelEvent::Signal(SignalRecord {
+            signal,
+            name: name.to_string(),
+        }));
+    }
+
+    /// Records the exit and keeps `code` as the trace's final exit status.
+    fn process_exit(&mut self, code: i32) {
+        self.get_mut_data().metadata.exit_code = Some(code);
+        self.add_event(TraceLowLevelEvent::Exit(ExitRecord { code }));
+    }
+
+    /// The metadata of the trace as recorded so far; the recording is taken
+    /// to end now.
+    fn trace_metadata(&mut self) -> TraceMetadata {
+        let data = self.get_data();
+        let mut metadata = TraceMetadata {
+            program: data.program.clone(),
+            args: data.args.clone(),
+            workdir: data.workdir.clone(),
+            ..data.metadata.clone()
+        };
+        if !data.derived {
+            metadata.end_time = host_info::unix_time_ms();
+        }
+        if data.probe_host {
+            metadata.probe_host();
+        }
+        metadata
+    }
+
     fn drop_last_step(&mut self) {
         self.add_event(TraceLowLevelEvent::DropLastStep);
     }
 
     fn finish_writing_trace_metadata(&mut self) -> Result<(), Box<dyn Error>> {
-        if let Some(path) = &self.get_data().trace_metadata_path {
-            // M-REC-1: mint the canonical UUIDv7 recording_id at the
-            // point metadata is finalised.  Recorders that need to
-            // pin a specific id (e.g. the import path) should call a
-            // dedicated entry point rather than this default trait
-            // method.
-            let trace_metadata = TraceMetadata::new(
-                self.get_data().program.clone(),
-                self.get_data().args.clone(),
-                self.get_data().workdir.clone(),
-            );
+        if let Some(path) = self.get_data().trace_metadata_path.clone() {
+            // M-REC-1: the canonical UUIDv7 recording_id was minted with
+            // the writer, see `recording_id()`.  Recorders that need to pin
+            // a specific id (e.g. the import path) should call a dedicated
+            // entry point rather than this default trait method.
+            let trace_metadata = self.trace_metadata();
             let json = serde_json::to_string(&trace_metadata)?;
             fs::write(path, json)?;
             Ok(())
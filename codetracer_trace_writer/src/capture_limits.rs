@@ -0,0 +1,237 @@
+//! Capture limits: cut large or deeply nested values down before encoding.
+//!
+//! The limits live in [`AbstractTraceWriterData`](crate::abstract_trace_writer::AbstractTraceWriterData)
+//! and are applied by `register_variable_with_full_value`, `arg` and
+//! `register_return`. Whatever gets cut is replaced by a
+//! `ValueRecord::Elided` marker that keeps the recorded prefix and the
+//! original length, so a reader can still tell "10,000 elements, first 100
+//! shown" apart from a 100-element collection.
+
+use codetracer_trace_types::{TypeId, ValueRecord};
+
+/// Apply the capture limits to `value`. `None` means "unlimited".
+///
+/// - `max_elements` caps the number of elements kept in a `Sequence`, the
+///   number of entries in a `Map` and the number of bytes in `Bytes`.
+/// - `max_depth` caps compound nesting: the top-level value is at depth 0 and
+///   any compound value at depth `>= max_depth` is dropped entirely.
+/// - `max_string_len` caps the number of characters kept in a `String`.
+///
+/// Struct fields and tuple items are never dropped by `max_elements`, since
+/// their position carries meaning; they are still subject to `max_depth`.
+pub fn limit_value(value: ValueRecord, max_elements: Option<usize>, max_depth: Option<usize>, max_string_len: Option<usize>) -> ValueRecord {
+    if max_elements.is_none() && max_depth.is_none() && max_string_len.is_none() {
+        return value;
+    }
+    let limits = Limits {
+        max_elements,
+        max_depth,
+        max_string_len,
+    };
+    limits.apply(value, 0)
+}
+
+struct Limits {
+    max_elements: Option<usize>,
+    max_depth: Option<usize>,
+    max_string_len: Option<usize>,
+}
+
+impl Limits {
+    fn apply(&self, value: ValueRecord, depth: usize) -> ValueRecord {
+        if let Some((type_id, child_count)) = compound_shape(&value)
+            && self.max_depth.is_some_and(|max_depth| depth >= max_depth)
+        {
+            return ValueRecord::Elided {
+                partial: Box::new(ValueRecord::None { type_id }),
+                original_len: child_count as u64,
+                depth_cutoff: true,
+            };
+        }
+
+        match value {
+            ValueRecord::String { text, type_id } => match self.max_string_len {
+                Some(max_len) if text.chars().count() > max_len => ValueRecord::Elided {
+                    original_len: text.chars().count() as u64,
+                    partial: Box::new(ValueRecord::String {
+                        text: text.chars().take(max_len).collect(),
+                        type_id,
+                    }),
+                    depth_cutoff: false,
+                },
+                _ => ValueRecord::String { text, type_id },
+            },
+            ValueRecord::Bytes { mut data, type_id } => match self.max_elements {
+                Some(max_len) if data.len() > max_len => {
+                    let original_len = data.len() as u64;
+                    data.truncate(max_len);
+                    ValueRecord::Elided {
+                        partial: Box::new(ValueRecord::Bytes { data, type_id }),
+                        original_len,
+                        depth_cutoff: false,
+                    }
+                }
+                _ => ValueRecord::Bytes { data, type_id },
+            },
+            ValueRecord::Sequence {
+                mut elements,
+                is_slice,
+                type_id,
+            } => {
+                let original_len = elements.len();
+                if let Some(max_len) = self.max_elements {
+                    elements.truncate(max_len);
+                }
+                let partial = ValueRecord::Sequence {
+                    elements: self.apply_all(elements, depth),
+                    is_slice,
+                    type_id,
+                };
+                self.mark_truncated(partial, original_len)
+            }
+            ValueRecord::Map { mut entries, type_id } => {
+                let original_len = entries.len();
+                if let Some(max_len) = self.max_elements {
+                    entries.truncate(max_len);
+                }
+                let partial = ValueRecord::Map {
+                    entries: entries
+                        .into_iter()
+                        .map(|(key, value)| (self.apply(key, depth + 1), self.apply(value, depth + 1)))
+                        .collect(),
+                    type_id,
+                };
+                self.mark_truncated(partial, original_len)
+            }
+            ValueRecord::Tuple { elements, type_id } => ValueRecord::Tuple {
+                elements: self.apply_all(elements, depth),
+                type_id,
+            },
+            ValueRecord::Struct { field_values, type_id } => ValueRecord::Struct {
+                field_values: self.apply_all(field_values, depth),
+                type_id,
+            },
+            ValueRecord::Variant {
+                discriminator,
+                contents,
+                type_id,
+            } => ValueRecord::Variant {
+                discriminator,
+                contents: Box::new(self.apply(*contents, depth + 1)),
+                type_id,
+            },
+            ValueRecord::Reference {
+                dereferenced,
+                address,
+                mutable,
+                type_id,
+            } => ValueRecord::Reference {
+                dereferenced: Box::new(self.apply(*dereferenced, depth + 1)),
+                address,
+                mutable,
+                type_id,
+            },
+            other => other,
+        }
+    }
+
+    fn apply_all(&self, values: Vec<ValueRecord>, depth: usize) -> Vec<ValueRecord> {
+        values.into_iter().map(|v| self.apply(v, depth + 1)).collect()
+    }
+
+    fn mark_truncated(&self, partial: ValueRecord, original_len: usize) -> ValueRecord {
+        if compound_shape(&partial).is_some_and(|(_, kept)| kept < original_len) {
+            ValueRecord::Elided {
+                partial: Box::new(partial),
+                original_len: original_len as u64,
+                depth_cutoff: false,
+            }
+        } else {
+            partial
+        }
+    }
+}
+
+/// Type id and child count of the compound value kinds that `max_depth`
+/// applies to; `None` for scalars.
+fn compound_shape(value: &ValueRecord) -> Option<(TypeId, usize)> {
+    match value {
+        ValueRecord::Sequence { elements, type_id, .. } | ValueRecord::Tuple { elements, type_id } => Some((*type_id, elements.len())),
+        ValueRecord::Struct { field_values, type_id } => Some((*type_id, field_values.len())),
+        ValueRecord::Map { entries, type_id } => Some((*type_id, entries.len())),
+        ValueRecord::Variant { type_id, .. } | ValueRecord::Reference { type_id, .. } => Some((*type_id, 1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(i: i64) -> ValueRecord {
+        ValueRecord::Int { i, type_id: TypeId(1) }
+    }
+
+    fn seq(elements: Vec<ValueRecord>) -> ValueRecord {
+        ValueRecord::Sequence {
+            elements,
+            is_slice: false,
+            type_id: TypeId(2),
+        }
+    }
+
+    #[test]
+    fn test_unlimited_is_identity() {
+        let value = seq((0..10).map(int).collect());
+        assert_eq!(limit_value(value.clone(), None, None, None), value);
+    }
+
+    #[test]
+    fn test_max_elements_keeps_prefix() {
+        let value = seq((0..10_000).map(int).collect());
+        match limit_value(value, Some(100), None, None) {
+            ValueRecord::Elided {
+                partial,
+                original_len,
+                depth_cutoff,
+            } => {
+                assert_eq!(original_len, 10_000);
+                assert!(!depth_cutoff);
+                assert_eq!(*partial, seq((0..100).map(int).collect()));
+            }
+            other => panic!("expected Elided, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_max_depth_drops_nested_compounds() {
+        let value = seq(vec![int(1), seq(vec![int(2), int(3)])]);
+        let limited = limit_value(value, None, Some(1), None);
+        assert_eq!(
+            limited,
+            seq(vec![
+                int(1),
+                ValueRecord::Elided {
+                    partial: Box::new(ValueRecord::None { type_id: TypeId(2) }),
+                    original_len: 2,
+                    depth_cutoff: true,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_max_string_len_counts_chars() {
+        let value = ValueRecord::String {
+            text: "héllo wörld".to_string(),
+            type_id: TypeId(3),
+        };
+        match limit_value(value, None, None, Some(5)) {
+            ValueRecord::Elided { partial, original_len, .. } => {
+                assert_eq!(original_len, 11);
+                assert!(matches!(*partial, ValueRecord::String { ref text, .. } if text == "héllo"));
+            }
+            other => panic!("expected Elided, got {other:?}"),
+        }
+    }
+}
@@ -8,6 +8,7 @@ use zeekstd::{EncodeOptions, Encoder, FrameSizePolicy};
 
 use crate::{
     abstract_trace_writer::{AbstractTraceWriter, AbstractTraceWriterData},
+    source_snapshots::{SOURCES_DATA_FILE, SOURCES_INDEX_FILE, SourceSnapshots},
     trace_writer::TraceWriter,
 };
 use codetracer_trace_types::TraceLowLevelEvent;
@@ -62,6 +63,8 @@ impl Write for SharedBuffer {
 /// - `events.fmt` — format marker ("cbor" or "split-binary")
 /// - `meta.json`  — trace metadata (program, args, workdir)
 /// - `paths.json` — registered source paths
+/// - `sources.json`, `sources.dat` — source snapshots, only with
+///   `set_embed_sources(true)` (see `source_snapshots`)
 ///
 /// In `SplitBinary` mode (the default), events are serialized using the compact
 /// split binary encoding and accumulated into chunks of `chunk_size` events.
@@ -354,6 +357,13 @@ impl TraceWriter for CtfsTraceWriter {
             }
         }
 
+        // M-REC-1: mint a UUIDv7 recording_id for this trace, unless
+        // one was already handed out via `recording_id()`.
+        // Recorders that need to pin a pre-existing id (the
+        // import flow, M-REC-7) should construct TraceMetadata
+        // directly with their own id and then write it out.
+        let trace_metadata = self.trace_metadata();
+
         if let Some(ref mut writer) = self.ctfs_writer {
             // Write the format marker file.
             let format_name = match self.serialization_format {
@@ -364,12 +374,6 @@ impl TraceWriter for CtfsTraceWriter {
             writer.write(format_handle, format_name)?;
 
             // Write metadata as meta.json.
-            // M-REC-1: mint a UUIDv7 recording_id for this trace.
-            // Recorders that need to pin a pre-existing id (the
-            // import flow, M-REC-7) should construct TraceMetadata
-            // directly with their own id and then write it out.
-            let trace_metadata =
-                codetracer_trace_types::TraceMetadata::new(self.base.program.clone(), self.base.args.clone(), self.base.workdir.clone());
             let meta_json = serde_json::to_string(&trace_metadata)?;
             let meta_handle = writer.add_file("meta.json")?;
             writer.write(meta_handle, meta_json.as_bytes())?;
@@ -378,6 +382,14 @@ impl TraceWriter for CtfsTraceWriter {
             let paths_json = serde_json::to_string(&self.base.path_list)?;
             let paths_handle = writer.add_file("paths.json")?;
             writer.write(paths_handle, paths_json.as_bytes())?;
+
+            if self.base.embed_sources {
+                let snapshots = SourceSnapshots::collect(&self.base.path_list, &self.base.workdir);
+                let index_handle = writer.add_file(SOURCES_INDEX_FILE)?;
+                writer.write(index_handle, serde_json::to_string(&snapshots.index)?.as_bytes())?;
+                let data_handle = writer.add_file(SOURCES_DATA_FILE)?;
+                writer.write(data_handle, &snapshots.data)?;
+            }
         }
 
         // Close the CTFS container (takes ownership)
@@ -399,6 +411,7 @@ mod tests {
         TraceLowLevelEvent::Step(StepRecord {
             path_id: PathId(0),
             line: Line(line),
+            span: None,
         })
     }
 
@@ -1,5 +1,8 @@
 pub mod abstract_trace_writer;
+pub mod capture_limits;
 pub mod non_streaming_trace_writer;
+pub mod object_identity;
+pub mod source_snapshots;
 pub mod trace_writer;
 
 #[cfg(target_arch = "wasm32")]
@@ -85,7 +88,7 @@ mod tests {
         // -4, -3 should be variables
         let should_be_step = &tracer.events[tracer.events.len() - 2];
         let should_be_call = &tracer.events[tracer.events.len() - 1];
-        if let TraceLowLevelEvent::Step(StepRecord { path_id, line }) = should_be_step {
+        if let TraceLowLevelEvent::Step(StepRecord { path_id, line, .. }) = should_be_step {
             assert_eq!(*path_id, function_path_id);
             assert_eq!(*line, function_line);
         } else {
@@ -177,4 +180,145 @@ mod tests {
         // tracer.store_trace_paths(&PathBuf::from("trace_paths.json")).unwrap();
         // tracer.store_trace_events(&PathBuf::from("trace.json")).unwrap();
     }
+
+    #[test]
+    fn test_capture_limits_applied_on_register() {
+        let mut tracer = NonStreamingTraceWriter::new("limits", &[]);
+        let path = Path::new("/test/limits.py");
+        tracer.start(path, Line(1));
+        tracer.set_capture_limits(Some(2), None, Some(3));
+
+        let int_type = tracer.ensure_type_id(TypeKind::Int, "int");
+        let list_type = tracer.ensure_type_id(TypeKind::Seq, "list");
+        let elements = (0..5).map(|i| ValueRecord::Int { i, type_id: int_type }).collect();
+        tracer.register_variable_with_full_value(
+            "xs",
+            ValueRecord::Sequence {
+                elements,
+                is_slice: false,
+                type_id: list_type,
+            },
+        );
+        let string_type = tracer.ensure_type_id(TypeKind::String, "str");
+        tracer.register_return(ValueRecord::String {
+            text: "abcdef".to_string(),
+            type_id: string_type,
+        });
+
+        let value = tracer.events.iter().find_map(|e| match e {
+            TraceLowLevelEvent::Value(v) => Some(v.value.clone()),
+            _ => None,
+        });
+        assert!(matches!(
+            value,
+            Some(ValueRecord::Elided {
+                original_len: 5,
+                depth_cutoff: false,
+                ..
+            })
+        ));
+        let return_value = tracer.events.iter().find_map(|e| match e {
+            TraceLowLevelEvent::Return(r) => Some(r.return_value.clone()),
+            _ => None,
+        });
+        assert!(matches!(return_value, Some(ValueRecord::Elided { original_len: 6, .. })));
+    }
+
+    #[test]
+    fn test_call_step_uses_function_span() {
+        let mut tracer = NonStreamingTraceWriter::new("spans", &[]);
+        let path = Path::new("/test/spans.nr");
+        tracer.start(path, Line(1));
+
+        let span = SourceSpan {
+            start_line: Line(4),
+            start_column: 3,
+            end_line: Line(4),
+            end_column: 20,
+        };
+        let function_id = tracer.ensure_function_id_with_span("check", path, span);
+        tracer.register_call(function_id, vec![]);
+
+        let steps: Vec<StepRecord> = tracer
+            .events
+            .iter()
+            .filter_map(|e| match e {
+                TraceLowLevelEvent::Step(step) => Some(*step),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].line, Line(4));
+        assert_eq!(steps[0].span, Some(span));
+    }
+
+    #[test]
+    fn test_child_metadata_links_parent_recording() {
+        use crate::abstract_trace_writer::AbstractTraceWriter;
+
+        let dir = tempfile::tempdir().unwrap();
+        let parent_meta = dir.path().join("parent_metadata.json");
+        let child_meta = dir.path().join("child_metadata.json");
+
+        let mut parent = NonStreamingTraceWriter::new("parent", &[]);
+        TraceWriter::begin_writing_trace_metadata(&mut parent, &parent_meta).unwrap();
+        let parent_id = parent.recording_id();
+
+        let mut child = NonStreamingTraceWriter::new("child", &[]);
+        TraceWriter::begin_writing_trace_metadata(&mut child, &child_meta).unwrap();
+        TraceWriter::set_language(&mut child, "ruby");
+        TraceWriter::set_recorder(&mut child, "codetracer-ruby-recorder", "1.2.3");
+        TraceWriter::probe_host(&mut child);
+        child.process_start(11, 10, Some(parent_id.clone()));
+        child.process_signal(15, "SIGTERM");
+        child.process_exit(143);
+        assert!(matches!(child.events.last(), Some(TraceLowLevelEvent::Exit(ExitRecord { code: 143 }))));
+
+        TraceWriter::finish_writing_trace_metadata(&mut parent).unwrap();
+        TraceWriter::finish_writing_trace_metadata(&mut child).unwrap();
+
+        let parent_meta: TraceMetadata = serde_json::from_slice(&std::fs::read(parent_meta).unwrap()).unwrap();
+        let child_meta: TraceMetadata = serde_json::from_slice(&std::fs::read(child_meta).unwrap()).unwrap();
+        assert_eq!(parent_meta.recording_id, parent_id);
+        assert_eq!(parent_meta.exit_code, None);
+        assert_eq!(child_meta.parent_recording_id, Some(parent_id));
+        assert_ne!(child_meta.recording_id, parent_meta.recording_id);
+        assert_eq!(child_meta.exit_code, Some(143));
+        assert_eq!(child_meta.language.as_deref(), Some("ruby"));
+        assert_eq!(child_meta.recorder_name.as_deref(), Some("codetracer-ruby-recorder"));
+        assert_eq!(child_meta.recorder_version.as_deref(), Some("1.2.3"));
+        assert_eq!(child_meta.metadata_version, TRACE_METADATA_VERSION);
+        assert!(child_meta.start_time.unwrap() <= child_meta.end_time.unwrap());
+        assert!(parent_meta.language.is_none());
+        // only recorders asking for host details get them
+        assert!(parent_meta.hostname.is_none() && parent_meta.git.is_none() && parent_meta.env.is_empty());
+        assert!(parent_meta.start_time.unwrap() <= parent_meta.end_time.unwrap());
+    }
+
+    #[test]
+    fn test_derived_metadata_keeps_the_source_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        let meta_path = dir.path().join("trace_metadata.json");
+        let mut source = TraceMetadata::new("app.py", vec!["--fast".to_string()], "/work".into());
+        source.language = Some("python".to_string());
+        source.start_time = Some(1_000);
+        source.end_time = Some(2_000);
+        source.hostname = Some("builder".to_string());
+        source.exit_code = Some(0);
+
+        let mut writer = NonStreamingTraceWriter::new("", &[]);
+        TraceWriter::begin_writing_trace_metadata(&mut writer, &meta_path).unwrap();
+        TraceWriter::derive_from(&mut writer, &source);
+        TraceWriter::finish_writing_trace_metadata(&mut writer).unwrap();
+
+        let derived: TraceMetadata = serde_json::from_slice(&std::fs::read(meta_path).unwrap()).unwrap();
+        assert_ne!(derived.recording_id, source.recording_id);
+        assert_eq!(derived.parent_recording_id, Some(source.recording_id));
+        assert_eq!((derived.program.as_str(), derived.args), ("app.py", vec!["--fast".to_string()]));
+        assert_eq!(derived.workdir, Path::new("/work"));
+        assert_eq!(derived.language.as_deref(), Some("python"));
+        assert_eq!((derived.start_time, derived.end_time), (Some(1_000), Some(2_000)));
+        assert_eq!(derived.hostname.as_deref(), Some("builder"));
+        assert_eq!(derived.exit_code, Some(0));
+    }
 }
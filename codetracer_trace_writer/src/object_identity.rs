@@ -0,0 +1,164 @@
+//! Writer-side object identity: record a shared heap object once and refer
+//! back to it afterwards.
+//!
+//! Recorders that walk an object graph themselves call
+//! `ensure_object_id` with the object's address (or `Place`) before
+//! descending into it: a fresh id means "emit `ValueRecord::Object`", a known
+//! id means "emit `ValueRecord::BackRef`". Because the id is assigned before
+//! the children are visited, cycles come out as back-references to an
+//! enclosing `Object`.
+//!
+//! Recorders that already produce plain `ValueRecord` trees can instead turn
+//! on reference dedup, which runs [`dedup_references`] over every registered
+//! value and keys `ValueRecord::Reference`s by their address.
+//!
+//! The identity table is cleared at every `Step`, so readers only need the
+//! events since the last step to resolve a back-reference.
+
+use std::collections::HashMap;
+
+use codetracer_trace_types::{ObjectId, Place, ValueRecord};
+
+/// What makes two recorded values "the same object".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectKey {
+    Address(u64),
+    Place(Place),
+}
+
+/// Replace every repeated `Reference` (same non-zero address) with a
+/// `BackRef`, wrapping the first occurrence in an `Object`.
+pub fn dedup_references(value: ValueRecord, object_ids: &mut HashMap<ObjectKey, ObjectId>, next_object_id: &mut u64) -> ValueRecord {
+    match value {
+        ValueRecord::Reference {
+            dereferenced,
+            address,
+            mutable,
+            type_id,
+        } if address != 0 => {
+            let key = ObjectKey::Address(address);
+            if let Some(id) = object_ids.get(&key) {
+                return ValueRecord::BackRef { id: *id, type_id };
+            }
+            let id = ObjectId(*next_object_id);
+            *next_object_id += 1;
+            object_ids.insert(key, id);
+            ValueRecord::Object {
+                id,
+                value: Box::new(ValueRecord::Reference {
+                    dereferenced: Box::new(dedup_references(*dereferenced, object_ids, next_object_id)),
+                    address,
+                    mutable,
+                    type_id,
+                }),
+            }
+        }
+        ValueRecord::Reference {
+            dereferenced,
+            address,
+            mutable,
+            type_id,
+        } => ValueRecord::Reference {
+            dereferenced: Box::new(dedup_references(*dereferenced, object_ids, next_object_id)),
+            address,
+            mutable,
+            type_id,
+        },
+        ValueRecord::Sequence { elements, is_slice, type_id } => ValueRecord::Sequence {
+            elements: dedup_all(elements, object_ids, next_object_id),
+            is_slice,
+            type_id,
+        },
+        ValueRecord::Tuple { elements, type_id } => ValueRecord::Tuple {
+            elements: dedup_all(elements, object_ids, next_object_id),
+            type_id,
+        },
+        ValueRecord::Struct { field_values, type_id } => ValueRecord::Struct {
+            field_values: dedup_all(field_values, object_ids, next_object_id),
+            type_id,
+        },
+        ValueRecord::Map { entries, type_id } => ValueRecord::Map {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = dedup_references(key, object_ids, next_object_id);
+                    (key, dedup_references(value, object_ids, next_object_id))
+                })
+                .collect(),
+            type_id,
+        },
+        ValueRecord::Variant {
+            discriminator,
+            contents,
+            type_id,
+        } => ValueRecord::Variant {
+            discriminator,
+            contents: Box::new(dedup_references(*contents, object_ids, next_object_id)),
+            type_id,
+        },
+        ValueRecord::Elided {
+            partial,
+            original_len,
+            depth_cutoff,
+        } => ValueRecord::Elided {
+            partial: Box::new(dedup_references(*partial, object_ids, next_object_id)),
+            original_len,
+            depth_cutoff,
+        },
+        ValueRecord::Object { id, value } => ValueRecord::Object {
+            id,
+            value: Box::new(dedup_references(*value, object_ids, next_object_id)),
+        },
+        other => other,
+    }
+}
+
+fn dedup_all(values: Vec<ValueRecord>, object_ids: &mut HashMap<ObjectKey, ObjectId>, next_object_id: &mut u64) -> Vec<ValueRecord> {
+    values.into_iter().map(|v| dedup_references(v, object_ids, next_object_id)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codetracer_trace_types::TypeId;
+
+    fn reference(address: u64, i: i64) -> ValueRecord {
+        ValueRecord::Reference {
+            dereferenced: Box::new(ValueRecord::Int { i, type_id: TypeId(1) }),
+            address,
+            mutable: false,
+            type_id: TypeId(2),
+        }
+    }
+
+    #[test]
+    fn test_repeated_address_becomes_back_ref() {
+        let mut ids = HashMap::new();
+        let mut next = 0;
+        let value = ValueRecord::Tuple {
+            elements: vec![reference(0x10, 1), reference(0x10, 1), reference(0x20, 2)],
+            type_id: TypeId(3),
+        };
+        let ValueRecord::Tuple { elements, .. } = dedup_references(value, &mut ids, &mut next) else {
+            panic!("expected a tuple");
+        };
+        assert!(matches!(elements[0], ValueRecord::Object { id: ObjectId(0), .. }));
+        assert_eq!(
+            elements[1],
+            ValueRecord::BackRef {
+                id: ObjectId(0),
+                type_id: TypeId(2)
+            }
+        );
+        assert!(matches!(elements[2], ValueRecord::Object { id: ObjectId(1), .. }));
+        assert_eq!(next, 2);
+    }
+
+    #[test]
+    fn test_null_references_are_not_shared() {
+        let mut ids = HashMap::new();
+        let mut next = 0;
+        assert_eq!(dedup_references(reference(0, 1), &mut ids, &mut next), reference(0, 1));
+        assert!(ids.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+//! Source file snapshots embedded in a trace container.
+//!
+//! When enabled with `AbstractTraceWriter::set_embed_sources`, the CTFS
+//! writer reads every registered path at finish time and stores:
+//! - `sources.dat`  — the distinct file contents, concatenated
+//! - `sources.json` — a [`SourceSnapshotIndex`]: the sha256 of each path,
+//!   indexed by `PathId`, and where each distinct content lives in
+//!   `sources.dat`
+//!
+//! Identical files are stored once. A path that could not be read at finish
+//! time has no hash; readers then have to fall back to the working copy.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use codetracer_trace_types::PathId;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const SOURCES_INDEX_FILE: &str = "sources.json";
+pub const SOURCES_DATA_FILE: &str = "sources.dat";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SourceSnapshotIndex {
+    /// Hex sha256 of the content of each path, by `PathId`.
+    pub path_hashes: Vec<Option<String>>,
+    pub blobs: Vec<SourceBlob>,
+}
+
+/// One distinct file content, at `offset..offset + len` of `sources.dat`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceBlob {
+    pub sha256: String,
+    pub offset: u64,
+    pub len: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SourceSnapshots {
+    pub index: SourceSnapshotIndex,
+    pub data: Vec<u8>,
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+impl SourceSnapshots {
+    /// Snapshot `paths` (in `PathId` order); relative paths are read from
+    /// `workdir`.
+    pub fn collect(paths: &[PathBuf], workdir: &Path) -> Self {
+        let mut snapshots = SourceSnapshots::default();
+        let mut stored: HashSet<String> = HashSet::new();
+        for path in paths {
+            let Ok(content) = std::fs::read(workdir.join(path)) else {
+                snapshots.index.path_hashes.push(None);
+                continue;
+            };
+            let sha256 = sha256_hex(&content);
+            if stored.insert(sha256.clone()) {
+                snapshots.index.blobs.push(SourceBlob {
+                    sha256: sha256.clone(),
+                    offset: snapshots.data.len() as u64,
+                    len: content.len() as u64,
+                });
+                snapshots.data.extend_from_slice(&content);
+            }
+            snapshots.index.path_hashes.push(Some(sha256));
+        }
+        snapshots
+    }
+
+    pub fn hash(&self, path_id: PathId) -> Option<&str> {
+        self.index.path_hashes.get(path_id.0)?.as_deref()
+    }
+
+    /// The content of the path at recording time.
+    pub fn content(&self, path_id: PathId) -> Option<&[u8]> {
+        let sha256 = self.hash(path_id)?;
+        let blob = self.index.blobs.iter().find(|blob| blob.sha256 == sha256)?;
+        self.data.get(blob.offset as usize..(blob.offset + blob.len) as usize)
+    }
+
+    /// Whether `current` (e.g. the working copy of the file) differs from
+    /// what was recorded; `None` when the path has no snapshot.
+    pub fn is_stale(&self, path_id: PathId, current: &[u8]) -> Option<bool> {
+        Some(self.hash(path_id)? != sha256_hex(current))
+    }
+}
@@ -1,10 +1,15 @@
 //! Split binary encoding: compact binary for event envelopes, CBOR only for dynamic payloads.
 //!
 //! Each event is encoded as:
-//! - 1-byte tag (0..23 for each `TraceLowLevelEvent` variant)
+//! - 1-byte tag (0..26 and 29..36 for each `TraceLowLevelEvent` variant; 27 and 28 are
+//!   `Step` and `Function` with a source span, so span-less traces keep the
+//!   original 17-byte steps, and 37 is an `Event` with attributes; 38..40 are
+//!   the memory events, whose written bytes are stored raw)
 //! - Fixed fields in little-endian (u64, i64, u32)
 //! - Strings: 4-byte LE length + UTF-8 bytes
 //! - Dynamic payloads (ValueRecord, TypeRecord, etc.): 4-byte LE CBOR length + CBOR bytes
+//!   (`ValueRecord::Bytes` data is a CBOR byte string inside the payload, not base64,
+//!   and `Int128`/`UInt128` are 16 little-endian bytes rather than decimal strings)
 
 use codetracer_trace_types::*;
 use std::io::{self, Cursor, Read};
@@ -33,6 +38,18 @@ fn write_cbor<T: serde::Serialize>(out: &mut Vec<u8>, value: &T) {
     out.extend_from_slice(&cbor);
 }
 
+// span: start and end line (i64), then the start and end columns (u32),
+// 24 bytes in all. The lines are stored whole rather than relative to the
+// event's line, so every span the writer is given decodes unchanged.
+const SPAN_SIZE: usize = 24;
+
+fn write_span(out: &mut Vec<u8>, span: &SourceSpan) {
+    write_i64(out, span.start_line.0);
+    write_i64(out, span.end_line.0);
+    write_u32(out, span.start_column);
+    write_u32(out, span.end_column);
+}
+
 fn read_u8(cursor: &mut Cursor<&[u8]>) -> io::Result<u8> {
     let mut buf = [0u8; 1];
     cursor.read_exact(&mut buf)?;
@@ -59,6 +76,16 @@ fn read_str(cursor: &mut Cursor<&[u8]>) -> io::Result<String> {
     cursor.read_exact(&mut buf)?;
     String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
+fn read_span(cursor: &mut Cursor<&[u8]>) -> io::Result<SourceSpan> {
+    let start_line = Line(read_i64(cursor)?);
+    let end_line = Line(read_i64(cursor)?);
+    Ok(SourceSpan {
+        start_line,
+        start_column: read_u32(cursor)?,
+        end_line,
+        end_column: read_u32(cursor)?,
+    })
+}
 fn read_cbor<T: serde::de::DeserializeOwned>(cursor: &mut Cursor<&[u8]>) -> io::Result<T> {
     let len = read_u32(cursor)? as usize;
     let mut buf = vec![0u8; len];
@@ -70,9 +97,12 @@ fn read_cbor<T: serde::de::DeserializeOwned>(cursor: &mut Cursor<&[u8]>) -> io::
 pub fn encode_event(event: &TraceLowLevelEvent, out: &mut Vec<u8>) -> io::Result<()> {
     match event {
         TraceLowLevelEvent::Step(s) => {
-            write_u8(out, 0);
+            write_u8(out, if s.span.is_some() { 27 } else { 0 });
             write_u64(out, s.path_id.0 as u64);
             write_i64(out, s.line.0);
+            if let Some(span) = &s.span {
+                write_span(out, span);
+            }
         }
         TraceLowLevelEvent::Path(p) => {
             write_u8(out, 1);
@@ -97,9 +127,12 @@ pub fn encode_event(event: &TraceLowLevelEvent, out: &mut Vec<u8>) -> io::Result
             write_cbor(out, &fvr.value);
         }
         TraceLowLevelEvent::Function(f) => {
-            write_u8(out, 6);
+            write_u8(out, if f.span.is_some() { 28 } else { 6 });
             write_u64(out, f.path_id.0 as u64);
             write_i64(out, f.line.0);
+            if let Some(span) = &f.span {
+                write_span(out, span);
+            }
             write_str(out, &f.name);
         }
         TraceLowLevelEvent::Call(c) => {
@@ -112,10 +145,17 @@ pub fn encode_event(event: &TraceLowLevelEvent, out: &mut Vec<u8>) -> io::Result
             write_cbor(out, &r.return_value);
         }
         TraceLowLevelEvent::Event(e) => {
-            write_u8(out, 9);
+            write_u8(out, if e.attributes.is_empty() { 9 } else { 37 });
             write_u8(out, e.kind as u8);
             write_str(out, &e.metadata);
             write_str(out, &e.content);
+            if !e.attributes.is_empty() {
+                write_u32(out, e.attributes.len() as u32);
+                for attribute in &e.attributes {
+                    write_str(out, &attribute.key);
+                    write_cbor(out, &attribute.value);
+                }
+            }
         }
         TraceLowLevelEvent::Asm(lines) => {
             write_u8(out, 10);
@@ -185,6 +225,69 @@ pub fn encode_event(event: &TraceLowLevelEvent, out: &mut Vec<u8>) -> io::Result
         TraceLowLevelEvent::DropLastStep => {
             write_u8(out, 23);
         }
+        TraceLowLevelEvent::TaskSpawn(id) => {
+            write_u8(out, 29);
+            write_u64(out, id.0);
+        }
+        TraceLowLevelEvent::TaskSuspend(id) => {
+            write_u8(out, 30);
+            write_u64(out, id.0);
+        }
+        TraceLowLevelEvent::TaskResume(id) => {
+            write_u8(out, 31);
+            write_u64(out, id.0);
+        }
+        TraceLowLevelEvent::TaskComplete(id) => {
+            write_u8(out, 32);
+            write_u64(out, id.0);
+        }
+        TraceLowLevelEvent::Throw(t) => {
+            write_u8(out, 24);
+            write_cbor(out, &t.exception);
+        }
+        TraceLowLevelEvent::Catch(c) => {
+            write_u8(out, 25);
+            write_cbor(out, &c.exception);
+        }
+        TraceLowLevelEvent::Unwind(u) => {
+            write_u8(out, 26);
+            write_u32(out, u.frames.len() as u32);
+            for id in &u.frames {
+                write_u64(out, id.0 as u64);
+            }
+        }
+        TraceLowLevelEvent::ProcessStart(p) => {
+            write_u8(out, 33);
+            write_cbor(out, p);
+        }
+        TraceLowLevelEvent::Exec(e) => {
+            write_u8(out, 34);
+            write_cbor(out, e);
+        }
+        TraceLowLevelEvent::Signal(s) => {
+            write_u8(out, 35);
+            write_u32(out, s.signal as u32);
+            write_str(out, &s.name);
+        }
+        TraceLowLevelEvent::Exit(e) => {
+            write_u8(out, 36);
+            write_u32(out, e.code as u32);
+        }
+        TraceLowLevelEvent::MemoryWrite(w) => {
+            write_u8(out, 38);
+            write_u64(out, w.address);
+            write_u32(out, w.bytes.len() as u32);
+            out.extend_from_slice(&w.bytes);
+        }
+        TraceLowLevelEvent::Alloc(a) => {
+            write_u8(out, 39);
+            write_u64(out, a.address);
+            write_u64(out, a.size);
+        }
+        TraceLowLevelEvent::Free(f) => {
+            write_u8(out, 40);
+            write_u64(out, f.address);
+        }
     }
     Ok(())
 }
@@ -193,10 +296,11 @@ pub fn encode_event(event: &TraceLowLevelEvent, out: &mut Vec<u8>) -> io::Result
 pub fn decode_event(cursor: &mut Cursor<&[u8]>) -> io::Result<TraceLowLevelEvent> {
     let tag = read_u8(cursor)?;
     match tag {
-        0 => {
+        0 | 27 => {
             let path_id = PathId(read_u64(cursor)? as usize);
             let line = Line(read_i64(cursor)?);
-            Ok(TraceLowLevelEvent::Step(StepRecord { path_id, line }))
+            let span = if tag == 27 { Some(read_span(cursor)?) } else { None };
+            Ok(TraceLowLevelEvent::Step(StepRecord { path_id, line, span }))
         }
         1 => {
             let s = read_str(cursor)?;
@@ -210,11 +314,12 @@ pub fn decode_event(cursor: &mut Cursor<&[u8]>) -> io::Result<TraceLowLevelEvent
             let value: ValueRecord = read_cbor(cursor)?;
             Ok(TraceLowLevelEvent::Value(FullValueRecord { variable_id, value }))
         }
-        6 => {
+        6 | 28 => {
             let path_id = PathId(read_u64(cursor)? as usize);
             let line = Line(read_i64(cursor)?);
+            let span = if tag == 28 { Some(read_span(cursor)?) } else { None };
             let name = read_str(cursor)?;
-            Ok(TraceLowLevelEvent::Function(FunctionRecord { path_id, line, name }))
+            Ok(TraceLowLevelEvent::Function(FunctionRecord { path_id, line, name, span }))
         }
         7 => {
             let function_id = FunctionId(read_u64(cursor)? as usize);
@@ -225,13 +330,29 @@ pub fn decode_event(cursor: &mut Cursor<&[u8]>) -> io::Result<TraceLowLevelEvent
             let return_value: ValueRecord = read_cbor(cursor)?;
             Ok(TraceLowLevelEvent::Return(ReturnRecord { return_value }))
         }
-        9 => {
+        9 | 37 => {
             let kind_byte = read_u8(cursor)?;
             let kind: EventLogKind = num_traits::FromPrimitive::from_u8(kind_byte)
                 .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("unknown EventLogKind: {}", kind_byte)))?;
             let metadata = read_str(cursor)?;
             let content = read_str(cursor)?;
-            Ok(TraceLowLevelEvent::Event(RecordEvent { kind, metadata, content }))
+            let mut attributes = vec![];
+            if tag == 37 {
+                let count = read_u32(cursor)? as usize;
+                attributes.reserve(count);
+                for _ in 0..count {
+                    attributes.push(EventAttribute {
+                        key: read_str(cursor)?,
+                        value: read_cbor(cursor)?,
+                    });
+                }
+            }
+            Ok(TraceLowLevelEvent::Event(RecordEvent {
+                kind,
+                metadata,
+                content,
+                attributes,
+            }))
         }
         10 => {
             let count = read_u32(cursor)? as usize;
@@ -290,6 +411,45 @@ pub fn decode_event(cursor: &mut Cursor<&[u8]>) -> io::Result<TraceLowLevelEvent
         21 => Ok(TraceLowLevelEvent::ThreadExit(ThreadId(read_u64(cursor)?))),
         22 => Ok(TraceLowLevelEvent::ThreadSwitch(ThreadId(read_u64(cursor)?))),
         23 => Ok(TraceLowLevelEvent::DropLastStep),
+        24 => Ok(TraceLowLevelEvent::Throw(ThrowRecord {
+            exception: read_cbor(cursor)?,
+        })),
+        25 => Ok(TraceLowLevelEvent::Catch(CatchRecord {
+            exception: read_cbor(cursor)?,
+        })),
+        26 => {
+            let count = read_u32(cursor)? as usize;
+            let mut frames = Vec::with_capacity(count);
+            for _ in 0..count {
+                frames.push(FunctionId(read_u64(cursor)? as usize));
+            }
+            Ok(TraceLowLevelEvent::Unwind(UnwindRecord { frames }))
+        }
+        29 => Ok(TraceLowLevelEvent::TaskSpawn(TaskId(read_u64(cursor)?))),
+        30 => Ok(TraceLowLevelEvent::TaskSuspend(TaskId(read_u64(cursor)?))),
+        31 => Ok(TraceLowLevelEvent::TaskResume(TaskId(read_u64(cursor)?))),
+        32 => Ok(TraceLowLevelEvent::TaskComplete(TaskId(read_u64(cursor)?))),
+        33 => Ok(TraceLowLevelEvent::ProcessStart(read_cbor(cursor)?)),
+        34 => Ok(TraceLowLevelEvent::Exec(read_cbor(cursor)?)),
+        35 => Ok(TraceLowLevelEvent::Signal(SignalRecord {
+            signal: read_u32(cursor)? as i32,
+            name: read_str(cursor)?,
+        })),
+        36 => Ok(TraceLowLevelEvent::Exit(ExitRecord {
+            code: read_u32(cursor)? as i32,
+        })),
+        38 => {
+            let address = read_u64(cursor)?;
+            let len = read_u32(cursor)? as usize;
+            let mut bytes = vec![0u8; len];
+            cursor.read_exact(&mut bytes)?;
+            Ok(TraceLowLevelEvent::MemoryWrite(MemoryWriteRecord { address, bytes }))
+        }
+        39 => Ok(TraceLowLevelEvent::Alloc(AllocRecord {
+            address: read_u64(cursor)?,
+            size: read_u64(cursor)?,
+        })),
+        40 => Ok(TraceLowLevelEvent::Free(FreeRecord { address: read_u64(cursor)? })),
         _ => Err(io::Error::new(io::ErrorKind::InvalidData, format!("unknown event tag: {}", tag))),
     }
 }
@@ -410,6 +570,57 @@ fn event_byte_size(data: &[u8], offset: usize) -> usize {
         19 => 9,           // DropVariable: tag(1) + var_id(8)
         20 | 21 | 22 => 9, // ThreadStart/Exit/Switch: tag(1) + thread_id(8)
         23 => 1,           // DropLastStep: tag(1)
+        24 | 25 => {
+            // Throw, Catch: tag(1) + cbor_len(4) + cbor
+            let cbor_len = u32::from_le_bytes(data[offset + 1..offset + 5].try_into().unwrap()) as usize;
+            5 + cbor_len
+        }
+        26 => {
+            // Unwind: tag(1) + count(4) + [function_id(8)]...
+            let count = u32::from_le_bytes(data[offset + 1..offset + 5].try_into().unwrap()) as usize;
+            5 + count * 8
+        }
+        27 => 17 + SPAN_SIZE, // Step with span: tag(1) + path_id(8) + line(8) + span(24)
+        28 => {
+            // Function with span: tag(1) + path_id(8) + line(8) + span(24) + name_len(4) + name
+            let name_pos = offset + 17 + SPAN_SIZE;
+            let name_len = u32::from_le_bytes(data[name_pos..name_pos + 4].try_into().unwrap()) as usize;
+            21 + SPAN_SIZE + name_len
+        }
+        29..=32 => 9, // TaskSpawn/Suspend/Resume/Complete: tag(1) + task_id(8)
+        33 | 34 => {
+            // ProcessStart, Exec: tag(1) + cbor_len(4) + cbor
+            let cbor_len = u32::from_le_bytes(data[offset + 1..offset + 5].try_into().unwrap()) as usize;
+            5 + cbor_len
+        }
+        35 => {
+            // Signal: tag(1) + signal(4) + name_len(4) + name
+            let name_len = u32::from_le_bytes(data[offset + 5..offset + 9].try_into().unwrap()) as usize;
+            9 + name_len
+        }
+        36 => 5, // Exit: tag(1) + code(4)
+        38 => {
+            // MemoryWrite: tag(1) + address(8) + len(4) + bytes
+            let len = u32::from_le_bytes(data[offset + 9..offset + 13].try_into().unwrap()) as usize;
+            13 + len
+        }
+        39 => 17, // Alloc: tag(1) + address(8) + size(8)
+        40 => 9,  // Free: tag(1) + address(8)
+        37 => {
+            // Event with attributes: the tag 9 layout, then count(4) + [key_len(4) + key + cbor_len(4) + cbor]...
+            let meta_len = u32::from_le_bytes(data[offset + 2..offset + 6].try_into().unwrap()) as usize;
+            let content_len = u32::from_le_bytes(data[offset + 6 + meta_len..offset + 10 + meta_len].try_into().unwrap()) as usize;
+            let mut pos = offset + 10 + meta_len + content_len;
+            let count = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
+            pos += 4;
+            for _ in 0..count {
+                let key_len = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
+                pos += 4 + key_len;
+                let cbor_len = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
+                pos += 4 + cbor_len;
+            }
+            pos - offset
+        }
         _ => panic!("unknown split-binary event tag: {}", tag),
     }
 }
@@ -423,6 +634,7 @@ mod tests {
         let event = TraceLowLevelEvent::Step(StepRecord {
             path_id: PathId(42),
             line: Line(100),
+            span: None,
         });
         let mut buf = Vec::new();
         encode_event(&event, &mut buf).unwrap();
@@ -455,6 +667,7 @@ mod tests {
             TraceLowLevelEvent::Step(StepRecord {
                 path_id: PathId(1),
                 line: Line(10),
+                span: None,
             }),
             TraceLowLevelEvent::Path("/hello".into()),
             TraceLowLevelEvent::DropLastStep,
@@ -470,12 +683,301 @@ mod tests {
         assert_eq!(offsets[2] as usize, sizes[0] + sizes[1]);
     }
 
+    #[test]
+    fn test_split_binary_spans() {
+        let span = SourceSpan {
+            start_line: Line(10),
+            start_column: 5,
+            end_line: Line(12),
+            end_column: 1,
+        };
+        let events = vec![
+            TraceLowLevelEvent::Function(FunctionRecord {
+                path_id: PathId(0),
+                line: Line(10),
+                name: "closure".to_string(),
+                span: Some(span),
+            }),
+            TraceLowLevelEvent::Step(StepRecord {
+                path_id: PathId(0),
+                line: Line(11),
+                span: Some(span),
+            }),
+            TraceLowLevelEvent::Step(StepRecord {
+                path_id: PathId(0),
+                line: Line(12),
+                span: None,
+            }),
+        ];
+
+        let (buf, sizes) = encode_events(&events);
+        assert_eq!(sizes, vec![21 + SPAN_SIZE + "closure".len(), 17 + SPAN_SIZE, 17]);
+        assert_eq!(scan_event_offsets(&buf), vec![0, sizes[0] as u32, (sizes[0] + sizes[1]) as u32]);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_spans_far_from_line() {
+        // spans far from the event's line, or ending before they start,
+        // decode as they were written
+        let step = |line: i64, start_line: i64, end_line: i64| {
+            TraceLowLevelEvent::Step(StepRecord {
+                path_id: PathId(0),
+                line: Line(line),
+                span: Some(SourceSpan {
+                    start_line: Line(start_line),
+                    start_column: 1,
+                    end_line: Line(end_line),
+                    end_column: 2,
+                }),
+            })
+        };
+        let events = vec![step(1, 1 << 40, (1 << 40) + 3), step(10, 12, 11), step(i64::MAX, 0, i64::MAX)];
+
+        let (buf, _) = encode_events(&events);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_exception_events() {
+        let exception = ValueRecord::Error {
+            msg: "boom".to_string(),
+            type_id: TypeId(4),
+        };
+        let events = vec![
+            TraceLowLevelEvent::Throw(ThrowRecord {
+                exception: exception.clone(),
+            }),
+            TraceLowLevelEvent::Unwind(UnwindRecord {
+                frames: vec![FunctionId(3), FunctionId(2)],
+            }),
+            TraceLowLevelEvent::Unwind(UnwindRecord { frames: vec![] }),
+            TraceLowLevelEvent::Catch(CatchRecord { exception }),
+        ];
+
+        let (buf, sizes) = encode_events(&events);
+        assert_eq!(sizes[1], 5 + 2 * 8);
+        let offsets = scan_event_offsets(&buf);
+        let expected: Vec<u32> = sizes
+            .iter()
+            .scan(0, |pos, size| {
+                let start = *pos;
+                *pos += *size as u32;
+                Some(start)
+            })
+            .collect();
+        assert_eq!(offsets, expected);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_process_events() {
+        let events = vec![
+            TraceLowLevelEvent::ProcessStart(ProcessStartRecord {
+                pid: 4242,
+                parent_pid: 4000,
+                parent_recording_id: Some("01949fcc-7d92-7e9c-aaaa-bbbbbbbbbbbb".to_string()),
+            }),
+            TraceLowLevelEvent::Exec(ExecRecord {
+                program: "/bin/sh".to_string(),
+                args: vec!["-c".to_string(), "true".to_string()],
+            }),
+            TraceLowLevelEvent::Signal(SignalRecord {
+                signal: 15,
+                name: "SIGTERM".to_string(),
+            }),
+            TraceLowLevelEvent::Exit(ExitRecord { code: -1 }),
+        ];
+
+        let (buf, sizes) = encode_events(&events);
+        assert_eq!(sizes[2], 9 + "SIGTERM".len());
+        assert_eq!(sizes[3], 5);
+        let offsets = scan_event_offsets(&buf);
+        let expected: Vec<u32> = sizes
+            .iter()
+            .scan(0, |pos, size| {
+                let start = *pos;
+                *pos += *size as u32;
+                Some(start)
+            })
+            .collect();
+        assert_eq!(offsets, expected);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_event_attributes() {
+        let plain = TraceLowLevelEvent::Event(RecordEvent {
+            kind: EventLogKind::Write,
+            metadata: String::new(),
+            content: "hello".to_string(),
+            attributes: vec![],
+        });
+        let with_attributes = TraceLowLevelEvent::Event(RecordEvent {
+            kind: EventLogKind::TraceLogEvent,
+            metadata: String::new(),
+            content: "connected".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "level".to_string(),
+                    value: ValueRecord::String {
+                        text: "info".to_string(),
+                        type_id: TypeId(1),
+                    },
+                },
+                EventAttribute {
+                    key: "fd".to_string(),
+                    value: ValueRecord::Int { i: 3, type_id: TypeId(0) },
+                },
+            ],
+        });
+        let events = vec![plain, with_attributes];
+
+        let (buf, sizes) = encode_events(&events);
+        // events without attributes keep the original layout
+        assert_eq!(buf[0], 9);
+        assert_eq!(sizes[0], 10 + "hello".len());
+        assert_eq!(buf[sizes[0]], 37);
+        let offsets = scan_event_offsets(&buf);
+        assert_eq!(offsets, vec![0, sizes[0] as u32]);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_memory_events() {
+        let data: Vec<u8> = (0..64).collect();
+        let events = vec![
+            TraceLowLevelEvent::Alloc(AllocRecord {
+                address: 0x5000_0000,
+                size: 64,
+            }),
+            TraceLowLevelEvent::MemoryWrite(MemoryWriteRecord {
+                address: 0x5000_0000,
+                bytes: data.clone(),
+            }),
+            TraceLowLevelEvent::MemoryWrite(MemoryWriteRecord {
+                address: 0x7fff_0000,
+                bytes: vec![],
+            }),
+            TraceLowLevelEvent::Free(FreeRecord { address: 0x5000_0000 }),
+        ];
+
+        let (buf, sizes) = encode_events(&events);
+        assert_eq!(sizes, vec![17, 13 + data.len(), 13, 9]);
+        assert_eq!(&buf[17 + 13..17 + 13 + data.len()], data.as_slice());
+        assert_eq!(
+            scan_event_offsets(&buf),
+            vec![0, 17, 17 + 13 + data.len() as u32, 17 + 26 + data.len() as u32]
+        );
+        assert_eq!(format!("{:?}", events), format!("{:?}", decode_events(&buf)));
+    }
+
+    #[test]
+    fn test_split_binary_bytes_value_is_raw() {
+        let data: Vec<u8> = (0..=255).collect();
+        let event = TraceLowLevelEvent::Value(FullValueRecord {
+            variable_id: VariableId(1),
+            value: ValueRecord::Bytes {
+                data: data.clone(),
+                type_id: TypeId(3),
+            },
+        });
+        let mut buf = Vec::new();
+        encode_event(&event, &mut buf).unwrap();
+        // the blob is stored verbatim, not base64-expanded
+        assert!(buf.windows(data.len()).any(|w| w == data.as_slice()));
+        assert!(buf.len() < data.len() + 64);
+
+        let mut cursor = Cursor::new(buf.as_slice());
+        let decoded = decode_event(&mut cursor).unwrap();
+        assert_eq!(format!("{:?}", event), format!("{:?}", decoded));
+    }
+
+    #[test]
+    fn test_split_binary_wide_ints_are_little_endian() {
+        let i = i128::MIN + 7;
+        let u = u128::MAX - 7;
+        let events = vec![
+            TraceLowLevelEvent::Return(ReturnRecord {
+                return_value: ValueRecord::Int128 { i, type_id: TypeId(1) },
+            }),
+            TraceLowLevelEvent::Return(ReturnRecord {
+                return_value: ValueRecord::UInt128 { u, type_id: TypeId(2) },
+            }),
+            TraceLowLevelEvent::Return(ReturnRecord {
+                return_value: ValueRecord::UInt {
+                    u: u64::MAX,
+                    type_id: TypeId(3),
+                },
+            }),
+        ];
+        let (buf, sizes) = encode_events(&events);
+        assert!(buf.windows(16).any(|w| w == i.to_le_bytes()));
+        assert!(buf.windows(16).any(|w| w == u.to_le_bytes()));
+        // no decimal strings in the binary form
+        assert!(!buf.windows(10).any(|w| w == &u.to_string().as_bytes()[..10]));
+
+        let offsets = scan_event_offsets(&buf);
+        assert_eq!(offsets.len(), sizes.len());
+        let decoded = decode_events(&buf);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decoded));
+    }
+
+    #[test]
+    fn test_split_binary_type_specific_info_roundtrip() {
+        let events = vec![
+            TraceLowLevelEvent::Type(TypeRecord {
+                kind: TypeKind::Seq,
+                lang_type: "Vec<i64>".to_string(),
+                specific_info: TypeSpecificInfo::Collection { element_type_id: TypeId(1) },
+            }),
+            TraceLowLevelEvent::Type(TypeRecord {
+                kind: TypeKind::TableKind,
+                lang_type: "HashMap<String, i64>".to_string(),
+                specific_info: TypeSpecificInfo::Table {
+                    key_type_id: TypeId(2),
+                    value_type_id: TypeId(1),
+                },
+            }),
+            TraceLowLevelEvent::Type(TypeRecord {
+                kind: TypeKind::Variant,
+                lang_type: "Option<i64>".to_string(),
+                specific_info: TypeSpecificInfo::Variants {
+                    variants: vec![
+                        VariantTypeRecord {
+                            name: "None".to_string(),
+                            type_id: NONE_TYPE_ID,
+                        },
+                        VariantTypeRecord {
+                            name: "Some".to_string(),
+                            type_id: TypeId(1),
+                        },
+                    ],
+                },
+            }),
+            TraceLowLevelEvent::Type(TypeRecord {
+                kind: TypeKind::FunctionKind,
+                lang_type: "fn(i64, String) -> bool".to_string(),
+                specific_info: TypeSpecificInfo::Function {
+                    param_type_ids: vec![TypeId(1), TypeId(2)],
+                    return_type_id: TypeId(3),
+                },
+            }),
+        ];
+
+        let (buf, sizes) = encode_events(&events);
+        assert_eq!(scan_event_offsets(&buf).len(), sizes.len());
+        let decoded = decode_events(&buf);
+        assert_eq!(format!("{:?}", events), format!("{:?}", decoded));
+    }
+
     #[test]
     fn test_split_binary_all_fixed_size_variants() {
         let events = vec![
             TraceLowLevelEvent::Step(StepRecord {
                 path_id: PathId(1),
                 line: Line(1),
+                span: None,
             }),
             TraceLowLevelEvent::BindVariable(BindVariableRecord {
                 variable_id: VariableId(5),
@@ -494,6 +996,10 @@ mod tests {
             TraceLowLevelEvent::ThreadStart(ThreadId(1)),
             TraceLowLevelEvent::ThreadExit(ThreadId(2)),
             TraceLowLevelEvent::ThreadSwitch(ThreadId(3)),
+            TraceLowLevelEvent::TaskSpawn(TaskId(4)),
+            TraceLowLevelEvent::TaskResume(TaskId(4)),
+            TraceLowLevelEvent::TaskSuspend(TaskId(4)),
+            TraceLowLevelEvent::TaskComplete(TaskId(4)),
             TraceLowLevelEvent::DropLastStep,
         ];
 
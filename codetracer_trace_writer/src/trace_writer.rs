@@ -1,9 +1,10 @@
 use std::{error::Error, path::Path};
 
 use crate::abstract_trace_writer::AbstractTraceWriter;
+use crate::object_identity::ObjectKey;
 use codetracer_trace_types::{
-    EventLogKind, FullValueRecord, FunctionId, Line, PassBy, PathId, Place, RValue, TraceLowLevelEvent, TypeId, TypeKind, TypeRecord, ValueRecord,
-    VariableId,
+    EventAttribute, EventLogKind, FullValueRecord, FunctionId, Line, ObjectId, PassBy, PathId, Place, RValue, SourceSpan, TraceLowLevelEvent,
+    TraceMetadata, TypeId, TypeKind, TypeRecord, ValueRecord, VariableId,
 };
 
 pub trait TraceWriter: AbstractTraceWriter {
@@ -17,6 +18,18 @@ pub trait TraceWriter: AbstractTraceWriter {
     fn set_workdir(&mut self, workdir: &Path) {
         AbstractTraceWriter::set_workdir(self, workdir)
     }
+    fn set_language(&mut self, language: &str) {
+        AbstractTraceWriter::set_language(self, language)
+    }
+    fn set_recorder(&mut self, name: &str, version: &str) {
+        AbstractTraceWriter::set_recorder(self, name, version)
+    }
+    fn probe_host(&mut self) {
+        AbstractTraceWriter::probe_host(self)
+    }
+    fn derive_from(&mut self, source: &TraceMetadata) {
+        AbstractTraceWriter::derive_from(self, source)
+    }
 
     fn start(&mut self, path: &Path, line: Line) {
         AbstractTraceWriter::start(self, path, line)
@@ -27,6 +40,9 @@ pub trait TraceWriter: AbstractTraceWriter {
     fn ensure_function_id(&mut self, function_name: &str, path: &Path, line: Line) -> FunctionId {
         AbstractTraceWriter::ensure_function_id(self, function_name, path, line)
     }
+    fn ensure_function_id_with_span(&mut self, function_name: &str, path: &Path, span: SourceSpan) -> FunctionId {
+        AbstractTraceWriter::ensure_function_id_with_span(self, function_name, path, span)
+    }
     fn ensure_type_id(&mut self, kind: TypeKind, lang_type: &str) -> TypeId {
         AbstractTraceWriter::ensure_type_id(self, kind, lang_type)
     }
@@ -42,9 +58,15 @@ pub trait TraceWriter: AbstractTraceWriter {
     fn register_function(&mut self, name: &str, path: &Path, line: Line) {
         AbstractTraceWriter::register_function(self, name, path, line)
     }
+    fn register_function_with_span(&mut self, name: &str, path: &Path, span: SourceSpan) {
+        AbstractTraceWriter::register_function_with_span(self, name, path, span)
+    }
     fn register_step(&mut self, path: &Path, line: Line) {
         AbstractTraceWriter::register_step(self, path, line)
     }
+    fn register_step_with_span(&mut self, path: &Path, span: SourceSpan) {
+        AbstractTraceWriter::register_step_with_span(self, path, span)
+    }
     fn register_call(&mut self, function_id: FunctionId, args: Vec<FullValueRecord>) {
         AbstractTraceWriter::register_call(self, function_id, args)
     }
@@ -54,9 +76,48 @@ pub trait TraceWriter: AbstractTraceWriter {
     fn register_return(&mut self, return_value: ValueRecord) {
         AbstractTraceWriter::register_return(self, return_value)
     }
+    fn register_throw(&mut self, exception: ValueRecord) {
+        AbstractTraceWriter::register_throw(self, exception)
+    }
+    fn register_catch(&mut self, exception: ValueRecord) {
+        AbstractTraceWriter::register_catch(self, exception)
+    }
+    fn register_unwind(&mut self, frames: &[FunctionId]) {
+        AbstractTraceWriter::register_unwind(self, frames)
+    }
+    fn register_memory_write(&mut self, address: u64, bytes: &[u8]) {
+        AbstractTraceWriter::register_memory_write(self, address, bytes)
+    }
+    fn register_alloc(&mut self, address: u64, size: u64) {
+        AbstractTraceWriter::register_alloc(self, address, size)
+    }
+    fn register_free(&mut self, address: u64) {
+        AbstractTraceWriter::register_free(self, address)
+    }
+    fn set_capture_limits(&mut self, max_elements: Option<usize>, max_depth: Option<usize>, max_string_len: Option<usize>) {
+        AbstractTraceWriter::set_capture_limits(self, max_elements, max_depth, max_string_len)
+    }
+    fn limit_value(&self, value: ValueRecord) -> ValueRecord {
+        AbstractTraceWriter::limit_value(self, value)
+    }
+    fn ensure_object_id(&mut self, key: ObjectKey) -> (ObjectId, bool) {
+        AbstractTraceWriter::ensure_object_id(self, key)
+    }
+    fn set_reference_dedup(&mut self, enabled: bool) {
+        AbstractTraceWriter::set_reference_dedup(self, enabled)
+    }
+    fn set_embed_sources(&mut self, enabled: bool) {
+        AbstractTraceWriter::set_embed_sources(self, enabled)
+    }
+    fn prepare_value(&mut self, value: ValueRecord) -> ValueRecord {
+        AbstractTraceWriter::prepare_value(self, value)
+    }
     fn register_special_event(&mut self, kind: EventLogKind, metadata: &str, content: &str) {
         AbstractTraceWriter::register_special_event(self, kind, metadata, content)
     }
+    fn register_special_event_with_attributes(&mut self, kind: EventLogKind, metadata: &str, content: &str, attributes: Vec<EventAttribute>) {
+        AbstractTraceWriter::register_special_event_with_attributes(self, kind, metadata, content, attributes)
+    }
     fn to_raw_type(&self, kind: TypeKind, lang_type: &str) -> TypeRecord {
         AbstractTraceWriter::to_raw_type(self, kind, lang_type)
     }
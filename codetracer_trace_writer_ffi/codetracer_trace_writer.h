@@ -198,6 +198,150 @@ void trace_writer_register_variable_raw(struct TraceWriterHandle *handle,
                                         enum Tk type_kind,
                                         const char *type_name);
 
+/**
+ * Begin a map (dict / hash table) value.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` must be `NULL` or a NUL-terminated string.
+ */
+void trace_writer_value_begin_map(struct TraceWriterHandle *handle,
+                                  enum Tk type_kind,
+                                  const char *type_name);
+
+/**
+ * Close the innermost compound value opened with `trace_writer_value_begin_*`.
+ *
+ * Returns `false` if no compound is open or a map entry is missing its value.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`].
+ */
+bool trace_writer_value_end(struct TraceWriterHandle *handle);
+
+/**
+ * Write an integer leaf value into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` must be `NULL` or a NUL-terminated string.
+ */
+bool trace_writer_value_int(struct TraceWriterHandle *handle,
+                            int64_t value,
+                            enum Tk type_kind,
+                            const char *type_name);
+
+/**
+ * Write an unsigned 64-bit integer leaf value into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` must be `NULL` or a NUL-terminated string.
+ */
+bool trace_writer_value_uint(struct TraceWriterHandle *handle,
+                             uint64_t value,
+                             enum Tk type_kind,
+                             const char *type_name);
+
+/**
+ * Write a signed 128-bit integer leaf value, given as its low and high
+ * 64 bits, into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` must be `NULL` or a NUL-terminated string.
+ */
+bool trace_writer_value_int128(struct TraceWriterHandle *handle,
+                               uint64_t lo,
+                               uint64_t hi,
+                               enum Tk type_kind,
+                               const char *type_name);
+
+/**
+ * Write an unsigned 128-bit integer leaf value, given as its low and high
+ * 64 bits, into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` must be `NULL` or a NUL-terminated string.
+ */
+bool trace_writer_value_uint128(struct TraceWriterHandle *handle,
+                                uint64_t lo,
+                                uint64_t hi,
+                                enum Tk type_kind,
+                                const char *type_name);
+
+/**
+ * Write a string (raw) leaf value into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `type_name` and `value_repr` must be `NULL` or NUL-terminated strings.
+ */
+bool trace_writer_value_raw(struct TraceWriterHandle *handle,
+                            const char *value_repr,
+                            enum Tk type_kind,
+                            const char *type_name);
+
+/**
+ * Write a byte-buffer leaf value into the value being built.
+ *
+ * Returns `false` if a finished value is still waiting to be registered.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * `type_name` must be `NULL` or a NUL-terminated string, and `data` must
+ * point to `len` readable bytes; it may be `NULL` when `len` is 0.
+ */
+bool trace_writer_value_bytes(struct TraceWriterHandle *handle,
+                              const uint8_t *data,
+                              uintptr_t len,
+                              enum Tk type_kind,
+                              const char *type_name);
+
+/**
+ * Register a variable whose value was built with the `trace_writer_value_*`
+ * functions.
+ *
+ * Returns `false` if no complete value is available.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+ * and `name` must be `NULL` or a NUL-terminated string.
+ */
+bool trace_writer_register_variable_value(struct TraceWriterHandle *handle, const char *name);
+
+/**
+ * Register a function return whose value was built with the
+ * `trace_writer_value_*` functions.
+ *
+ * Returns `false` if no complete value is available.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a live handle from [`trace_writer_new`].
+ */
+bool trace_writer_register_return_value(struct TraceWriterHandle *handle);
+
 /**
  * Register an I/O or special event with optional metadata.
  *
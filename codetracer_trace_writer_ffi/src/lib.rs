@@ -15,7 +15,7 @@ use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::path::Path;
 
-use codetracer_trace_types::{EventLogKind, Line, TypeKind, ValueRecord};
+use codetracer_trace_types::{EventLogKind, Line, TypeId, TypeKind, ValueRecord};
 use codetracer_trace_writer::trace_writer::TraceWriter;
 use codetracer_trace_writer::{TraceEventsFileFormat, create_trace_writer};
 use num_traits::FromPrimitive;
@@ -51,6 +51,56 @@ pub extern "C" fn trace_writer_last_error() -> *const c_char {
 /// Opaque handle passed across the FFI boundary.
 pub struct TraceWriterHandle {
     inner: Box<dyn TraceWriter + Send>,
+    /// Compound values opened with `trace_writer_value_begin_*`, innermost last.
+    pending: Vec<PendingValue>,
+    /// The last completed top-level value, consumed by
+    /// `trace_writer_register_variable_value` / `trace_writer_register_return_value`.
+    value: Option<ValueRecord>,
+}
+
+/// A compound value still being assembled by the `trace_writer_value_*` calls.
+enum PendingValue {
+    /// Leaves alternate between key and value; `key` holds a key that is
+    /// still waiting for its value.
+    Map {
+        entries: Vec<(ValueRecord, ValueRecord)>,
+        key: Option<ValueRecord>,
+        type_id: TypeId,
+    },
+}
+
+impl TraceWriterHandle {
+    /// Attach a finished value to the innermost open compound, or make it
+    /// the completed top-level value.  Fails if a completed top-level value
+    /// is already waiting to be registered.
+    fn push_value(&mut self, value: ValueRecord) -> bool {
+        match self.pending.last_mut() {
+            None if self.value.is_some() => {
+                set_error("the previous value was not registered; call trace_writer_register_*_value first");
+                return false;
+            }
+            None => self.value = Some(value),
+            Some(PendingValue::Map { entries, key, .. }) => match key.take() {
+                None => *key = Some(value),
+                Some(k) => entries.push((k, value)),
+            },
+        }
+        true
+    }
+
+    /// Take the completed top-level value, failing if nothing was built or a
+    /// compound is still open.
+    fn take_value(&mut self) -> Option<ValueRecord> {
+        if !self.pending.is_empty() {
+            set_error("a compound value is still open; call trace_writer_value_end first");
+            return None;
+        }
+        let value = self.value.take();
+        if value.is_none() {
+            set_error("no value was built with the trace_writer_value_* functions");
+        }
+        value
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -172,7 +222,11 @@ fn w(handle: &mut TraceWriterHandle) -> &mut dyn TraceWriter {
 pub unsafe extern "C" fn trace_writer_new(program: *const c_char, format: FfiTraceFormat) -> *mut TraceWriterHandle {
     let prog = unsafe { cstr_to_str(program) };
     let writer = create_trace_writer(prog, &[], to_format(format));
-    Box::into_raw(Box::new(TraceWriterHandle { inner: writer }))
+    Box::into_raw(Box::new(TraceWriterHandle {
+        inner: writer,
+        pending: Vec::new(),
+        value: None,
+    }))
 }
 
 /// Free a trace writer handle.  Passing `NULL` is a no-op.
@@ -448,6 +502,270 @@ pub unsafe extern "C" fn trace_writer_register_variable_raw(
     );
 }
 
+// ---------------------------------------------------------------------------
+// Compound values
+// ---------------------------------------------------------------------------
+//
+// Compound values are built bottom-up, mirroring the Nim streaming value
+// encoder: open a compound with `trace_writer_value_begin_*`, write its
+// children with the leaf functions (or nested compounds), and close it with
+// `trace_writer_value_end`.  Inside a map, children alternate key, value.
+// The finished top-level value is then registered with
+// `trace_writer_register_variable_value` or `trace_writer_register_return_value`.
+
+/// Begin a map (dict / hash table) value.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_begin_map(handle: *mut TraceWriterHandle, type_kind: FfiTypeKind, type_name: *const c_char) {
+    if handle.is_null() {
+        return;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    h.pending.push(PendingValue::Map {
+        entries: Vec::new(),
+        key: None,
+        type_id,
+    });
+}
+
+/// Close the innermost compound value opened with `trace_writer_value_begin_*`.
+///
+/// Returns `false` if no compound is open or a map entry is missing its value.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_end(handle: *mut TraceWriterHandle) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let value = match h.pending.pop() {
+        None => {
+            set_error("trace_writer_value_end called without an open compound value");
+            return false;
+        }
+        Some(PendingValue::Map { key: Some(_), .. }) => {
+            set_error("map entry is missing its value");
+            return false;
+        }
+        Some(PendingValue::Map { entries, key: None, type_id }) => ValueRecord::Map { entries, type_id },
+    };
+    h.push_value(value)
+}
+
+/// Write an integer leaf value into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_int(
+    handle: *mut TraceWriterHandle,
+    value: i64,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    h.push_value(ValueRecord::Int { i: value, type_id })
+}
+
+/// Write an unsigned 64-bit integer leaf value into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_uint(
+    handle: *mut TraceWriterHandle,
+    value: u64,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    h.push_value(ValueRecord::UInt { u: value, type_id })
+}
+
+/// Write a signed 128-bit integer leaf value, given as its low and high
+/// 64 bits, into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_int128(
+    handle: *mut TraceWriterHandle,
+    lo: u64,
+    hi: u64,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    let i = (((hi as u128) << 64) | lo as u128) as i128;
+    h.push_value(ValueRecord::Int128 { i, type_id })
+}
+
+/// Write an unsigned 128-bit integer leaf value, given as its low and high
+/// 64 bits, into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_uint128(
+    handle: *mut TraceWriterHandle,
+    lo: u64,
+    hi: u64,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    let u = ((hi as u128) << 64) | lo as u128;
+    h.push_value(ValueRecord::UInt128 { u, type_id })
+}
+
+/// Write a string (raw) leaf value into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `type_name` and `value_repr` must be `NULL` or NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_raw(
+    handle: *mut TraceWriterHandle,
+    value_repr: *const c_char,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    h.push_value(ValueRecord::Raw {
+        r: unsafe { cstr_to_str(value_repr) }.to_string(),
+        type_id,
+    })
+}
+
+/// Write a byte-buffer leaf value into the value being built.
+///
+/// Returns `false` if a finished value is still waiting to be registered.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// `type_name` must be `NULL` or a NUL-terminated string, and `data` must
+/// point to `len` readable bytes; it may be `NULL` when `len` is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_value_bytes(
+    handle: *mut TraceWriterHandle,
+    data: *const u8,
+    len: usize,
+    type_kind: FfiTypeKind,
+    type_name: *const c_char,
+) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let type_id = TraceWriter::ensure_type_id(w(h), to_type_kind(type_kind), unsafe { cstr_to_str(type_name) });
+    let data = if data.is_null() || len == 0 {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }.to_vec()
+    };
+    h.push_value(ValueRecord::Bytes { data, type_id })
+}
+
+/// Register a variable whose value was built with the `trace_writer_value_*`
+/// functions.
+///
+/// Returns `false` if no complete value is available.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`],
+/// and `name` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_register_variable_value(handle: *mut TraceWriterHandle, name: *const c_char) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let Some(value) = h.take_value() else {
+        return false;
+    };
+    TraceWriter::register_variable_with_full_value(w(h), unsafe { cstr_to_str(name) }, value);
+    true
+}
+
+/// Register a function return whose value was built with the
+/// `trace_writer_value_*` functions.
+///
+/// Returns `false` if no complete value is available.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a live handle from [`trace_writer_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trace_writer_register_return_value(handle: *mut TraceWriterHandle) -> bool {
+    if handle.is_null() {
+        set_error("null handle");
+        return false;
+    }
+    let h = unsafe { &mut *handle };
+    let Some(value) = h.take_value() else {
+        return false;
+    };
+    TraceWriter::register_return(w(h), value);
+    true
+}
+
 /// Register an I/O or special event with optional metadata.
 ///
 /// `metadata` is an arbitrary NUL-terminated string attached to the event
@@ -528,4 +846,99 @@ mod tests {
         unsafe { trace_writer_free(handle) };
         fs::remove_dir_all(&tmp).ok();
     }
+
+    #[test]
+    fn test_map_value() {
+        let tmp = std::env::temp_dir().join(format!("ffi_map_test_{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+
+        let program = CString::new("test_program").unwrap();
+        let handle = unsafe { trace_writer_new(program.as_ptr(), FfiTraceFormat::Json) };
+        let events_path = CString::new(tmp.join("trace.json").to_str().unwrap()).unwrap();
+        assert!(unsafe { trace_writer_begin_events(handle, events_path.as_ptr()) });
+
+        let source = CString::new("/test/main.py").unwrap();
+        unsafe { trace_writer_start(handle, source.as_ptr(), 1) };
+
+        let dict_name = CString::new("dict").unwrap();
+        let str_name = CString::new("str").unwrap();
+        let int_name = CString::new("int").unwrap();
+        let key = CString::new("answer").unwrap();
+        unsafe {
+            trace_writer_value_begin_map(handle, FfiTypeKind::TableKind, dict_name.as_ptr());
+            trace_writer_value_raw(handle, key.as_ptr(), FfiTypeKind::String, str_name.as_ptr());
+            trace_writer_value_int(handle, 42, FfiTypeKind::Int, int_name.as_ptr());
+        }
+        // still open: registering must fail
+        let var_name = CString::new("d").unwrap();
+        assert!(!unsafe { trace_writer_register_variable_value(handle, var_name.as_ptr()) });
+        assert!(unsafe { trace_writer_value_end(handle) });
+        assert!(unsafe { trace_writer_register_variable_value(handle, var_name.as_ptr()) });
+        // the value was consumed
+        assert!(!unsafe { trace_writer_register_return_value(handle) });
+
+        // a finished value must be registered before the next one is built
+        assert!(unsafe { trace_writer_value_int(handle, 1, FfiTypeKind::Int, int_name.as_ptr()) });
+        assert!(!unsafe { trace_writer_value_int(handle, 2, FfiTypeKind::Int, int_name.as_ptr()) });
+        assert!(unsafe { trace_writer_register_return_value(handle) });
+
+        assert!(unsafe { trace_writer_finish_events(handle) });
+        let trace_content = fs::read_to_string(tmp.join("trace.json")).unwrap();
+        let events: Vec<codetracer_trace_types::TraceLowLevelEvent> = serde_json::from_str(&trace_content).unwrap();
+        let map = events.iter().find_map(|e| match e {
+            codetracer_trace_types::TraceLowLevelEvent::Value(v) => Some(v.value.clone()),
+            _ => None,
+        });
+        match map {
+            Some(ValueRecord::Map { entries, .. }) => {
+                assert_eq!(entries.len(), 1);
+                assert!(matches!(&entries[0].0, ValueRecord::Raw { r, .. } if r == "answer"));
+                assert!(matches!(entries[0].1, ValueRecord::Int { i: 42, .. }));
+            }
+            other => panic!("expected a Map value, got {other:?}"),
+        }
+
+        unsafe { trace_writer_free(handle) };
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_wide_int_values() {
+        let tmp = std::env::temp_dir().join(format!("ffi_wide_int_test_{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+
+        let program = CString::new("test_program").unwrap();
+        let handle = unsafe { trace_writer_new(program.as_ptr(), FfiTraceFormat::Json) };
+        let events_path = CString::new(tmp.join("trace.json").to_str().unwrap()).unwrap();
+        assert!(unsafe { trace_writer_begin_events(handle, events_path.as_ptr()) });
+
+        let source = CString::new("/test/main.rs").unwrap();
+        unsafe { trace_writer_start(handle, source.as_ptr(), 1) };
+
+        let u64_name = CString::new("u64").unwrap();
+        let i128_name = CString::new("i128").unwrap();
+        let var_name = CString::new("big").unwrap();
+        unsafe { trace_writer_value_uint(handle, u64::MAX, FfiTypeKind::Int, u64_name.as_ptr()) };
+        assert!(unsafe { trace_writer_register_variable_value(handle, var_name.as_ptr()) });
+        let min = i128::MIN + 1;
+        unsafe { trace_writer_value_int128(handle, min as u64, (min >> 64) as u64, FfiTypeKind::Int, i128_name.as_ptr()) };
+        assert!(unsafe { trace_writer_register_return_value(handle) });
+
+        assert!(unsafe { trace_writer_finish_events(handle) });
+        let trace_content = fs::read_to_string(tmp.join("trace.json")).unwrap();
+        // 128-bit values are kept as decimal strings in JSON
+        assert!(trace_content.contains(&format!("\"{min}\"")));
+        let events: Vec<codetracer_trace_types::TraceLowLevelEvent> = serde_json::from_str(&trace_content).unwrap();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            codetracer_trace_types::TraceLowLevelEvent::Value(v) if matches!(v.value, ValueRecord::UInt { u: u64::MAX, .. })
+        )));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            codetracer_trace_types::TraceLowLevelEvent::Return(r) if r.return_value == ValueRecord::Int128 { i: min, type_id: TypeId(2) }
+        )));
+
+        unsafe { trace_writer_free(handle) };
+        fs::remove_dir_all(&tmp).ok();
+    }
 }
@@ -261,6 +261,73 @@ extern "C" {
     fn ct_free_buffer(buf: *mut u8);
 }
 
+// Exports for the event and value kinds added after the thread events. They
+// are only declared with the `extended-events` feature, which needs a
+// codetracer-trace-format-nim checkout exporting them; without it the writer
+// drops these events (and writes the other values as `Raw`) instead of
+// failing to link.
+#[cfg(feature = "extended-events")]
+extern "C" {
+    fn ct_value_write_uint(h: *mut std::ffi::c_void, value: u64, type_id: u64) -> i32;
+    fn ct_value_write_int128(h: *mut std::ffi::c_void, lo: u64, hi: u64, type_id: u64) -> i32;
+    fn ct_value_write_uint128(h: *mut std::ffi::c_void, lo: u64, hi: u64, type_id: u64) -> i32;
+    fn ct_value_begin_map(h: *mut std::ffi::c_void, type_id: u64, entry_count: i32) -> i32;
+    fn ct_value_begin_elided(h: *mut std::ffi::c_void, original_len: u64, depth_cutoff: i32) -> i32;
+    fn ct_value_begin_object(h: *mut std::ffi::c_void, object_id: u64) -> i32;
+    fn ct_value_write_back_ref(h: *mut std::ffi::c_void, object_id: u64, type_id: u64) -> i32;
+    fn ct_value_write_bytes(h: *mut std::ffi::c_void, data: *const u8, len: usize, type_id: u64) -> i32;
+
+    // Attribute values are streaming-encoder CBOR, concatenated; `value_lens`
+    // gives each value's length in order.
+    fn trace_writer_register_special_event_with_attributes(
+        handle: *mut std::ffi::c_void,
+        kind: i32,
+        metadata: *const std::os::raw::c_char,
+        content: *const std::os::raw::c_char,
+        keys: *const *const std::os::raw::c_char,
+        values_cbor: *const u8,
+        value_lens: *const usize,
+        count: usize,
+    );
+
+    // ----- async task events -----
+    fn trace_writer_register_task_spawn(handle: *mut std::ffi::c_void, task_id: u64);
+    fn trace_writer_register_task_suspend(handle: *mut std::ffi::c_void, task_id: u64);
+    fn trace_writer_register_task_resume(handle: *mut std::ffi::c_void, task_id: u64);
+    fn trace_writer_register_task_complete(handle: *mut std::ffi::c_void, task_id: u64);
+
+    // ----- exception events -----
+    //
+    // Exception values travel as streaming-encoder CBOR, like return values;
+    // an unwind carries the ids of the frames it pops, innermost first.
+    fn trace_writer_register_throw_cbor(handle: *mut std::ffi::c_void, cbor_data: *const u8, cbor_len: usize);
+    fn trace_writer_register_catch_cbor(handle: *mut std::ffi::c_void, cbor_data: *const u8, cbor_len: usize);
+    fn trace_writer_register_unwind(handle: *mut std::ffi::c_void, function_ids: *const u64, count: usize);
+
+    // ----- process events -----
+    //
+    // An empty parent recording id means the parent was not recorded.
+    fn trace_writer_register_process_start(
+        handle: *mut std::ffi::c_void,
+        pid: u32,
+        parent_pid: u32,
+        parent_recording_id: *const std::os::raw::c_char,
+    );
+    fn trace_writer_register_exec(
+        handle: *mut std::ffi::c_void,
+        program: *const std::os::raw::c_char,
+        args: *const *const std::os::raw::c_char,
+        args_count: usize,
+    );
+    fn trace_writer_register_signal(handle: *mut std::ffi::c_void, signal: i32, name: *const std::os::raw::c_char);
+    fn trace_writer_register_exit(handle: *mut std::ffi::c_void, code: i32);
+
+    // ----- memory events -----
+    fn trace_writer_register_memory_write(handle: *mut std::ffi::c_void, address: u64, bytes: *const u8, len: usize);
+    fn trace_writer_register_alloc(handle: *mut std::ffi::c_void, address: u64, size: u64);
+    fn trace_writer_register_free(handle: *mut std::ffi::c_void, address: u64);
+}
+
 // ---------------------------------------------------------------------------
 // Initialization
 // ---------------------------------------------------------------------------
@@ -488,6 +555,24 @@ impl StreamingValueEncoder {
         unsafe { ct_value_write_int(self.handle, value, type_id.0 as u64) };
     }
 
+    /// Write an unsigned 64-bit integer value.
+    #[cfg(feature = "extended-events")]
+    pub fn write_uint(&mut self, value: u64, type_id: TypeId) {
+        unsafe { ct_value_write_uint(self.handle, value, type_id.0 as u64) };
+    }
+
+    /// Write a signed 128-bit integer value, passed as its low and high 64 bits.
+    #[cfg(feature = "extended-events")]
+    pub fn write_int128(&mut self, value: i128, type_id: TypeId) {
+        unsafe { ct_value_write_int128(self.handle, value as u64, (value >> 64) as u64, type_id.0 as u64) };
+    }
+
+    /// Write an unsigned 128-bit integer value, passed as its low and high 64 bits.
+    #[cfg(feature = "extended-events")]
+    pub fn write_uint128(&mut self, value: u128, type_id: TypeId) {
+        unsafe { ct_value_write_uint128(self.handle, value as u64, (value >> 64) as u64, type_id.0 as u64) };
+    }
+
     /// Write a floating-point value.
     pub fn write_float(&mut self, value: f64, type_id: TypeId) {
         unsafe { ct_value_write_float(self.handle, value, type_id.0 as u64) };
@@ -518,6 +603,12 @@ impl StreamingValueEncoder {
         unsafe { ct_value_write_error(self.handle, msg.as_ptr(), msg.len(), type_id.0 as u64) };
     }
 
+    /// Write a byte buffer value (stored as a raw CBOR byte string).
+    #[cfg(feature = "extended-events")]
+    pub fn write_bytes(&mut self, data: &[u8], type_id: TypeId) {
+        unsafe { ct_value_write_bytes(self.handle, data.as_ptr(), data.len(), type_id.0 as u64) };
+    }
+
     /// Begin a sequence (list/array) with a known element count.
     /// Must be followed by exactly `count` element encodings and one
     /// [`end_compound`](Self::end_compound) call.
@@ -541,9 +632,17 @@ impl StreamingValueEncoder {
         unsafe { ct_value_begin_tuple(self.handle, type_id.0 as u64, count as i32) };
     }
 
-    /// End a compound value (sequence or tuple) started by
-    /// [`begin_sequence`](Self::begin_sequence) or
-    /// [`begin_tuple`](Self::begin_tuple).
+    /// Begin a map with a known entry count.
+    /// Must be followed by exactly `count` key/value pairs (key first, then
+    /// value, for each entry) and one [`end_compound`](Self::end_compound) call.
+    #[cfg(feature = "extended-events")]
+    pub fn begin_map(&mut self, type_id: TypeId, count: usize) {
+        unsafe { ct_value_begin_map(self.handle, type_id.0 as u64, count as i32) };
+    }
+
+    /// End a compound value (sequence, tuple or map) started by
+    /// [`begin_sequence`](Self::begin_sequence),
+    /// [`begin_tuple`](Self::begin_tuple) or `begin_map`.
     pub fn end_compound(&mut self) {
         unsafe { ct_value_end_compound(self.handle) };
     }
@@ -557,6 +656,18 @@ impl StreamingValueEncoder {
             ValueRecord::Int { i, type_id } => {
                 unsafe { ct_value_write_int(self.handle, *i, type_id.0 as u64) };
             }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::UInt { u, type_id } => {
+                unsafe { ct_value_write_uint(self.handle, *u, type_id.0 as u64) };
+            }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::Int128 { i, type_id } => {
+                unsafe { ct_value_write_int128(self.handle, *i as u64, (*i >> 64) as u64, type_id.0 as u64) };
+            }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::UInt128 { u, type_id } => {
+                unsafe { ct_value_write_uint128(self.handle, *u as u64, (*u >> 64) as u64, type_id.0 as u64) };
+            }
             ValueRecord::Float { f, type_id } => {
                 unsafe { ct_value_write_float(self.handle, *f, type_id.0 as u64) };
             }
@@ -593,6 +704,15 @@ impl StreamingValueEncoder {
                 }
                 unsafe { ct_value_end_compound(self.handle) };
             }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::Map { entries, type_id } => {
+                unsafe { ct_value_begin_map(self.handle, type_id.0 as u64, entries.len() as i32) };
+                for (key, value) in entries {
+                    self.encode_recursive(key);
+                    self.encode_recursive(value);
+                }
+                unsafe { ct_value_end_compound(self.handle) };
+            }
             ValueRecord::Variant {
                 discriminator,
                 contents,
@@ -623,6 +743,30 @@ impl StreamingValueEncoder {
                 };
                 unsafe { ct_value_write_bigint(self.handle, ptr, len, if *negative { 1 } else { 0 }, type_id.0 as u64) };
             }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::Bytes { data, type_id } => {
+                unsafe { ct_value_write_bytes(self.handle, data.as_ptr(), data.len(), type_id.0 as u64) };
+            }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::Elided {
+                partial,
+                original_len,
+                depth_cutoff,
+            } => {
+                unsafe { ct_value_begin_elided(self.handle, *original_len, if *depth_cutoff { 1 } else { 0 }) };
+                self.encode_recursive(partial);
+                unsafe { ct_value_end_compound(self.handle) };
+            }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::Object { id, value } => {
+                unsafe { ct_value_begin_object(self.handle, id.0) };
+                self.encode_recursive(value);
+                unsafe { ct_value_end_compound(self.handle) };
+            }
+            #[cfg(feature = "extended-events")]
+            ValueRecord::BackRef { id, type_id } => {
+                unsafe { ct_value_write_back_ref(self.handle, id.0, type_id.0 as u64) };
+            }
             // Cell has no streaming-encoder counterpart yet — its CBOR shape
             // (`{ "kind":"Cell", "place": int }`) only appears in tracer-side
             // intermediates, never in recorder output. Fall back to a raw
@@ -631,6 +775,23 @@ impl StreamingValueEncoder {
                 let (repr, _kind, _type_name) = value_record_to_raw(value);
                 unsafe { ct_value_write_raw(self.handle, repr.as_ptr(), repr.len(), 0) };
             }
+            // Without the `extended-events` exports these have no CBOR
+            // shape either: elided and object markers are dropped in favour
+            // of the value they wrap, the rest is written as `Raw`.
+            #[cfg(not(feature = "extended-events"))]
+            ValueRecord::Elided { partial, .. } => self.encode_recursive(partial),
+            #[cfg(not(feature = "extended-events"))]
+            ValueRecord::Object { value, .. } => self.encode_recursive(value),
+            #[cfg(not(feature = "extended-events"))]
+            ValueRecord::UInt { .. }
+            | ValueRecord::Int128 { .. }
+            | ValueRecord::UInt128 { .. }
+            | ValueRecord::Map { .. }
+            | ValueRecord::Bytes { .. }
+            | ValueRecord::BackRef { .. } => {
+                let (repr, _kind, _type_name) = value_record_to_raw(value);
+                unsafe { ct_value_write_raw(self.handle, repr.as_ptr(), repr.len(), 0) };
+            }
         }
     }
 }
@@ -678,6 +839,9 @@ pub struct NimTraceWriter {
     /// string to call `register_variable_with_full_value`.  Populated from
     /// preceding `VariableName(String)` / `Variable(String)` events.
     variable_table: Vec<String>,
+    #[cfg(not(feature = "extended-events"))]
+    /// The kinds of events `add_event` dropped and warned about already.
+    unrecorded_kinds: std::collections::HashSet<&'static str>,
 }
 
 // The Nim library is single-threaded but callers hold exclusive &mut self,
@@ -708,6 +872,8 @@ impl NimTraceWriter {
             streaming_encoder: StreamingValueEncoder::new(),
             path_table: Vec::new(),
             variable_table: Vec::new(),
+            #[cfg(not(feature = "extended-events"))]
+            unrecorded_kinds: std::collections::HashSet::new(),
         }
     }
 
@@ -941,6 +1107,36 @@ impl NimTraceWriter {
         unsafe { trace_writer_register_special_event(self.handle, kind as i32, c_metadata.as_ptr(), c_content.as_ptr()) }
     }
 
+    #[cfg(feature = "extended-events")]
+    pub fn register_special_event_with_attributes(&mut self, kind: EventLogKind, metadata: &str, content: &str, attributes: Vec<EventAttribute>) {
+        if attributes.is_empty() {
+            return self.register_special_event(kind, metadata, content);
+        }
+        let c_metadata = str_to_cstring(metadata);
+        let c_content = str_to_cstring(content);
+        let c_keys: Vec<CString> = attributes.iter().map(|attribute| str_to_cstring(&attribute.key)).collect();
+        let key_ptrs: Vec<*const std::os::raw::c_char> = c_keys.iter().map(|key| key.as_ptr()).collect();
+        let mut values_cbor = Vec::new();
+        let mut value_lens = Vec::with_capacity(attributes.len());
+        for attribute in &attributes {
+            let cbor = self.streaming_encoder.encode(&attribute.value);
+            value_lens.push(cbor.len());
+            values_cbor.extend_from_slice(cbor);
+        }
+        unsafe {
+            trace_writer_register_special_event_with_attributes(
+                self.handle,
+                kind as i32,
+                c_metadata.as_ptr(),
+                c_content.as_ptr(),
+                key_ptrs.as_ptr(),
+                values_cbor.as_ptr(),
+                value_lens.as_ptr(),
+                attributes.len(),
+            )
+        }
+    }
+
     /// Register a `ThreadStart` event (a new thread came into existence).
     ///
     /// Recorders observing multi-threaded execution should call this rather than
@@ -960,6 +1156,99 @@ impl NimTraceWriter {
         unsafe { trace_writer_register_thread_switch(self.handle, thread_id) }
     }
 
+    #[cfg(feature = "extended-events")]
+    /// Register a `TaskSpawn` event (a new async task exists).
+    pub fn register_task_spawn(&mut self, task_id: u64) {
+        unsafe { trace_writer_register_task_spawn(self.handle, task_id) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `TaskSuspend` event (the running task awaits).
+    pub fn register_task_suspend(&mut self, task_id: u64) {
+        unsafe { trace_writer_register_task_suspend(self.handle, task_id) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `TaskResume` event (the task runs on this thread again).
+    pub fn register_task_resume(&mut self, task_id: u64) {
+        unsafe { trace_writer_register_task_resume(self.handle, task_id) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `TaskComplete` event (the task finished).
+    pub fn register_task_complete(&mut self, task_id: u64) {
+        unsafe { trace_writer_register_task_complete(self.handle, task_id) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `Throw` event: `exception` is raised at the current step.
+    pub fn register_throw(&mut self, exception: ValueRecord) {
+        let cbor = self.streaming_encoder.encode(&exception);
+        unsafe { trace_writer_register_throw_cbor(self.handle, cbor.as_ptr(), cbor.len()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `Catch` event: `exception` is handled in the current frame.
+    pub fn register_catch(&mut self, exception: ValueRecord) {
+        let cbor = self.streaming_encoder.encode(&exception);
+        unsafe { trace_writer_register_catch_cbor(self.handle, cbor.as_ptr(), cbor.len()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register an `Unwind` event popping `frames` (innermost first).
+    pub fn register_unwind(&mut self, frames: &[FunctionId]) {
+        let ids: Vec<u64> = frames.iter().map(|id| id.0 as u64).collect();
+        unsafe { trace_writer_register_unwind(self.handle, ids.as_ptr(), ids.len()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `ProcessStart` event; `parent_recording_id` links a forked
+    /// child to its parent's trace.
+    pub fn register_process_start(&mut self, pid: u32, parent_pid: u32, parent_recording_id: Option<&str>) {
+        let c_parent = str_to_cstring(parent_recording_id.unwrap_or_default());
+        unsafe { trace_writer_register_process_start(self.handle, pid, parent_pid, c_parent.as_ptr()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register an `Exec` event (the process image was replaced).
+    pub fn register_exec(&mut self, program: &str, args: &[String]) {
+        let c_program = str_to_cstring(program);
+        let c_args: Vec<CString> = args.iter().map(|arg| str_to_cstring(arg)).collect();
+        let arg_ptrs: Vec<*const std::os::raw::c_char> = c_args.iter().map(|arg| arg.as_ptr()).collect();
+        unsafe { trace_writer_register_exec(self.handle, c_program.as_ptr(), arg_ptrs.as_ptr(), arg_ptrs.len()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `Signal` event (a signal was delivered to the process).
+    pub fn register_signal(&mut self, signal: i32, name: &str) {
+        let c_name = str_to_cstring(name);
+        unsafe { trace_writer_register_signal(self.handle, signal, c_name.as_ptr()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register an `Exit` event with the process exit code.
+    pub fn register_exit(&mut self, code: i32) {
+        unsafe { trace_writer_register_exit(self.handle, code) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `MemoryWrite` event: `bytes` were stored at `address`.
+    pub fn register_memory_write(&mut self, address: u64, bytes: &[u8]) {
+        unsafe { trace_writer_register_memory_write(self.handle, address, bytes.as_ptr(), bytes.len()) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register an `Alloc` event for a heap block of `size` bytes.
+    pub fn register_alloc(&mut self, address: u64, size: u64) {
+        unsafe { trace_writer_register_alloc(self.handle, address, size) }
+    }
+
+    #[cfg(feature = "extended-events")]
+    /// Register a `Free` event for the heap block at `address`.
+    pub fn register_free(&mut self, address: u64) {
+        unsafe { trace_writer_register_free(self.handle, address) }
+    }
+
     // --- Methods that are no-ops in the Nim backend ---
 
     pub fn ensure_path_id(&mut self, _path: &Path) -> PathId {
@@ -1123,7 +1412,9 @@ impl NimTraceWriter {
                 self.variable_table.push(name.clone());
                 self.register_variable_name(&name);
             }
-            TraceLowLevelEvent::Step(StepRecord { path_id, line }) => {
+            // The Nim multi-stream format has no span column yet, so steps
+            // (and functions below) keep only their line.
+            TraceLowLevelEvent::Step(StepRecord { path_id, line, .. }) => {
                 let path: std::path::PathBuf = self
                     .path_table
                     .get(path_id.0)
@@ -1160,6 +1451,11 @@ impl NimTraceWriter {
             TraceLowLevelEvent::Return(rec) => {
                 self.register_return(rec.return_value);
             }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Event(rec) => {
+                self.register_special_event_with_attributes(rec.kind, &rec.metadata, &rec.content, rec.attributes);
+            }
+            #[cfg(not(feature = "extended-events"))]
             TraceLowLevelEvent::Event(rec) => {
                 self.register_special_event(rec.kind, &rec.metadata, &rec.content);
             }
@@ -1226,12 +1522,105 @@ impl NimTraceWriter {
             TraceLowLevelEvent::ThreadSwitch(tid) => {
                 self.register_thread_switch(tid.0);
             }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::TaskSpawn(task_id) => {
+                self.register_task_spawn(task_id.0);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::TaskSuspend(task_id) => {
+                self.register_task_suspend(task_id.0);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::TaskResume(task_id) => {
+                self.register_task_resume(task_id.0);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::TaskComplete(task_id) => {
+                self.register_task_complete(task_id.0);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Throw(rec) => {
+                self.register_throw(rec.exception);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Catch(rec) => {
+                self.register_catch(rec.exception);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Unwind(rec) => {
+                self.register_unwind(&rec.frames);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::ProcessStart(rec) => {
+                self.register_process_start(rec.pid, rec.parent_pid, rec.parent_recording_id.as_deref());
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Exec(rec) => {
+                self.register_exec(&rec.program, &rec.args);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Signal(rec) => {
+                self.register_signal(rec.signal, &rec.name);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Exit(rec) => {
+                self.register_exit(rec.code);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::MemoryWrite(rec) => {
+                self.register_memory_write(rec.address, &rec.bytes);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Alloc(rec) => {
+                self.register_alloc(rec.address, rec.size);
+            }
+            #[cfg(feature = "extended-events")]
+            TraceLowLevelEvent::Free(rec) => {
+                self.register_free(rec.address);
+            }
+            // The Nim library has no exports for the rest without the
+            // `extended-events` feature.  An unwind still has to close its
+            // frames, or every later call would nest inside them, so each
+            // frame is recorded as a plain return; the other events are
+            // reported, once per kind, and not recorded.
+            #[cfg(not(feature = "extended-events"))]
+            TraceLowLevelEvent::Unwind(rec) => {
+                for _ in &rec.frames {
+                    self.register_return(NONE_VALUE);
+                }
+            }
+            #[cfg(not(feature = "extended-events"))]
+            TraceLowLevelEvent::TaskSpawn(_)
+            | TraceLowLevelEvent::TaskSuspend(_)
+            | TraceLowLevelEvent::TaskResume(_)
+            | TraceLowLevelEvent::TaskComplete(_)
+            | TraceLowLevelEvent::Throw(_)
+            | TraceLowLevelEvent::Catch(_)
+            | TraceLowLevelEvent::ProcessStart(_)
+            | TraceLowLevelEvent::Exec(_)
+            | TraceLowLevelEvent::Signal(_)
+            | TraceLowLevelEvent::Exit(_)
+            | TraceLowLevelEvent::MemoryWrite(_)
+            | TraceLowLevelEvent::Alloc(_)
+            | TraceLowLevelEvent::Free(_) => self.warn_unrecorded(&event),
             TraceLowLevelEvent::DropLastStep => {
                 self.drop_last_step();
             }
         }
     }
 
+    #[cfg(not(feature = "extended-events"))]
+    /// Warn on stderr, the first time for each kind, that `event` can't be
+    /// recorded without the `extended-events` feature.
+    fn warn_unrecorded(&mut self, event: &TraceLowLevelEvent) {
+        if self.unrecorded_kinds.insert(event.kind_name()) {
+            eprintln!(
+                "warning: the Nim trace writer drops {} events: they need the `extended-events` feature",
+                event.kind_name()
+            );
+        }
+    }
+
     /// Drain `events` into `add_event` one by one, dispatching every variant
     /// to its real `register_*` entry point.  The vector is cleared as a side
     /// effect so callers can reuse it as a scratch buffer.
@@ -1300,6 +1689,17 @@ pub trait TraceWriter: Send {
     fn register_return(&mut self, return_value: ValueRecord);
     fn register_special_event(&mut self, kind: EventLogKind, metadata: &str, content: &str);
 
+    /// Register an `Event` carrying typed attributes.  The default goes
+    /// through [`add_event`](Self::add_event), like the thread events.
+    fn register_special_event_with_attributes(&mut self, kind: EventLogKind, metadata: &str, content: &str, attributes: Vec<EventAttribute>) {
+        self.add_event(TraceLowLevelEvent::Event(RecordEvent {
+            kind,
+            metadata: metadata.to_string(),
+            content: content.to_string(),
+            attributes,
+        }));
+    }
+
     /// Register a `ThreadStart` event.  Default implementation delegates to
     /// [`add_event`](Self::add_event) so existing implementations (notably the
     /// in-memory test double) continue to capture the event with no extra
@@ -1319,6 +1719,93 @@ pub trait TraceWriter: Send {
         self.add_event(TraceLowLevelEvent::ThreadSwitch(ThreadId(thread_id)));
     }
 
+    /// Register a `TaskSpawn` event.  Like the thread events, the default
+    /// goes through [`add_event`](Self::add_event).
+    fn register_task_spawn(&mut self, task_id: u64) {
+        self.add_event(TraceLowLevelEvent::TaskSpawn(TaskId(task_id)));
+    }
+
+    /// Register a `TaskSuspend` event.  See [`register_task_spawn`].
+    fn register_task_suspend(&mut self, task_id: u64) {
+        self.add_event(TraceLowLevelEvent::TaskSuspend(TaskId(task_id)));
+    }
+
+    /// Register a `TaskResume` event.  See [`register_task_spawn`].
+    fn register_task_resume(&mut self, task_id: u64) {
+        self.add_event(TraceLowLevelEvent::TaskResume(TaskId(task_id)));
+    }
+
+    /// Register a `TaskComplete` event.  See [`register_task_spawn`].
+    fn register_task_complete(&mut self, task_id: u64) {
+        self.add_event(TraceLowLevelEvent::TaskComplete(TaskId(task_id)));
+    }
+
+    /// Register a `Throw` event.  Like the thread events, the default goes
+    /// through [`add_event`](Self::add_event).
+    fn register_throw(&mut self, exception: ValueRecord) {
+        self.add_event(TraceLowLevelEvent::Throw(ThrowRecord { exception }));
+    }
+
+    /// Register a `Catch` event.  See [`register_throw`].
+    fn register_catch(&mut self, exception: ValueRecord) {
+        self.add_event(TraceLowLevelEvent::Catch(CatchRecord { exception }));
+    }
+
+    /// Register an `Unwind` event popping `frames`, innermost first.
+    fn register_unwind(&mut self, frames: &[FunctionId]) {
+        self.add_event(TraceLowLevelEvent::Unwind(UnwindRecord { frames: frames.to_vec() }));
+    }
+
+    /// Register a `ProcessStart` event.  Like the thread events, the default
+    /// goes through [`add_event`](Self::add_event).
+    fn register_process_start(&mut self, pid: u32, parent_pid: u32, parent_recording_id: Option<&str>) {
+        self.add_event(TraceLowLevelEvent::ProcessStart(ProcessStartRecord {
+            pid,
+            parent_pid,
+            parent_recording_id: parent_recording_id.map(str::to_string),
+        }));
+    }
+
+    /// Register an `Exec` event.  See [`register_process_start`].
+    fn register_exec(&mut self, program: &str, args: &[String]) {
+        self.add_event(TraceLowLevelEvent::Exec(ExecRecord {
+            program: program.to_string(),
+            args: args.to_vec(),
+        }));
+    }
+
+    /// Register a `Signal` event.  See [`register_process_start`].
+    fn register_signal(&mut self, signal: i32, name: &str) {
+        self.add_event(TraceLowLevelEvent::Signal(SignalRecord {
+            signal,
+            name: name.to_string(),
+        }));
+    }
+
+    /// Register an `Exit` event.  See [`register_process_start`].
+    fn register_exit(&mut self, code: i32) {
+        self.add_event(TraceLowLevelEvent::Exit(ExitRecord { code }));
+    }
+
+    /// Register a `MemoryWrite` event.  Like the thread events, the default
+    /// goes through [`add_event`](Self::add_event).
+    fn register_memory_write(&mut self, address: u64, bytes: &[u8]) {
+        self.add_event(TraceLowLevelEvent::MemoryWrite(MemoryWriteRecord {
+            address,
+            bytes: bytes.to_vec(),
+        }));
+    }
+
+    /// Register an `Alloc` event.  See [`register_memory_write`].
+    fn register_alloc(&mut self, address: u64, size: u64) {
+        self.add_event(TraceLowLevelEvent::Alloc(AllocRecord { address, size }));
+    }
+
+    /// Register a `Free` event.  See [`register_memory_write`].
+    fn register_free(&mut self, address: u64) {
+        self.add_event(TraceLowLevelEvent::Free(FreeRecord { address }));
+    }
+
     fn to_raw_type(&self, kind: TypeKind, lang_type: &str) -> TypeRecord;
     fn register_type(&mut self, kind: TypeKind, lang_type: &str);
     fn register_raw_type(&mut self, typ: TypeRecord);
@@ -1473,6 +1960,10 @@ impl TraceWriter for NimTraceWriter {
     fn register_special_event(&mut self, kind: EventLogKind, metadata: &str, content: &str) {
         NimTraceWriter::register_special_event(self, kind, metadata, content)
     }
+    #[cfg(feature = "extended-events")]
+    fn register_special_event_with_attributes(&mut self, kind: EventLogKind, metadata: &str, content: &str, attributes: Vec<EventAttribute>) {
+        NimTraceWriter::register_special_event_with_attributes(self, kind, metadata, content, attributes)
+    }
     fn register_thread_start(&mut self, thread_id: u64) {
         NimTraceWriter::register_thread_start(self, thread_id)
     }
@@ -1482,6 +1973,62 @@ impl TraceWriter for NimTraceWriter {
     fn register_thread_switch(&mut self, thread_id: u64) {
         NimTraceWriter::register_thread_switch(self, thread_id)
     }
+    #[cfg(feature = "extended-events")]
+    fn register_task_spawn(&mut self, task_id: u64) {
+        NimTraceWriter::register_task_spawn(self, task_id)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_task_suspend(&mut self, task_id: u64) {
+        NimTraceWriter::register_task_suspend(self, task_id)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_task_resume(&mut self, task_id: u64) {
+        NimTraceWriter::register_task_resume(self, task_id)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_task_complete(&mut self, task_id: u64) {
+        NimTraceWriter::register_task_complete(self, task_id)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_throw(&mut self, exception: ValueRecord) {
+        NimTraceWriter::register_throw(self, exception)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_catch(&mut self, exception: ValueRecord) {
+        NimTraceWriter::register_catch(self, exception)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_unwind(&mut self, frames: &[FunctionId]) {
+        NimTraceWriter::register_unwind(self, frames)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_process_start(&mut self, pid: u32, parent_pid: u32, parent_recording_id: Option<&str>) {
+        NimTraceWriter::register_process_start(self, pid, parent_pid, parent_recording_id)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_exec(&mut self, program: &str, args: &[String]) {
+        NimTraceWriter::register_exec(self, program, args)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_signal(&mut self, signal: i32, name: &str) {
+        NimTraceWriter::register_signal(self, signal, name)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_exit(&mut self, code: i32) {
+        NimTraceWriter::register_exit(self, code)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_memory_write(&mut self, address: u64, bytes: &[u8]) {
+        NimTraceWriter::register_memory_write(self, address, bytes)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_alloc(&mut self, address: u64, size: u64) {
+        NimTraceWriter::register_alloc(self, address, size)
+    }
+    #[cfg(feature = "extended-events")]
+    fn register_free(&mut self, address: u64) {
+        NimTraceWriter::register_free(self, address)
+    }
     fn to_raw_type(&self, kind: TypeKind, lang_type: &str) -> TypeRecord {
         NimTraceWriter::to_raw_type(self, kind, lang_type)
     }
@@ -2051,6 +2598,9 @@ pub fn create_trace_writer(program: &str, args: &[String], format: TraceEventsFi
 fn value_record_to_raw(value: &ValueRecord) -> (String, TypeKind, String) {
     match value {
         ValueRecord::Int { i, type_id } => (i.to_string(), TypeKind::Int, format!("type_{}", type_id.0)),
+        ValueRecord::UInt { u, type_id } => (u.to_string(), TypeKind::Int, format!("type_{}", type_id.0)),
+        ValueRecord::Int128 { i, type_id } => (i.to_string(), TypeKind::Int, format!("type_{}", type_id.0)),
+        ValueRecord::UInt128 { u, type_id } => (u.to_string(), TypeKind::Int, format!("type_{}", type_id.0)),
         ValueRecord::Float { f, type_id } => (f.to_string(), TypeKind::Float, format!("type_{}", type_id.0)),
         ValueRecord::Bool { b, type_id } => (b.to_string(), TypeKind::Bool, format!("type_{}", type_id.0)),
         ValueRecord::String { text, type_id } => (text.clone(), TypeKind::String, format!("type_{}", type_id.0)),
@@ -2061,6 +2611,14 @@ fn value_record_to_raw(value: &ValueRecord) -> (String, TypeKind, String) {
         ValueRecord::Sequence { type_id, .. } => ("[...]".to_string(), TypeKind::Seq, format!("type_{}", type_id.0)),
         ValueRecord::Tuple { type_id, .. } => ("(...)".to_string(), TypeKind::Tuple, format!("type_{}", type_id.0)),
         ValueRecord::Struct { type_id, .. } => ("{...}".to_string(), TypeKind::Struct, format!("type_{}", type_id.0)),
+        ValueRecord::Map { type_id, .. } => ("{...}".to_string(), TypeKind::TableKind, format!("type_{}", type_id.0)),
+        ValueRecord::Bytes { data, type_id } => (format!("<{} bytes>", data.len()), TypeKind::Seq, format!("type_{}", type_id.0)),
+        ValueRecord::Elided { partial, original_len, .. } => {
+            let (repr, kind, type_name) = value_record_to_raw(partial);
+            (format!("{repr} (of {original_len})"), kind, type_name)
+        }
+        ValueRecord::Object { value, .. } => value_record_to_raw(value),
+        ValueRecord::BackRef { id, type_id } => (format!("<object {}>", id.0), TypeKind::Ref, format!("type_{}", type_id.0)),
         ValueRecord::Variant { discriminator, type_id, .. } => (discriminator.clone(), TypeKind::Variant, format!("type_{}", type_id.0)),
         ValueRecord::Reference { address, type_id, .. } => (format!("0x{:x}", address), TypeKind::Pointer, format!("type_{}", type_id.0)),
         ValueRecord::Cell { place } => (format!("place_{}", place.0), TypeKind::Raw, "Cell".to_string()),
@@ -2128,6 +2686,8 @@ fn cbor_value_to_record(value: &ciborium::value::Value) -> Option<ValueRecord> {
     let as_u64 = |v: &V| -> Option<u64> { v.as_integer().and_then(|i| u64::try_from(i).ok()) };
     let as_i64 = |v: &V| -> Option<i64> { v.as_integer().and_then(|i| i64::try_from(i).ok()) };
     let as_str = |v: &V| -> Option<String> { v.as_text().map(|s| s.to_string()) };
+    // 128-bit integers are 16 little-endian bytes in CBOR
+    let as_wide_int = |v: &V| -> Option<[u8; 16]> { v.as_bytes().and_then(|b| b.as_slice().try_into().ok()) };
     let type_id = |default: u64| -> TypeId { TypeId(get("type_id").and_then(as_u64).unwrap_or(default) as usize) };
 
     let kind = get("kind").and_then(|v| v.as_text())?.to_string();
@@ -2136,6 +2696,18 @@ fn cbor_value_to_record(value: &ciborium::value::Value) -> Option<ValueRecord> {
             i: get("i").and_then(as_i64)?,
             type_id: type_id(0),
         },
+        "UInt" => ValueRecord::UInt {
+            u: get("u").and_then(as_u64)?,
+            type_id: type_id(0),
+        },
+        "Int128" => ValueRecord::Int128 {
+            i: i128::from_le_bytes(get("i").and_then(as_wide_int)?),
+            type_id: type_id(0),
+        },
+        "UInt128" => ValueRecord::UInt128 {
+            u: u128::from_le_bytes(get("u").and_then(as_wide_int)?),
+            type_id: type_id(0),
+        },
         "Float" => ValueRecord::Float {
             f: get("f").and_then(|v| v.as_float())?,
             type_id: type_id(0),
@@ -2177,6 +2749,10 @@ fn cbor_value_to_record(value: &ciborium::value::Value) -> Option<ValueRecord> {
             field_values: decode_cbor_elements(get("field_values")?)?,
             type_id: type_id(0),
         },
+        "Map" => ValueRecord::Map {
+            entries: decode_cbor_entries(get("entries")?)?,
+            type_id: type_id(0),
+        },
         "Variant" => ValueRecord::Variant {
             discriminator: get("discriminator").and_then(as_str)?,
             contents: Box::new(cbor_value_to_record(get("contents")?)?),
@@ -2193,6 +2769,23 @@ fn cbor_value_to_record(value: &ciborium::value::Value) -> Option<ValueRecord> {
             negative: get("negative").and_then(|v| v.as_bool()).unwrap_or(false),
             type_id: type_id(0),
         },
+        "Bytes" => ValueRecord::Bytes {
+            data: get("data").and_then(|v| v.as_bytes().map(|b| b.to_vec()))?,
+            type_id: type_id(0),
+        },
+        "Elided" => ValueRecord::Elided {
+            partial: Box::new(cbor_value_to_record(get("partial")?)?),
+            original_len: get("original_len").and_then(as_u64).unwrap_or(0),
+            depth_cutoff: get("depth_cutoff").and_then(|v| v.as_bool()).unwrap_or(false),
+        },
+        "Object" => ValueRecord::Object {
+            id: ObjectId(get("id").and_then(as_u64)?),
+            value: Box::new(cbor_value_to_record(get("value")?)?),
+        },
+        "BackRef" => ValueRecord::BackRef {
+            id: ObjectId(get("id").and_then(as_u64)?),
+            type_id: type_id(0),
+        },
         "Enum" => {
             // The streaming encoder can emit an Enum leaf; `ValueRecord` has no
             // Enum variant, so represent it as a Raw "name" — faithful enough
@@ -2216,6 +2809,18 @@ fn decode_cbor_elements(value: &ciborium::value::Value) -> Option<Vec<ValueRecor
     Some(out)
 }
 
+fn decode_cbor_entries(value: &ciborium::value::Value) -> Option<Vec<(ValueRecord, ValueRecord)>> {
+    let arr = value.as_array()?;
+    let mut out = Vec::with_capacity(arr.len());
+    for item in arr {
+        match item.as_array()?.as_slice() {
+            [key, value] => out.push((cbor_value_to_record(key)?, cbor_value_to_record(value)?)),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
 // ---------------------------------------------------------------------------
 // NonStreamingTraceWriter — in-memory test double
 // ---------------------------------------------------------------------------
@@ -2354,6 +2959,7 @@ pub mod non_streaming_trace_writer {
                 name: function_name.to_string(),
                 path_id,
                 line,
+                span: None,
             }));
             id
         }
@@ -2393,7 +2999,7 @@ pub mod non_streaming_trace_writer {
         }
         fn register_step(&mut self, path: &Path, line: Line) {
             let path_id = self.ensure_path_id(path);
-            self.events.push(TraceLowLevelEvent::Step(StepRecord { path_id, line }));
+            self.events.push(TraceLowLevelEvent::Step(StepRecord { path_id, line, span: None }));
         }
         fn register_call(&mut self, function_id: FunctionId, args: Vec<FullValueRecord>) {
             self.events.push(TraceLowLevelEvent::Call(CallRecord { function_id, args }));
@@ -2410,6 +3016,7 @@ pub mod non_streaming_trace_writer {
                 kind,
                 metadata: metadata.to_string(),
                 content: content.to_string(),
+                attributes: vec![],
             }));
         }
         fn to_raw_type(&self, kind: TypeKind, lang_type: &str) -> TypeRecord {
@@ -224,3 +224,40 @@ fn append_events_drains_and_dispatches() {
     );
     assert!(reader.step_json(3).unwrap().contains("\"kind\":\"thread_exit\""), "missing thread_exit");
 }
+
+#[cfg(not(feature = "extended-events"))]
+#[test]
+fn add_event_unwind_closes_the_frames_without_extended_events() {
+    use codetracer_trace_types::{UnwindRecord, NONE_VALUE};
+
+    let _guard = NIM_TEST_LOCK.lock().unwrap();
+
+    let program = "unwind_dispatch";
+    let (dir, mut writer) = make_writer(program);
+    let path = Path::new("/tmp/unwind_dispatch.rb");
+
+    writer.start(path, Line(1));
+    let outer = writer.ensure_function_id("outer", path, Line(10));
+    let inner = writer.ensure_function_id("inner", path, Line(20));
+    let after = writer.ensure_function_id("after", path, Line(30));
+    writer.register_call(outer, vec![]);
+    writer.register_call(inner, vec![]);
+    // an exception leaves both frames; without the Nim exports for it the
+    // unwind has to be recorded as returns
+    writer.add_event(TraceLowLevelEvent::Unwind(UnwindRecord { frames: vec![inner, outer] }));
+    writer.register_step(path, Line(2));
+    writer.register_call(after, vec![]);
+    writer.register_return(NONE_VALUE);
+
+    let ct_path = close_writer(dir, writer, program);
+    let reader = NimTraceReaderHandle::open(ct_path.to_str().unwrap()).expect("reader open");
+
+    // calls are keyed in entry order: outer, inner, after
+    let call_count = reader.call_count();
+    let depth = |key: u64| reader.call_fields(key).expect("call_fields").4;
+    assert_eq!(
+        depth(call_count - 1),
+        depth(call_count - 3),
+        "the call after the unwind should be a sibling of `outer`"
+    );
+}